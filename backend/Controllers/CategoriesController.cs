@@ -0,0 +1,49 @@
+using Microsoft.AspNetCore.Mvc;
+using Microsoft.EntityFrameworkCore;
+using PondoBro.Backend.Data;
+
+namespace PondoBro.Backend.Controllers;
+
+[ApiController]
+[Route("api/categories")]
+public class CategoriesController : ControllerBase
+{
+    private readonly AppDbContext _db;
+
+    public CategoriesController(AppDbContext db)
+    {
+        _db = db;
+    }
+
+    [HttpGet]
+    public async Task<IActionResult> GetAll()
+    {
+        int? userId = null;
+
+        if (Request.Cookies.TryGetValue("refresh_token", out var token))
+        {
+            var session = await _db.Sessions.FirstOrDefaultAsync(s => s.RefreshToken == token);
+            if (session is not null) userId = session.UserId;
+        }
+
+        if (userId is null && User?.Identity?.IsAuthenticated == true)
+        {
+            var sub = User.FindFirst(System.IdentityModel.Tokens.Jwt.JwtRegisteredClaimNames.Sub)?.Value
+                      ?? User.FindFirst(System.Security.Claims.ClaimTypes.NameIdentifier)?.Value;
+            if (int.TryParse(sub, out var parsed)) userId = parsed;
+        }
+
+        if (userId is null) return Unauthorized(new { error = "Not authenticated" });
+
+        // categories are free text on transactions, so a new one is known as
+        // soon as a transaction uses it
+        var categories = await _db.Transactions
+            .Where(t => t.UserId == userId.Value && t.Category != "")
+            .Select(t => t.Category)
+            .Distinct()
+            .OrderBy(c => c)
+            .ToListAsync();
+
+        return Ok(categories);
+    }
+}
@@ -35,8 +35,9 @@ public class DashboardController : ControllerBase
 
         if (userId is null) return Unauthorized(new { error = "Not authenticated" });
 
-        var totalIncome = await _db.Transactions.Where(t => t.UserId == userId && t.Amount > 0).SumAsync(t => (long?)t.Amount) ?? 0L;
-        var totalExpenses = await _db.Transactions.Where(t => t.UserId == userId && t.Amount < 0).SumAsync(t => (long?)t.Amount) ?? 0L;
+        // transfers move money between the user's own accounts; both legs are excluded
+        var totalIncome = await _db.Transactions.Where(t => t.UserId == userId && t.Amount > 0 && t.Category != "Transfer").SumAsync(t => (long?)t.Amount) ?? 0L;
+        var totalExpenses = await _db.Transactions.Where(t => t.UserId == userId && t.Amount < 0 && t.Category != "Transfer").SumAsync(t => (long?)t.Amount) ?? 0L;
         totalExpenses = Math.Abs(totalExpenses);
 
         var balance = totalIncome - totalExpenses;
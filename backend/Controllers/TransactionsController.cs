@@ -17,7 +17,7 @@ public class TransactionsController : ControllerBase
     }
 
     [HttpGet]
-    public async Task<IActionResult> GetAll()
+    public async Task<IActionResult> GetAll([FromQuery] int? offset, [FromQuery] int? limit)
     {
         int? userId = null;
 
@@ -38,10 +38,19 @@ public class TransactionsController : ControllerBase
 
         if (userId is null) return Unauthorized(new { error = "Not authenticated" });
 
-        var list = await _db.Transactions
+        // newest first; Id breaks ties so pages don't overlap or skip rows
+        IQueryable<Transaction> query = _db.Transactions
             .Where(t => t.UserId == userId.Value)
             .OrderByDescending(t => t.Date)
-            .ToListAsync();
+            .ThenByDescending(t => t.Id);
+
+        // without a limit the whole history is returned, as before
+        if (limit is > 0)
+        {
+            query = query.Skip(Math.Max(offset ?? 0, 0)).Take(limit.Value);
+        }
+
+        var list = await query.ToListAsync();
 
         return Ok(list);
     }
@@ -52,6 +61,13 @@ public class TransactionsController : ControllerBase
         public string Description { get; set; } = string.Empty;
         public string Category { get; set; } = string.Empty;
         public long Amount { get; set; }
+        public string? Note { get; set; }
+        public string? ReceiptUrl { get; set; }
+        public string? Account { get; set; }
+        public bool Tax { get; set; }
+        public string? SplitGroup { get; set; }
+        public bool Flagged { get; set; }
+        public string? Currency { get; set; }
     }
 
     [HttpPost]
@@ -82,6 +98,13 @@ public class TransactionsController : ControllerBase
                 Description = req.Description ?? string.Empty,
                 Category = req.Category ?? string.Empty,
                 Amount = req.Amount,
+                Note = string.IsNullOrWhiteSpace(req.Note) ? null : req.Note,
+                ReceiptUrl = string.IsNullOrWhiteSpace(req.ReceiptUrl) ? null : req.ReceiptUrl,
+                Account = string.IsNullOrWhiteSpace(req.Account) ? null : req.Account,
+                Tax = req.Tax,
+                SplitGroup = string.IsNullOrWhiteSpace(req.SplitGroup) ? null : req.SplitGroup,
+                Flagged = req.Flagged,
+                Currency = string.IsNullOrWhiteSpace(req.Currency) ? null : req.Currency,
                 UserId = userId.Value
             };
 
@@ -95,4 +118,80 @@ public class TransactionsController : ControllerBase
             return Problem("Could not create transaction.");
         }
     }
+
+    [HttpPut("{id:int}")]
+    public async Task<IActionResult> Update(int id, CreateTransactionRequest req)
+    {
+        try
+        {
+            int? userId = null;
+
+            if (Request.Cookies.TryGetValue("refresh_token", out var token))
+            {
+                var session = await _db.Sessions.FirstOrDefaultAsync(s => s.RefreshToken == token);
+                if (session is not null) userId = session.UserId;
+            }
+
+            if (userId is null && User?.Identity?.IsAuthenticated == true)
+            {
+                var sub = User.FindFirst(System.IdentityModel.Tokens.Jwt.JwtRegisteredClaimNames.Sub)?.Value
+                          ?? User.FindFirst(System.Security.Claims.ClaimTypes.NameIdentifier)?.Value;
+                if (int.TryParse(sub, out var parsed)) userId = parsed;
+            }
+
+            if (userId is null) return Unauthorized(new { error = "Not authenticated" });
+
+            var tx = await _db.Transactions.FirstOrDefaultAsync(t => t.Id == id && t.UserId == userId.Value);
+            if (tx is null) return NotFound(new { error = "Transaction not found" });
+
+            if (!string.IsNullOrWhiteSpace(req.Date)) tx.Date = DateTime.Parse(req.Date);
+            tx.Description = req.Description ?? string.Empty;
+            tx.Category = req.Category ?? string.Empty;
+            tx.Amount = req.Amount;
+            tx.Note = string.IsNullOrWhiteSpace(req.Note) ? null : req.Note;
+            tx.ReceiptUrl = string.IsNullOrWhiteSpace(req.ReceiptUrl) ? null : req.ReceiptUrl;
+            tx.Account = string.IsNullOrWhiteSpace(req.Account) ? null : req.Account;
+            tx.Tax = req.Tax;
+            tx.SplitGroup = string.IsNullOrWhiteSpace(req.SplitGroup) ? null : req.SplitGroup;
+            tx.Flagged = req.Flagged;
+            tx.Currency = string.IsNullOrWhiteSpace(req.Currency) ? null : req.Currency;
+
+            await _db.SaveChangesAsync();
+
+            return Ok(tx);
+        }
+        catch (Exception)
+        {
+            return Problem("Could not update transaction.");
+        }
+    }
+
+    [HttpDelete("{id:int}")]
+    public async Task<IActionResult> Delete(int id)
+    {
+        int? userId = null;
+
+        if (Request.Cookies.TryGetValue("refresh_token", out var token))
+        {
+            var session = await _db.Sessions.FirstOrDefaultAsync(s => s.RefreshToken == token);
+            if (session is not null) userId = session.UserId;
+        }
+
+        if (userId is null && User?.Identity?.IsAuthenticated == true)
+        {
+            var sub = User.FindFirst(System.IdentityModel.Tokens.Jwt.JwtRegisteredClaimNames.Sub)?.Value
+                      ?? User.FindFirst(System.Security.Claims.ClaimTypes.NameIdentifier)?.Value;
+            if (int.TryParse(sub, out var parsed)) userId = parsed;
+        }
+
+        if (userId is null) return Unauthorized(new { error = "Not authenticated" });
+
+        var tx = await _db.Transactions.FirstOrDefaultAsync(t => t.Id == id && t.UserId == userId.Value);
+        if (tx is null) return NotFound(new { error = "Transaction not found" });
+
+        _db.Transactions.Remove(tx);
+        await _db.SaveChangesAsync();
+
+        return NoContent();
+    }
 }
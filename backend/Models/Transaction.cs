@@ -17,6 +17,20 @@ public class Transaction
 
     public long Amount { get; set; }
 
+    public string? Note { get; set; }
+
+    public string? ReceiptUrl { get; set; }
+
+    public string? Account { get; set; }
+
+    public bool Tax { get; set; }
+
+    public string? SplitGroup { get; set; }
+
+    public bool Flagged { get; set; }
+
+    public string? Currency { get; set; }
+
     public int? UserId { get; set; }
 
     public User? User { get; set; }
@@ -71,11 +71,27 @@ using (var scope = app.Services.CreateScope())
                 ""Description"" TEXT NOT NULL,
                 ""Category"" TEXT NOT NULL,
                 ""Amount"" INTEGER NOT NULL,
+                ""Note"" TEXT NULL,
+                ""ReceiptUrl"" TEXT NULL,
+                ""Account"" TEXT NULL,
+                ""Tax"" INTEGER NOT NULL DEFAULT 0,
+                ""SplitGroup"" TEXT NULL,
+                ""Flagged"" INTEGER NOT NULL DEFAULT 0,
+                ""Currency"" TEXT NULL,
                 ""UserId"" INTEGER,
                 CONSTRAINT ""FK_Transactions_Users_UserId"" FOREIGN KEY (""UserId"") REFERENCES ""Users"" (""Id"") ON DELETE CASCADE
             );"
         );
         db.Database.ExecuteSqlRaw(@"CREATE INDEX IF NOT EXISTS ""IX_Transactions_UserId"" ON ""Transactions"" (""UserId"");");
+
+        // Columns added to Transaction after the table was first created
+        EnsureColumn(db, "Transactions", "Note", "TEXT NULL");
+        EnsureColumn(db, "Transactions", "ReceiptUrl", "TEXT NULL");
+        EnsureColumn(db, "Transactions", "Account", "TEXT NULL");
+        EnsureColumn(db, "Transactions", "Tax", "INTEGER NOT NULL DEFAULT 0");
+        EnsureColumn(db, "Transactions", "SplitGroup", "TEXT NULL");
+        EnsureColumn(db, "Transactions", "Flagged", "INTEGER NOT NULL DEFAULT 0");
+        EnsureColumn(db, "Transactions", "Currency", "TEXT NULL");
     }
 }
 
@@ -87,3 +103,17 @@ app.MapControllers();
 app.MapGet("/api/health", () => new { ok = true });
 
 app.Run();
+
+static void EnsureColumn(AppDbContext db, string table, string column, string definition)
+{
+    var connection = db.Database.GetDbConnection();
+    if (connection.State != System.Data.ConnectionState.Open) connection.Open();
+
+    using var command = connection.CreateCommand();
+    command.CommandText = $"SELECT COUNT(*) FROM pragma_table_info('{table}') WHERE name = '{column}';";
+    var exists = Convert.ToInt64(command.ExecuteScalar()) > 0;
+    if (!exists)
+    {
+        db.Database.ExecuteSqlRaw($@"ALTER TABLE ""{table}"" ADD COLUMN ""{column}"" {definition};");
+    }
+}
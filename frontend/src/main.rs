@@ -1,10 +1,21 @@
-use gloo_net::http::Request;
+use gloo_events::{EventListener, EventListenerOptions, EventListenerPhase};
+use gloo_net::http::{Request, RequestBuilder, Response};
+use gloo_timers::callback::{Interval, Timeout};
+use gloo_timers::future::TimeoutFuture;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use wasm_bindgen_futures::spawn_local;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::{InputEvent, RequestCredentials};
 use yew::prelude::*;
 
+mod token_store;
+
 #[derive(Clone, PartialEq, Deserialize, Serialize)]
 struct Transaction {
     pub id: Option<i32>,
@@ -12,20 +23,413 @@ struct Transaction {
     pub description: String,
     pub category: String,
     pub amount: i64,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default, rename = "receiptUrl")]
+    pub receipt_url: Option<String>,
+    #[serde(default)]
+    pub account: Option<String>,
+    /// Tax-relevant (deductible or taxable); servers without the field send none.
+    #[serde(default)]
+    pub tax: bool,
+    /// ISO code the amount is in; `None` means the app currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Shared by the parts a transaction was split into; `None` for whole rows.
+    #[serde(
+        default,
+        rename = "splitGroup",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub split_group: Option<String>,
+    /// Marked for a later look while reconciling; servers without the field
+    /// send none.
+    #[serde(default)]
+    pub flagged: bool,
 }
 
 const API_BASE_URL: &str = "http://localhost:5000";
 
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 struct AppSettings {
     currency_code: String,
     currency_symbol: String,
+    /// Percent of each new income offered to the saving goal; 0 disables it.
+    auto_save_percent: u32,
+    /// 0 or 2; zero-decimal currencies always display 0.
+    decimal_places: u32,
+    grouping: GroupingStyle,
+    negative_style: NegativeStyle,
+    /// Whether a space separates the symbol from the number.
+    symbol_spacing: bool,
+    /// Browser notification when a new expense pushes a category over budget.
+    budget_alerts: bool,
+    /// Browser notification when a goal contribution is due for its cadence.
+    saving_alerts: bool,
+    /// First day of the week, 0 = Sunday through 6 = Saturday.
+    week_start: u32,
+    /// Day of the month budgeting periods start on, e.g. a payday; 1 is the
+    /// calendar month.
+    month_start_day: u32,
+    /// Seconds an API request may take before it is given up on.
+    request_timeout_secs: u32,
+    /// Page the app opens on.
+    default_page: Page,
+    rounding_mode: RoundingMode,
+    /// Whether `Savings` expenses (goal contributions) count as spending in
+    /// expense totals, breakdowns and budgets.
+    savings_as_spending: bool,
+    /// Whether budgets with nothing spent this period are left out of the
+    /// Dashboard budget card and the Budget page progress list.
+    hide_zero_spend: bool,
+    amount_entry: AmountEntry,
+    /// Whether new expenses are rounded up and the spare change saved to
+    /// the goal.
+    round_up_enabled: bool,
+    /// Whole currency units expenses are rounded up to, e.g. 10 or 50.
+    round_up_increment: i64,
+    /// Whether the Dashboard shows last month's report on the first visit
+    /// of a new month.
+    monthly_report: bool,
+    sign_cue: SignCue,
+    sign_palette: SignPalette,
+    /// Whether leaving a page with a half-filled form asks first.
+    confirm_on_leave: bool,
+}
+
+/// How digits are grouped and which decimal separator is used.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum GroupingStyle {
+    /// 1,234,567.00
+    #[default]
+    Western,
+    /// 1.234.567,00
+    European,
+    /// 12,34,567.00 (lakh/crore)
+    Indian,
+    /// 1 234 567,00
+    Space,
+}
+
+impl GroupingStyle {
+    const ALL: [GroupingStyle; 4] = [
+        GroupingStyle::Western,
+        GroupingStyle::European,
+        GroupingStyle::Indian,
+        GroupingStyle::Space,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            GroupingStyle::Western => "western",
+            GroupingStyle::European => "european",
+            GroupingStyle::Indian => "indian",
+            GroupingStyle::Space => "space",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            GroupingStyle::Western => "Western (1,234,567.00)",
+            GroupingStyle::European => "European (1.234.567,00)",
+            GroupingStyle::Indian => "Indian (12,34,567.00)",
+            GroupingStyle::Space => "Space-separated (1 234 567,00)",
+        }
+    }
+
+    fn from_key(key: &str) -> GroupingStyle {
+        GroupingStyle::ALL
+            .into_iter()
+            .find(|style| style.key() == key)
+            .unwrap_or_default()
+    }
+
+    fn separators(self) -> (char, char) {
+        match self {
+            GroupingStyle::Western | GroupingStyle::Indian => (',', '.'),
+            GroupingStyle::European => ('.', ','),
+            GroupingStyle::Space => ('\u{a0}', ','),
+        }
+    }
+}
+
+/// How derived amounts (percentages, averages, paces) are rounded to whole
+/// units. Every such figure goes through `round_ratio`.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum RoundingMode {
+    /// 2.5 → 3, -2.5 → -3
+    #[default]
+    HalfUp,
+    /// Floor, toward minus infinity: 2.9 → 2, -2.1 → -3
+    Down,
+    /// Ties go to the even neighbour: 2.5 → 2, 3.5 → 4
+    HalfEven,
+}
+
+impl RoundingMode {
+    const ALL: [RoundingMode; 3] = [
+        RoundingMode::HalfUp,
+        RoundingMode::Down,
+        RoundingMode::HalfEven,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            RoundingMode::HalfUp => "half_up",
+            RoundingMode::Down => "down",
+            RoundingMode::HalfEven => "half_even",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            RoundingMode::HalfUp => "Nearest, halves up (2.5 → 3)",
+            RoundingMode::Down => "Round down (2.9 → 2, -2.1 → -3)",
+            RoundingMode::HalfEven => "Banker's, halves to even (2.5 → 2)",
+        }
+    }
+
+    fn from_key(key: &str) -> RoundingMode {
+        RoundingMode::ALL
+            .into_iter()
+            .find(|mode| mode.key() == key)
+            .unwrap_or_default()
+    }
+}
+
+/// How amount fields read what is typed.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum AmountEntry {
+    /// `12.50` is ₱12.50
+    #[default]
+    Decimal,
+    /// Whole minor units: `1200` is ₱12.00. Amounts are stored in whole
+    /// units, so cents that don't make one are rejected.
+    Cents,
+}
+
+impl AmountEntry {
+    const ALL: [AmountEntry; 2] = [AmountEntry::Decimal, AmountEntry::Cents];
+
+    fn key(self) -> &'static str {
+        match self {
+            AmountEntry::Decimal => "decimal",
+            AmountEntry::Cents => "cents",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            AmountEntry::Decimal => "Decimals (12.50)",
+            AmountEntry::Cents => "Cents (1200 = 12.00)",
+        }
+    }
+
+    fn from_key(key: &str) -> AmountEntry {
+        AmountEntry::ALL
+            .into_iter()
+            .find(|entry| entry.key() == key)
+            .unwrap_or_default()
+    }
+}
+
+/// `numerator / denominator` as a whole number, rounded by `mode`. The
+/// nearest modes work on magnitudes, so negative amounts mirror positive
+/// ones; `Down` is a true floor. Integer-only, so there is no float drift at
+/// the .5 boundary; a zero denominator gives 0.
+fn round_ratio(numerator: i64, denominator: i64, mode: RoundingMode) -> i64 {
+    if denominator == 0 {
+        return 0;
+    }
+    let negative = (numerator < 0) != (denominator < 0);
+    let (n, d) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+    let (quotient, remainder) = (n / d, n % d);
+    let round_up = match mode {
+        RoundingMode::HalfUp => remainder * 2 >= d,
+        // away from zero on the magnitude is toward minus infinity
+        RoundingMode::Down => negative && remainder > 0,
+        RoundingMode::HalfEven => remainder * 2 > d || (remainder * 2 == d && quotient % 2 == 1),
+    };
+    let magnitude = (quotient + round_up as u64) as i64;
+    if negative {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Where the minus sign goes relative to the currency symbol.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum NegativeStyle {
+    /// -₱1,000.00
+    #[default]
+    LeadingSign,
+    /// ₱-1,000.00
+    SignAfterSymbol,
+    /// (₱1,000.00)
+    Parentheses,
+}
+
+impl NegativeStyle {
+    const ALL: [NegativeStyle; 3] = [
+        NegativeStyle::LeadingSign,
+        NegativeStyle::SignAfterSymbol,
+        NegativeStyle::Parentheses,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            NegativeStyle::LeadingSign => "leading",
+            NegativeStyle::SignAfterSymbol => "after_symbol",
+            NegativeStyle::Parentheses => "parentheses",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            NegativeStyle::LeadingSign => "Sign first (-₱1,000.00)",
+            NegativeStyle::SignAfterSymbol => "Sign after symbol (₱-1,000.00)",
+            NegativeStyle::Parentheses => "Parentheses ((₱1,000.00))",
+        }
+    }
+
+    fn from_key(key: &str) -> NegativeStyle {
+        NegativeStyle::ALL
+            .into_iter()
+            .find(|style| style.key() == key)
+            .unwrap_or_default()
+    }
+}
+
+/// How positive and negative amounts are told apart besides the sign itself.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum SignCue {
+    /// Red for negative, green for signed positive.
+    #[default]
+    Color,
+    /// ▼ before negative and ▲ before signed positive amounts, no colour.
+    Symbols,
+    Both,
+}
+
+impl SignCue {
+    const ALL: [SignCue; 3] = [SignCue::Color, SignCue::Symbols, SignCue::Both];
+
+    fn key(self) -> &'static str {
+        match self {
+            SignCue::Color => "color",
+            SignCue::Symbols => "symbols",
+            SignCue::Both => "both",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SignCue::Color => "Colour",
+            SignCue::Symbols => "Symbols (▲ / ▼)",
+            SignCue::Both => "Colour and symbols",
+        }
+    }
+
+    fn from_key(key: &str) -> SignCue {
+        SignCue::ALL
+            .into_iter()
+            .find(|cue| cue.key() == key)
+            .unwrap_or_default()
+    }
+
+    fn shows_color(self) -> bool {
+        self != SignCue::Symbols
+    }
+
+    fn shows_symbols(self) -> bool {
+        self != SignCue::Color
+    }
+}
+
+/// Colours used for good (positive) and bad (negative) amounts.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum SignPalette {
+    #[default]
+    RedGreen,
+    /// Blue and orange, which stay distinct with red-green colour blindness.
+    ColorBlindSafe,
+}
+
+impl SignPalette {
+    const ALL: [SignPalette; 2] = [SignPalette::RedGreen, SignPalette::ColorBlindSafe];
+
+    fn key(self) -> &'static str {
+        match self {
+            SignPalette::RedGreen => "red_green",
+            SignPalette::ColorBlindSafe => "color_blind_safe",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SignPalette::RedGreen => "Red and green",
+            SignPalette::ColorBlindSafe => "Blue and orange (colour-blind safe)",
+        }
+    }
+
+    fn from_key(key: &str) -> SignPalette {
+        SignPalette::ALL
+            .into_iter()
+            .find(|palette| palette.key() == key)
+            .unwrap_or_default()
+    }
+
+    fn class(self, good: bool) -> &'static str {
+        match (self, good) {
+            (SignPalette::RedGreen, true) => "text-green-600",
+            (SignPalette::RedGreen, false) => "text-red-600",
+            (SignPalette::ColorBlindSafe, true) => "text-[#0072B2]",
+            (SignPalette::ColorBlindSafe, false) => "text-[#D55E00]",
+        }
+    }
+}
+
+/// Increment new expenses are rounded up to, or 0 while rounding up is off.
+fn round_up_setting(settings: Option<&UseStateHandle<AppSettings>>) -> i64 {
+    settings
+        .filter(|s| s.round_up_enabled)
+        .map(|s| s.round_up_increment)
+        .unwrap_or(0)
 }
 
 fn default_settings() -> AppSettings {
     AppSettings {
         currency_code: "PHP".to_string(),
         currency_symbol: "₱".to_string(),
+        auto_save_percent: 0,
+        decimal_places: 2,
+        grouping: GroupingStyle::Western,
+        negative_style: NegativeStyle::LeadingSign,
+        symbol_spacing: false,
+        budget_alerts: true,
+        saving_alerts: true,
+        week_start: 0,
+        month_start_day: 1,
+        request_timeout_secs: DEFAULT_REQUEST_TIMEOUT_SECS,
+        default_page: Page::Dashboard,
+        rounding_mode: RoundingMode::HalfUp,
+        savings_as_spending: true,
+        hide_zero_spend: false,
+        amount_entry: AmountEntry::Decimal,
+        round_up_enabled: false,
+        round_up_increment: 10,
+        monthly_report: true,
+        sign_cue: SignCue::Color,
+        sign_palette: SignPalette::RedGreen,
+        confirm_on_leave: true,
+    }
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        default_settings()
     }
 }
 
@@ -33,7 +437,14 @@ fn load_settings() -> AppSettings {
     if let Some(window) = web_sys::window() {
         if let Ok(Some(storage)) = window.local_storage() {
             if let Ok(Some(raw)) = storage.get_item("settings") {
-                if let Ok(settings) = serde_json::from_str::<AppSettings>(&raw) {
+                if let Ok(mut settings) = serde_json::from_str::<AppSettings>(&raw) {
+                    // Older saves could pair a code with a stale symbol.
+                    if let Some((_, symbol, _)) = currency_entry(&settings.currency_code) {
+                        settings.currency_symbol = symbol.to_string();
+                    }
+                    if !raw.contains("\"symbol_spacing\"") {
+                        settings.symbol_spacing = default_symbol_spacing(&settings.currency_symbol);
+                    }
                     return settings;
                 }
             }
@@ -52,14 +463,223 @@ fn save_settings(settings: &AppSettings) {
     }
 }
 
-fn currency_symbol_for(code: &str) -> &'static str {
-    match code {
-        "USD" => "$",
-        "EUR" => "€",
-        "GBP" => "£",
-        "JPY" => "¥",
-        _ => "₱",
+/// Currencies without a minor unit, which never show decimals.
+fn is_zero_decimal_currency(code: &str) -> bool {
+    matches!(code, "JPY" | "KRW" | "VND")
+}
+
+/// The settings `format_currency` needs, resolved once per render.
+#[derive(Clone, PartialEq)]
+struct CurrencyFormat {
+    /// ISO code from Settings, to tell foreign amounts apart.
+    code: String,
+    symbol: String,
+    decimal_places: u32,
+    grouping: GroupingStyle,
+    negative_style: NegativeStyle,
+    symbol_spacing: bool,
+    entry: AmountEntry,
+    sign_cue: SignCue,
+    sign_palette: SignPalette,
+}
+
+impl CurrencyFormat {
+    fn from_settings(settings: &AppSettings) -> Self {
+        CurrencyFormat {
+            code: settings.currency_code.clone(),
+            symbol: settings.currency_symbol.clone(),
+            decimal_places: if is_zero_decimal_currency(&settings.currency_code) {
+                0
+            } else {
+                settings.decimal_places
+            },
+            grouping: settings.grouping,
+            negative_style: settings.negative_style,
+            symbol_spacing: settings.symbol_spacing,
+            entry: settings.amount_entry,
+            sign_cue: settings.sign_cue,
+            sign_palette: settings.sign_palette,
+        }
+    }
+
+    /// Whether amount fields take whole minor units. Never for a currency
+    /// without decimals, where both modes read the same.
+    fn cents_entry(&self) -> bool {
+        self.entry == AmountEntry::Cents && self.decimal_places > 0
+    }
+
+    /// Fraction digits an amount field accepts.
+    fn entry_places(&self) -> u32 {
+        if self.cents_entry() {
+            0
+        } else {
+            self.decimal_places
+        }
+    }
+
+    /// `amount` as it would be typed into an amount field, for prefilling.
+    fn entry_text(&self, amount: i64) -> String {
+        if self.cents_entry() {
+            (amount as i128 * 10i128.pow(self.decimal_places)).to_string()
+        } else {
+            amount.to_string()
+        }
+    }
+
+    /// Placeholder for an amount field; `decimal` unless it takes cents.
+    fn entry_placeholder(&self, decimal: String) -> String {
+        if self.cents_entry() {
+            format!("Cents ({}), e.g. 1200", self.symbol)
+        } else {
+            decimal
+        }
+    }
+
+    /// Colour class for a good (positive) or bad (negative) amount; `None`
+    /// when the sign cue is symbols only.
+    fn sign_color(&self, good: bool) -> Option<&'static str> {
+        self.sign_cue
+            .shows_color()
+            .then(|| self.sign_palette.class(good))
+    }
+
+    /// The same layout for an amount stored in `code`; the app's own format
+    /// when `code` is absent or already the app currency.
+    fn for_code(&self, code: Option<&str>) -> CurrencyFormat {
+        match code.map(str::trim) {
+            Some(code) if !code.is_empty() && !code.eq_ignore_ascii_case(&self.code) => {
+                let code = code.to_uppercase();
+                CurrencyFormat {
+                    symbol: currency_symbol_for(&code),
+                    decimal_places: if is_zero_decimal_currency(&code) {
+                        0
+                    } else {
+                        self.decimal_places
+                    },
+                    code,
+                    ..self.clone()
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Where weeks and months begin when grouping by period.
+#[derive(Clone, Copy, PartialEq)]
+struct PeriodBounds {
+    /// 0 = Sunday through 6 = Saturday.
+    week_start: u32,
+    /// 1 to 31; a day past the end of a month means its last day.
+    month_start_day: u32,
+}
+
+impl PeriodBounds {
+    fn from_settings(settings: &AppSettings) -> Self {
+        PeriodBounds {
+            week_start: settings.week_start % 7,
+            month_start_day: settings.month_start_day.clamp(1, 31),
+        }
+    }
+}
+
+#[hook]
+fn use_period_bounds() -> PeriodBounds {
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    match settings {
+        Some(settings) => PeriodBounds::from_settings(&settings),
+        None => PeriodBounds::from_settings(&default_settings()),
+    }
+}
+
+#[hook]
+fn use_currency_format() -> CurrencyFormat {
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    match settings {
+        Some(settings) => CurrencyFormat::from_settings(&settings),
+        None => CurrencyFormat::from_settings(&default_settings()),
+    }
+}
+
+/// Supported currencies as (ISO code, symbol, display name).
+const CURRENCIES: [(&str, &str, &str); 30] = [
+    ("PHP", "₱", "Philippine Peso"),
+    ("USD", "$", "US Dollar"),
+    ("EUR", "€", "Euro"),
+    ("GBP", "£", "British Pound"),
+    ("JPY", "¥", "Japanese Yen"),
+    ("CNY", "CN¥", "Chinese Yuan"),
+    ("KRW", "₩", "South Korean Won"),
+    ("INR", "₹", "Indian Rupee"),
+    ("IDR", "Rp", "Indonesian Rupiah"),
+    ("MYR", "RM", "Malaysian Ringgit"),
+    ("SGD", "S$", "Singapore Dollar"),
+    ("THB", "฿", "Thai Baht"),
+    ("VND", "₫", "Vietnamese Dong"),
+    ("HKD", "HK$", "Hong Kong Dollar"),
+    ("TWD", "NT$", "New Taiwan Dollar"),
+    ("AUD", "A$", "Australian Dollar"),
+    ("NZD", "NZ$", "New Zealand Dollar"),
+    ("CAD", "C$", "Canadian Dollar"),
+    ("MXN", "MX$", "Mexican Peso"),
+    ("BRL", "R$", "Brazilian Real"),
+    ("CHF", "CHF", "Swiss Franc"),
+    ("SEK", "kr", "Swedish Krona"),
+    ("NOK", "kr", "Norwegian Krone"),
+    ("DKK", "kr", "Danish Krone"),
+    ("PLN", "zł", "Polish Zloty"),
+    ("RUB", "₽", "Russian Ruble"),
+    ("TRY", "₺", "Turkish Lira"),
+    ("ZAR", "R", "South African Rand"),
+    ("AED", "AED", "UAE Dirham"),
+    ("SAR", "SAR", "Saudi Riyal"),
+];
+
+fn currency_entry(code: &str) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    CURRENCIES.iter().find(|(c, _, _)| *c == code)
+}
+
+/// Unknown codes fall back to the code itself rather than another currency's symbol.
+fn currency_symbol_for(code: &str) -> String {
+    currency_entry(code)
+        .map(|(_, symbol, _)| symbol.to_string())
+        .unwrap_or_else(|| code.to_string())
+}
+
+/// Sentinel code for a user-entered symbol outside the preset list.
+const CUSTOM_CURRENCY: &str = "CUSTOM";
+const CUSTOM_SYMBOL_MAX_CHARS: usize = 5;
+
+fn validate_custom_symbol(raw: &str) -> Result<String, String> {
+    let symbol = raw.trim();
+    if symbol.is_empty() {
+        return Err("Enter a currency symbol.".to_string());
     }
+    if symbol.chars().count() > CUSTOM_SYMBOL_MAX_CHARS {
+        return Err(format!(
+            "Keep the symbol to {} characters or fewer.",
+            CUSTOM_SYMBOL_MAX_CHARS
+        ));
+    }
+    Ok(symbol.to_string())
+}
+
+/// Sign-like symbols (₱, $, R$) sit flush against the number; letter
+/// symbols (CHF, Rp, kr) read better with a space.
+fn default_symbol_spacing(symbol: &str) -> bool {
+    symbol.chars().last().is_some_and(char::is_alphabetic)
+}
+
+fn currency_matches(query: &str) -> Vec<&'static (&'static str, &'static str, &'static str)> {
+    let query = query.trim().to_lowercase();
+    CURRENCIES
+        .iter()
+        .filter(|(code, _, name)| {
+            query.is_empty()
+                || code.to_lowercase().contains(&query)
+                || name.to_lowercase().contains(&query)
+        })
+        .collect()
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -69,8 +689,9 @@ enum AuthStatus {
     Unauthenticated,
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 enum Page {
+    #[default]
     Dashboard,
     Budget,
     Income,
@@ -96,67 +717,252 @@ struct LayoutProps {
 
 #[function_component(Layout)]
 fn layout(props: &LayoutProps) -> Html {
+    let sidebar_collapsed = use_state(load_sidebar_collapsed);
+    let on_toggle_sidebar = {
+        let sidebar_collapsed = sidebar_collapsed.clone();
+        Callback::from(move |_| {
+            let next = !*sidebar_collapsed;
+            save_sidebar_collapsed(next);
+            sidebar_collapsed.set(next);
+        })
+    };
+
+    // Layout outlives page switches, so it keeps each page's last `<main>`
+    // scroll offset for the session. A page seen before is restored there;
+    // a first visit starts at the top.
+    let main_ref = use_node_ref();
+    let scroll_positions = use_mut_ref(HashMap::<Page, i32>::new);
+    let current_page = use_mut_ref(|| props.active_page);
+    let pending_restore = use_mut_ref(|| None::<i32>);
+    let restore_timer = use_mut_ref(|| None::<Interval>);
+    let show_scroll_top = use_state_eq(|| false);
+
+    {
+        let main_ref = main_ref.clone();
+        let scroll_positions = scroll_positions.clone();
+        let current_page = current_page.clone();
+        let pending_restore = pending_restore.clone();
+        let show_scroll_top = show_scroll_top.clone();
+        use_effect_with_deps(
+            move |_| {
+                let listener = main_ref.cast::<web_sys::Element>().map(|main| {
+                    let target = main.clone();
+                    EventListener::new(&main, "scroll", move |_| {
+                        let top = target.scroll_top();
+                        show_scroll_top.set(top > SCROLL_TOP_BUTTON_THRESHOLD);
+                        // ignore clamping while a restore is still waiting for content
+                        if pending_restore.borrow().is_none() {
+                            scroll_positions
+                                .borrow_mut()
+                                .insert(*current_page.borrow(), top);
+                        }
+                    })
+                });
+                move || drop(listener)
+            },
+            (),
+        );
+    }
+
+    {
+        let main_ref = main_ref.clone();
+        let current_page = current_page.clone();
+        use_effect_with_deps(
+            move |page| {
+                *current_page.borrow_mut() = *page;
+                let target = scroll_positions.borrow().get(page).copied().unwrap_or(0);
+                if let Some(main) = main_ref.cast::<web_sys::Element>() {
+                    main.set_scroll_top(target);
+                    // Pages fetch their data on mount, so the list may be too
+                    // short to reach the old offset yet; retry briefly.
+                    if main.scroll_top() < target {
+                        *pending_restore.borrow_mut() = Some(target);
+                        let pending = pending_restore.clone();
+                        let mut attempts = 0;
+                        let interval = Interval::new(SCROLL_RESTORE_RETRY_MS, move || {
+                            let Some(target) = *pending.borrow() else {
+                                return;
+                            };
+                            main.set_scroll_top(target);
+                            attempts += 1;
+                            if main.scroll_top() >= target || attempts >= SCROLL_RESTORE_ATTEMPTS {
+                                *pending.borrow_mut() = None;
+                            }
+                        });
+                        *restore_timer.borrow_mut() = Some(interval);
+                    } else {
+                        *pending_restore.borrow_mut() = None;
+                        *restore_timer.borrow_mut() = None;
+                    }
+                }
+                || ()
+            },
+            props.active_page,
+        );
+    }
+
+    let on_scroll_top = {
+        let main_ref = main_ref.clone();
+        Callback::from(move |_| {
+            if let Some(main) = main_ref.cast::<web_sys::Element>() {
+                let options = web_sys::ScrollToOptions::new();
+                options.set_top(0.0);
+                options.set_behavior(web_sys::ScrollBehavior::Smooth);
+                main.scroll_to_with_scroll_to_options(&options);
+            }
+        })
+    };
+
     html! {
         <div class="flex h-screen bg-background">
-            <div class="hidden md:flex">
-                <Sidebar active_page={props.active_page} on_select={props.on_select.clone()} />
+            <div class="hidden md:flex shrink-0">
+                <Sidebar active_page={props.active_page} on_select={props.on_select.clone()} collapsed={*sidebar_collapsed} on_toggle_collapse={on_toggle_sidebar} />
             </div>
 
             <div class="flex-1 flex flex-col overflow-hidden">
                 <Header />
-                <main class="flex-1 overflow-y-auto">
+                <main ref={main_ref} class="flex-1 overflow-y-auto">
                     { for props.children.iter() }
                 </main>
+                if *show_scroll_top {
+                    <button type="button" onclick={on_scroll_top} aria-label="Scroll to top" title="Scroll to top"
+                        class="fixed bottom-6 right-6 z-40 p-3 bg-card border border-border rounded-full shadow-lg hover:bg-muted transition-colors">
+                        { icon_arrow_up() }
+                    </button>
+                }
             </div>
         </div>
     }
 }
 
+/// How often, and how many times, Layout retries restoring a page's scroll
+/// offset while its content loads (about one second in total).
+const SCROLL_RESTORE_RETRY_MS: u32 = 50;
+const SCROLL_RESTORE_ATTEMPTS: u32 = 20;
+/// `<main>` scroll offset (px) past which the "scroll to top" button shows.
+const SCROLL_TOP_BUTTON_THRESHOLD: i32 = 600;
+
 #[function_component(Header)]
 fn header() -> Html {
     let show_notifications = use_state(|| false);
+    let bell_ref = use_node_ref();
+    let panel_ref = use_node_ref();
     let toggle_notifications = {
         let show_notifications = show_notifications.clone();
         Callback::from(move |_| show_notifications.set(!*show_notifications))
     };
 
-    let notifications = vec![
-        (
-            "Saving Milestone!",
-            "You've reached 30% of your goal.",
-            "Just now",
-        ),
-        (
-            "Goal Accomplished! 🏆",
-            "You've reached 100% of your goal.",
-            "1h ago",
-        ),
-    ];
+    // While open: focus the first item, close on Escape (returning focus to
+    // the bell) or on a click anywhere outside the bell and the panel.
+    {
+        let open = *show_notifications;
+        let show_notifications = show_notifications.clone();
+        let bell_ref = bell_ref.clone();
+        let panel_ref = panel_ref.clone();
+        use_effect_with_deps(
+            move |open| {
+                let mut listeners = Vec::new();
+                if *open {
+                    if let Some(first) = panel_ref
+                        .cast::<web_sys::Element>()
+                        .and_then(|panel| panel.query_selector("[tabindex]").ok().flatten())
+                        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+                    {
+                        let _ = first.focus();
+                    }
+                    if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+                        let on_key = {
+                            let show_notifications = show_notifications.clone();
+                            let bell_ref = bell_ref.clone();
+                            EventListener::new(&document, "keydown", move |event| {
+                                if let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() {
+                                    if event.key() == "Escape" {
+                                        show_notifications.set(false);
+                                        if let Some(bell) = bell_ref.cast::<web_sys::HtmlElement>()
+                                        {
+                                            let _ = bell.focus();
+                                        }
+                                    }
+                                }
+                            })
+                        };
+                        let on_outside_click =
+                            EventListener::new(&document, "mousedown", move |event| {
+                                let target = event
+                                    .target()
+                                    .and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                                let inside = |node: &NodeRef| {
+                                    node.get().is_some_and(|n| n.contains(target.as_ref()))
+                                };
+                                if !inside(&panel_ref) && !inside(&bell_ref) {
+                                    show_notifications.set(false);
+                                }
+                            });
+                        listeners.push(on_key);
+                        listeners.push(on_outside_click);
+                    }
+                }
+                move || drop(listeners)
+            },
+            open,
+        );
+    }
+
+    let nav_status = use_context::<UseStateHandle<NavStatus>>()
+        .map(|status| *status)
+        .unwrap_or_default();
+    let bounds = use_period_bounds();
+    let notifications =
+        header_notifications(&load_saving_goal(), nav_status, &current_month_key(bounds));
+    let read_ids = use_state(load_read_notifications);
+    let has_unread = notifications.iter().any(|n| !read_ids.contains(&n.id));
+    let on_mark_all_read = {
+        let read_ids = read_ids.clone();
+        // only the ids still showing are kept, so the list can't grow forever
+        let ids: Vec<String> = notifications.iter().map(|n| n.id.clone()).collect();
+        Callback::from(move |_| {
+            save_read_notifications(&ids);
+            read_ids.set(ids.clone());
+        })
+    };
 
     html! {
-        <header class="bg-[#D8E1E8] border-b border-border h-16 flex items-center justify-between px-6">
+        <header class="bg-muted border-b border-border h-16 flex items-center justify-between px-6">
             <div class="flex-1"></div>
             <div class="relative flex items-center gap-4">
-                <button class="p-2 hover:bg-secondary rounded-full transition-colors relative" aria-label="Notifications" onclick={toggle_notifications}>
+                <button ref={bell_ref} class="p-2 hover:bg-secondary rounded-full transition-colors relative" aria-label="Notifications" aria-haspopup="true" aria-expanded={(*show_notifications).to_string()} onclick={toggle_notifications}>
                     { icon_bell() }
-                    <span class="absolute top-1 right-1 w-2 h-2 bg-red-500 rounded-full"></span>
+                    if has_unread {
+                        <span class="absolute top-1 right-1 w-2 h-2 bg-red-500 rounded-full"></span>
+                    }
                 </button>
                 {
                     if *show_notifications {
                         html! {
-                            <div class="absolute right-0 top-12 w-80 bg-white border border-border rounded-xl shadow-lg overflow-hidden z-50">
-                                <div class="px-4 py-3 border-b border-border">
-                                    <h4 class="text-sm font-bold text-[#173E63]">{"Notifications"}</h4>
+                            <div ref={panel_ref.clone()} class="absolute right-0 top-12 w-80 bg-popover text-popover-foreground border border-border rounded-xl shadow-lg overflow-hidden z-50" role="region" aria-label="Notifications">
+                                <div class="px-4 py-3 border-b border-border flex items-center justify-between">
+                                    <h4 class="text-sm font-bold text-primary">{"Notifications"}</h4>
+                                    if has_unread {
+                                        <button type="button" tabindex="0" onclick={on_mark_all_read} class="text-xs text-primary hover:underline">{"Mark all as read"}</button>
+                                    }
                                 </div>
                                 <div class="divide-y divide-border">
-                                    { for notifications.iter().map(|(title, message, time)| html! {
-                                        <div class="px-4 py-3 hover:bg-slate-50">
-                                            <div class="flex items-center justify-between">
-                                                <p class="text-sm font-bold text-[#173E63]">{ *title }</p>
-                                                <span class="text-[10px] text-slate-400 font-bold uppercase tracking-tighter">{ *time }</span>
+                                    if notifications.is_empty() {
+                                        <p tabindex="0" class="px-4 py-6 text-xs text-muted-foreground text-center focus:outline-none">{"You're all caught up."}</p>
+                                    }
+                                    { for notifications.iter().map(|n| {
+                                        let unread = !read_ids.contains(&n.id);
+                                        html! {
+                                            <div tabindex="0" class="px-4 py-3 hover:bg-muted focus:bg-muted focus:outline-none">
+                                                <div class="flex items-center justify-between">
+                                                    <p class={classes!("text-sm", "text-primary", unread.then_some("font-bold"))}>{ n.title.clone() }</p>
+                                                    if unread {
+                                                        <span class="w-2 h-2 bg-red-500 rounded-full" aria-label="Unread"></span>
+                                                    }
+                                                </div>
+                                                <p class="text-xs text-muted-foreground mt-1">{ n.message.clone() }</p>
                                             </div>
-                                            <p class="text-xs text-slate-500 mt-1">{ *message }</p>
-                                        </div>
+                                        }
                                     }) }
                                 </div>
                             </div>
@@ -170,32 +976,315 @@ fn header() -> Html {
     }
 }
 
+/// An entry in the header's notification panel. `id` is stable for as long
+/// as the underlying condition holds, so its read state survives reloads.
+struct HeaderNotification {
+    id: String,
+    title: String,
+    message: String,
+}
+
+/// Saving goal share (%) that earns a milestone notification.
+const GOAL_MILESTONES: [i64; 3] = [25, 50, 75];
+
+/// Notifications derived from the saving goal and the sidebar badge state.
+/// Ids include what they describe (goal target, month, budget count), so a
+/// new goal or another overspent budget shows up unread again.
+fn header_notifications(
+    goal: &SavingGoalState,
+    status: NavStatus,
+    month: &str,
+) -> Vec<HeaderNotification> {
+    let mut notifications = Vec::new();
+    if status.overspent_budgets > 0 {
+        let n = status.overspent_budgets;
+        notifications.push(HeaderNotification {
+            id: format!("budgets-overspent:{}:{}", month, n),
+            title: "Over budget".to_string(),
+            message: format!(
+                "{} {} past {} limit this month.",
+                n,
+                if n == 1 { "budget is" } else { "budgets are" },
+                if n == 1 { "its" } else { "their" }
+            ),
+        });
+    }
+    if goal.target_amount > 0 {
+        let saved: i64 = goal.contributions.iter().map(|c| c.amount).sum();
+        let goal_id = format!("{}:{}", goal.target_amount, goal.title);
+        if goal_completed(goal) {
+            notifications.push(HeaderNotification {
+                id: format!("goal-completed:{}", goal_id),
+                title: "Goal Accomplished! 🏆".to_string(),
+                message: "You've reached 100% of your goal.".to_string(),
+            });
+        } else if let Some(pct) = GOAL_MILESTONES
+            .iter()
+            .rev()
+            .find(|&&pct| saved * 100 >= goal.target_amount * pct)
+        {
+            notifications.push(HeaderNotification {
+                id: format!("goal-milestone:{}:{}", pct, goal_id),
+                title: "Saving Milestone!".to_string(),
+                message: format!("You've reached {}% of your goal.", pct),
+            });
+        }
+    }
+    notifications
+}
+
+/// Ids of notifications the user has marked read.
+fn load_read_notifications() -> Vec<String> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item("notifications_read").ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn save_read_notifications(ids: &[String]) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        if let Ok(raw) = serde_json::to_string(ids) {
+            let _ = storage.set_item("notifications_read", &raw);
+        }
+    }
+}
+
 struct NavItem {
     label: &'static str,
     page: Page,
     icon: fn() -> Html,
 }
 
-#[derive(Properties, PartialEq)]
-struct SidebarProps {
-    active_page: Page,
-    on_select: Callback<Page>,
+/// Badge state for the sidebar nav, provided by `App` and published by the
+/// pages that compute it.
+#[derive(Clone, Copy, PartialEq, Default)]
+struct NavStatus {
+    /// Budgets spent past their limit; badges "Budget" when non-zero.
+    overspent_budgets: usize,
+    /// Badges "Saving Goal" once its target is reached.
+    goal_completed: bool,
 }
 
-#[function_component(Sidebar)]
-fn sidebar(props: &SidebarProps) -> Html {
-    let nav_items = vec![
-        NavItem {
-            label: "Dashboard",
-            page: Page::Dashboard,
-            icon: icon_layout_grid,
-        },
-        NavItem {
-            label: "Budget",
-            page: Page::Budget,
-            icon: icon_wallet,
-        },
-        NavItem {
+/// Categories suggested in category fields, provided by `App` and rendered
+/// once as the `CATEGORY_LIST_ID` datalist.
+#[derive(Clone, PartialEq)]
+struct KnownCategories(UseStateHandle<Vec<String>>);
+
+impl KnownCategories {
+    /// Adds `category` unless one differing only in case or spacing is
+    /// already known.
+    fn register(&self, category: &str) {
+        let key = category_key(category);
+        if key.is_empty() || self.0.iter().any(|known| category_key(known) == key) {
+            return;
+        }
+        let mut next = (*self.0).clone();
+        next.push(normalize_category(category));
+        next.sort_by_key(|c| c.to_lowercase());
+        self.0.set(next);
+    }
+}
+
+/// `id` of the datalist holding `KnownCategories`, for `list` attributes.
+const CATEGORY_LIST_ID: &str = "known-categories";
+
+/// Fetches `/api/categories`: the distinct categories the server knows.
+async fn fetch_categories() -> Option<Vec<String>> {
+    let url = format!("{}/api/categories", API_BASE_URL);
+    let resp = send_api(api_request(Request::get(&url)).build(), None)
+        .await
+        .ok()?;
+    if !resp.ok() {
+        return None;
+    }
+    resp.json::<Vec<String>>().await.ok()
+}
+
+/// Distinct categories from the cached Dashboard rows and the budgets, for
+/// when the server can't be reached.
+fn local_categories() -> Vec<String> {
+    let cached = load_dashboard_cache()
+        .map(|cache| cache.transactions)
+        .unwrap_or_default();
+    let budgets = load_budgets();
+    distinct_categories(
+        cached
+            .iter()
+            .map(|tx| tx.category.as_str())
+            .chain(budgets.iter().map(|b| b.category.as_str())),
+    )
+}
+
+/// Categories merged as `category_key` does, first spelling kept, sorted.
+fn distinct_categories<'a>(categories: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut list = categories
+        .into_iter()
+        .map(normalize_category)
+        .filter(|c| !c.is_empty() && seen.insert(c.to_lowercase()))
+        .collect::<Vec<_>>();
+    list.sort_by_key(|c| c.to_lowercase());
+    list
+}
+
+/// Names of forms on the current page holding input that has not been saved,
+/// provided by `App` and checked before navigating away.
+#[derive(Clone, Default)]
+struct UnsavedForms(Rc<RefCell<HashSet<&'static str>>>);
+
+impl PartialEq for UnsavedForms {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Registers form `name` as unsaved while `dirty` holds. Pages derive
+/// `dirty` from the fields, so a save or clear that empties them resets it;
+/// leaving the page drops the entry.
+#[hook]
+fn use_unsaved_form(name: &'static str, dirty: bool) {
+    let unsaved = use_context::<UnsavedForms>();
+    use_effect_with_deps(
+        move |&dirty| {
+            if let Some(unsaved) = &unsaved {
+                if dirty {
+                    unsaved.0.borrow_mut().insert(name);
+                }
+            }
+            move || {
+                if let Some(unsaved) = unsaved {
+                    unsaved.0.borrow_mut().remove(name);
+                }
+            }
+        },
+        dirty,
+    );
+}
+
+/// Pushes a page's freshly computed badge values to the sidebar when they
+/// change. `None` leaves that value as it is, e.g. while data is loading.
+#[hook]
+fn use_publish_nav_status(overspent_budgets: Option<usize>, goal_completed: Option<bool>) {
+    let nav_status = use_context::<UseStateHandle<NavStatus>>();
+    use_effect_with_deps(
+        move |&(overspent_budgets, goal_completed)| {
+            if let Some(nav_status) = nav_status {
+                let mut next = *nav_status;
+                if let Some(count) = overspent_budgets {
+                    next.overspent_budgets = count;
+                }
+                if let Some(completed) = goal_completed {
+                    next.goal_completed = completed;
+                }
+                nav_status.set(next);
+            }
+            || ()
+        },
+        (overspent_budgets, goal_completed),
+    );
+}
+
+#[derive(Properties, PartialEq)]
+struct SidebarProps {
+    active_page: Page,
+    on_select: Callback<Page>,
+    collapsed: bool,
+    on_toggle_collapse: Callback<()>,
+}
+
+fn load_sidebar_collapsed() -> bool {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(Some(raw)) = storage.get_item("sidebar_collapsed") {
+                return raw == "true";
+            }
+        }
+    }
+    false
+}
+
+fn save_sidebar_collapsed(collapsed: bool) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            let _ = storage.set_item(
+                "sidebar_collapsed",
+                if collapsed { "true" } else { "false" },
+            );
+        }
+    }
+}
+
+/// Keyboard focus ring for controls on the light sidebar background.
+const SIDEBAR_FOCUS_RING: &str = "focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 focus-visible:ring-offset-muted";
+/// Focus ring inside the dark nav panel. It is drawn outside the button, so it
+/// stays distinct from the active item's fill.
+const NAV_FOCUS_RING: &str = "focus:outline-none focus-visible:ring-2 focus-visible:ring-sidebar-foreground focus-visible:ring-offset-2 focus-visible:ring-offset-sidebar";
+
+/// Arrow Up/Down and Home/End move focus between nav items, wrapping at the
+/// ends. Tab still walks every item in order, and Enter/Space activate the
+/// focused button natively.
+fn on_nav_keydown(e: KeyboardEvent) {
+    let step: isize = match e.key().as_str() {
+        "ArrowDown" => 1,
+        "ArrowUp" => -1,
+        "Home" | "End" => 0,
+        _ => return,
+    };
+    let Some(nav) = e
+        .current_target()
+        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+    else {
+        return;
+    };
+    let Ok(buttons) = nav.query_selector_all("button") else {
+        return;
+    };
+    let count = buttons.length() as isize;
+    if count == 0 {
+        return;
+    }
+    let active = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element());
+    let current = (0..count).find(|&i| {
+        buttons
+            .item(i as u32)
+            .zip(active.as_ref())
+            .is_some_and(|(button, active)| button.is_same_node(Some(active)))
+    });
+    let next = match (e.key().as_str(), current) {
+        ("Home", _) => 0,
+        ("End", _) => count - 1,
+        (_, Some(i)) => (i + step).rem_euclid(count),
+        (_, None) => 0,
+    };
+    e.prevent_default();
+    if let Some(button) = buttons
+        .item(next as u32)
+        .and_then(|n| n.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let _ = button.focus();
+    }
+}
+
+#[function_component(Sidebar)]
+fn sidebar(props: &SidebarProps) -> Html {
+    let nav_status = use_context::<UseStateHandle<NavStatus>>()
+        .map(|status| *status)
+        .unwrap_or_default();
+    let nav_items = [
+        NavItem {
+            label: "Dashboard",
+            page: Page::Dashboard,
+            icon: icon_layout_grid,
+        },
+        NavItem {
+            label: "Budget",
+            page: Page::Budget,
+            icon: icon_wallet,
+        },
+        NavItem {
             label: "Income Tracker",
             page: Page::Income,
             icon: icon_trending_up,
@@ -225,53 +1314,97 @@ fn sidebar(props: &SidebarProps) -> Html {
     let on_logout = Callback::from(move |_| {
         spawn_local(async move {
             let url = format!("{}/api/auth/logout", API_BASE_URL);
-            let _ = Request::post(&url)
+            let request = Request::post(&url)
                 .credentials(RequestCredentials::Include)
-                .send()
-                .await;
+                .build();
+            let _ = send_api(request, None).await;
+            // needs the token to find the user's key, so runs before removing it
+            clear_summary_cache();
+            token_store::clear();
             if let Some(window) = web_sys::window() {
-                if let Ok(Some(storage)) = window.local_storage() {
-                    let _ = storage.remove_item("access_token");
-                }
                 let _ = window.location().reload();
             }
         });
     });
 
+    let collapsed = props.collapsed;
+    let on_toggle_collapse = {
+        let on_toggle_collapse = props.on_toggle_collapse.clone();
+        Callback::from(move |_| on_toggle_collapse.emit(()))
+    };
+    let on_logo = {
+        let on_select = props.on_select.clone();
+        Callback::from(move |_| on_select.emit(Page::Dashboard))
+    };
+
     html! {
-        <div class="w-[220px] h-screen bg-[#D8E1E8] p-4 flex flex-col">
-            <div class="flex items-center gap-3 px-2 mb-8">
-                <div class="w-12 h-12 bg-[#173E63] rounded-full flex items-center justify-center">
-                    <img src="PondoBro.png" alt="Logo" class="w-full h-full object-cover rounded-full" />
-                </div>
-                <span class="text-[#173E63] text-2xl font-black tracking-tight">{"PondoBro"}</span>
+        <div class={classes!("h-screen", "bg-muted", "p-4", "flex", "flex-col", "transition-all", if collapsed { "w-[84px]" } else { "w-[220px]" })}>
+            <div class={classes!("flex", "items-center", "gap-3", "mb-4", if collapsed { "justify-center" } else { "px-2" })}>
+                <button type="button" onclick={on_logo} aria-label="PondoBro, go to Dashboard" class={classes!("w-12", "h-12", "shrink-0", "bg-primary", "rounded-full", "flex", "items-center", "justify-center", SIDEBAR_FOCUS_RING)}>
+                    <img src="PondoBro.png" alt="" class="w-full h-full object-cover rounded-full" />
+                </button>
+                if !collapsed {
+                    <span class="text-primary text-2xl font-black tracking-tight">{"PondoBro"}</span>
+                }
             </div>
 
-            <div class="flex-1 bg-[#173E63] rounded-[24px] flex flex-col py-6 px-3 shadow-lg">
-                <nav class="flex-1 space-y-2">
+            <button
+                type="button"
+                onclick={on_toggle_collapse}
+                class={classes!("flex", "items-center", "mb-4", "p-2", "rounded-xl", "hover:bg-white/40", "transition-colors", SIDEBAR_FOCUS_RING, if collapsed { "self-center" } else { "self-end" })}
+                aria-label={if collapsed { "Expand sidebar" } else { "Collapse sidebar" }}
+                title={if collapsed { "Expand sidebar" } else { "Collapse sidebar" }}
+            >
+                <span class={classes!("inline-flex", "transition-transform", collapsed.then_some("rotate-180"))}>{ icon_chevron_left() }</span>
+            </button>
+
+            <div class={classes!("flex-1", "bg-sidebar", "rounded-[24px]", "flex", "flex-col", "py-6", "shadow-lg", if collapsed { "px-2" } else { "px-3" })}>
+                <nav class="flex-1 space-y-2" aria-label="Main" onkeydown={Callback::from(on_nav_keydown)}>
                     { for nav_items.iter().map(|item| {
                         let is_active = item.page == props.active_page;
                         let class_name = if is_active {
-                            "flex items-center gap-3 px-4 py-3 rounded-xl transition-all text-[13px] font-medium bg-[#B2CBDE] text-[#173E63] w-full"
+                            "flex items-center gap-3 px-4 py-3 rounded-xl transition-all text-[13px] font-medium bg-sidebar-primary text-sidebar-primary-foreground w-full"
                         } else {
-                            "flex items-center gap-3 px-4 py-3 rounded-xl transition-all text-[13px] font-medium text-slate-300 hover:bg-white/5 hover:text-white w-full"
+                            "flex items-center gap-3 px-4 py-3 rounded-xl transition-all text-[13px] font-medium text-sidebar-foreground/80 hover:bg-sidebar-accent hover:text-sidebar-accent-foreground w-full"
                         };
                         let on_select = props.on_select.clone();
                         let page = item.page;
+                        let badge = match item.page {
+                            Page::Budget if nav_status.overspent_budgets > 0 => Some((
+                                nav_status.overspent_budgets.to_string(),
+                                format!("{} over budget", nav_status.overspent_budgets),
+                            )),
+                            Page::Savings if nav_status.goal_completed => {
+                                Some(("✓".to_string(), "Goal reached".to_string()))
+                            }
+                            _ => None,
+                        };
 
                         html! {
-                            <button type="button" class={class_name} onclick={Callback::from(move |_| on_select.emit(page))}>
-                                <span class="shrink-0">{ (item.icon)() }</span>
-                                <span class="truncate whitespace-nowrap text-left">{ item.label }</span>
+                            <button type="button" class={classes!(class_name, NAV_FOCUS_RING, collapsed.then_some("justify-center"))} title={collapsed.then_some(item.label)} aria-label={item.label} aria-current={is_active.then_some("page")} onclick={Callback::from(move |_| on_select.emit(page))}>
+                                <span class="relative shrink-0">
+                                    { (item.icon)() }
+                                    if let (true, Some((text, title))) = (collapsed, &badge) {
+                                        <span class="absolute -top-1.5 -right-1.5 min-w-[16px] h-4 px-1 rounded-full bg-red-500 text-white text-[10px] font-bold leading-4 text-center" title={title.clone()}>{ text }</span>
+                                    }
+                                </span>
+                                if !collapsed {
+                                    <span class="truncate whitespace-nowrap text-left">{ item.label }</span>
+                                    if let Some((text, title)) = &badge {
+                                        <span class="ml-auto min-w-[20px] h-5 px-1.5 rounded-full bg-red-500 text-white text-[11px] font-bold leading-5 text-center" title={title.clone()}>{ text }</span>
+                                    }
+                                }
                             </button>
                         }
                     }) }
                 </nav>
 
                 <div class="mt-auto pt-4">
-                    <button onclick={on_logout} class="flex items-center gap-3 w-full px-4 py-3 rounded-xl hover:bg-white/10 transition-colors text-[13px] font-medium text-slate-300">
+                    <button type="button" onclick={on_logout} title={collapsed.then_some("Log Out")} aria-label="Log Out" class={classes!("flex", "items-center", "gap-3", "w-full", "px-4", "py-3", "rounded-xl", "hover:bg-sidebar-accent", "transition-colors", "text-[13px]", "font-medium", "text-sidebar-foreground/80", NAV_FOCUS_RING, collapsed.then_some("justify-center"))}>
                         { icon_log_out() }
-                        <span>{"Log Out"}</span>
+                        if !collapsed {
+                            <span>{"Log Out"}</span>
+                        }
                     </button>
                 </div>
             </div>
@@ -279,113 +1412,329 @@ fn sidebar(props: &SidebarProps) -> Html {
     }
 }
 
+/// Hold on the single in-flight save behind a form's flag. Dropping it frees
+/// the form, so every way a save can end re-enables it.
+struct SubmitClaim(Rc<RefCell<bool>>);
+
+impl Drop for SubmitClaim {
+    fn drop(&mut self) {
+        self.0.replace(false);
+    }
+}
+
+/// Starts a save: claims `in_flight` and hands the claim to `save`, which
+/// sends the POST and keeps the claim until the save has finished. While an
+/// earlier claim is held this returns `false` without calling `save`, so a
+/// second click never sends another POST.
+fn start_submit(in_flight: &Rc<RefCell<bool>>, save: impl FnOnce(SubmitClaim)) -> bool {
+    if in_flight.replace(true) {
+        return false;
+    }
+    save(SubmitClaim(in_flight.clone()));
+    true
+}
+
+#[derive(Properties, PartialEq)]
+struct DashboardPageProps {
+    /// Set by the `n` shortcut; the page opens its add form and acknowledges.
+    open_add: bool,
+    on_open_add_handled: Callback<()>,
+    /// Transaction to copy into the add form, e.g. from another page's
+    /// "Duplicate" action; acknowledged like `open_add`.
+    draft: Option<Transaction>,
+    on_draft_handled: Callback<()>,
+    on_navigate: Callback<Page>,
+    /// Fired once loading shows a brand-new account, to offer onboarding.
+    on_first_run: Callback<()>,
+}
+
 #[function_component(DashboardPage)]
-fn dashboard_page() -> Html {
-    let transactions = use_state(|| Vec::<Transaction>::new());
+fn dashboard_page(props: &DashboardPageProps) -> Html {
+    let transactions = use_state(Vec::<Transaction>::new);
     let loading = use_state(|| true);
+    // older pages are still on the server; fetched as the list scrolls
+    let has_more = use_state(|| false);
+    let loading_more = use_state(|| false);
     let show_add = use_state(|| false);
+    let duplicate_pending = use_state(|| false);
+    let add_date_ref = use_node_ref();
+    let add_toggle_ref = use_node_ref();
+
+    {
+        let show_add = show_add.clone();
+        let add_date_ref = add_date_ref.clone();
+        let on_open_add_handled = props.on_open_add_handled.clone();
+        use_effect_with_deps(
+            move |open_add| {
+                if *open_add {
+                    if *show_add {
+                        // already open, so the effect below won't fire
+                        focus_node(&add_date_ref);
+                    }
+                    show_add.set(true);
+                    on_open_add_handled.emit(());
+                }
+                || ()
+            },
+            props.open_add,
+        );
+    }
+
+    // opening the form moves focus to its first field and closing it hands
+    // focus back to the toggle; skipped on mount so the page doesn't grab it
+    {
+        let add_date_ref = add_date_ref.clone();
+        let add_toggle_ref = add_toggle_ref.clone();
+        let mounted = use_mut_ref(|| false);
+        use_effect_with_deps(
+            move |open| {
+                if std::mem::replace(&mut *mounted.borrow_mut(), true) {
+                    focus_node(if *open {
+                        &add_date_ref
+                    } else {
+                        &add_toggle_ref
+                    });
+                }
+                || ()
+            },
+            *show_add,
+        );
+    }
 
     let settings = use_context::<UseStateHandle<AppSettings>>();
-    let currency_symbol = settings
+    let currency = use_currency_format();
+    let bounds = use_period_bounds();
+
+    let auto_save_percent = settings.as_ref().map(|s| s.auto_save_percent).unwrap_or(0);
+    let round_up_increment = round_up_setting(settings.as_ref());
+    let known_categories = use_context::<KnownCategories>();
+    let monthly_report_enabled = settings.as_ref().is_none_or(|s| s.monthly_report);
+    let rounding = settings
         .as_ref()
-        .map(|s| s.currency_symbol.clone())
-        .unwrap_or_else(|| "₱".to_string());
+        .map(|s| s.rounding_mode)
+        .unwrap_or_default();
+    let budget_alerts = settings.as_ref().is_none_or(|s| s.budget_alerts);
+    let savings_as_spending = settings.as_ref().is_none_or(|s| s.savings_as_spending);
+    let hide_zero_spend = settings.as_ref().is_some_and(|s| s.hide_zero_spend);
+    let auto_save_offer = use_state(|| None::<Transaction>);
 
     let current_goal = load_saving_goal();
 
-    let form_date = use_state(|| "".to_string());
+    // date inputs default to, and are capped at, today
+    let today = today_key();
+    let form_date = use_state(today_key);
     let form_description = use_state(|| "".to_string());
     let form_category = use_state(|| "".to_string());
     let form_amount = use_state(|| "".to_string());
+    let form_note = use_state(|| "".to_string());
+    let form_receipt = use_state(|| "".to_string());
+    let form_tax = use_state(|| false);
+    // direction applied to an unsigned amount; new entries default to expenses
+    let form_income = use_state(|| false);
+    let accounts = use_state(load_accounts);
+    let form_account = {
+        let first = accounts.first().cloned().unwrap_or_default();
+        use_state(move || first)
+    };
+    let account_currencies = use_state(load_account_currencies);
+    // pre-filled from the chosen account; still editable per transaction
+    let form_currency = {
+        let code = account_currency(&account_currencies, &form_account, &currency.code);
+        use_state(move || code)
+    };
+    let account_filter = use_state(|| None::<String>);
+    let review_only = use_state(|| false);
+    let min_amount = use_state(|| "".to_string());
+    let max_amount = use_state(|| "".to_string());
+    use_unsaved_form(
+        "add-transaction",
+        *show_add
+            && [&form_description, &form_amount, &form_note, &form_receipt]
+                .iter()
+                .any(|field| !field.trim().is_empty()),
+    );
+    let search_text = use_state(|| "".to_string());
+    // lowercased and applied after `SEARCH_DEBOUNCE_MS` without typing
+    let search_query = use_state(|| "".to_string());
+    let search_timer = use_mut_ref(|| None::<Timeout>);
+    let show_transfer = use_state(|| false);
+    let transfer_date = use_state(today_key);
+    let transfer_from = {
+        let first = accounts.first().cloned().unwrap_or_default();
+        use_state(move || first)
+    };
+    let transfer_to = {
+        let second = accounts.get(1).cloned().unwrap_or_default();
+        use_state(move || second)
+    };
+    let transfer_amount = use_state(|| "".to_string());
+    let transfer_error = use_state(|| None::<String>);
+    let transferring = use_state(|| false);
     let form_error = use_state(|| None::<String>);
     let form_success = use_state(|| None::<String>);
     let saving = use_state(|| false);
+    let selected_tx = use_state(|| None::<Transaction>);
+    let last_created = use_state(|| None::<UndoEntry>);
+    // dropping the timeout (on unmount or a newer save) cancels the undo window
+    let undo_timer = use_mut_ref(|| None::<Timeout>);
+    // `saving` only disables the button after a re-render; this flag closes
+    // the gap so a rapid second click can't send another POST
+    let submitting = use_mut_ref(|| false);
+
+    let on_duplicate = {
+        let form_date = form_date.clone();
+        let form_description = form_description.clone();
+        let form_category = form_category.clone();
+        let form_amount = form_amount.clone();
+        let form_income = form_income.clone();
+        let form_account = form_account.clone();
+        let form_currency = form_currency.clone();
+        let form_tax = form_tax.clone();
+        let form_error = form_error.clone();
+        let form_success = form_success.clone();
+        let show_add = show_add.clone();
+        let selected_tx = selected_tx.clone();
+        let currency = currency.clone();
+        Callback::from(move |tx: Transaction| {
+            form_date.set(today_key());
+            form_description.set(tx.description.clone());
+            form_category.set(tx.category.clone());
+            // the toggle carries the sign, so a duplicated expense stays an
+            // expense; the text is written for the row's currency, which the
+            // form parses it in
+            form_amount.set(
+                currency
+                    .for_code(tx.currency.as_deref())
+                    .entry_text(tx.amount.abs()),
+            );
+            form_income.set(tx.amount > 0);
+            form_account.set(account_name(&tx).to_string());
+            form_currency.set(tx.currency.clone().unwrap_or_else(|| currency.code.clone()));
+            form_tax.set(tx.tax);
+            form_error.set(None);
+            form_success.set(None);
+            selected_tx.set(None);
+            show_add.set(true);
+        })
+    };
+
+    {
+        let on_duplicate = on_duplicate.clone();
+        let on_draft_handled = props.on_draft_handled.clone();
+        use_effect_with_deps(
+            move |draft| {
+                if let Some(tx) = draft {
+                    on_duplicate.emit(tx.clone());
+                    on_draft_handled.emit(());
+                }
+                || ()
+            },
+            props.draft.clone(),
+        );
+    }
 
     let budgets = use_state(load_budgets);
 
-    // fetch transactions and dashboard summary
-    let total_income = use_state(|| 0i64);
-    let total_expenses = use_state(|| 0i64);
-    let balance = use_state(|| 0i64);
+    // fetch transactions; the summary comes from the shared cache
+    let summary = use_summary();
+    let refresh_summary = summary.refresh.clone();
+    let cache_status = use_state(|| None::<CacheStatus>);
+    let scope = use_request_scope();
+    let load_error = use_state(|| None::<ApiError>);
+    let load_attempt = use_state(|| 0u32);
+    let refreshed_at = use_state(|| None::<f64>);
 
+    let page_scope = scope.clone();
     {
         let transactions = transactions.clone();
         let loading = loading.clone();
-        let total_income = total_income.clone();
-        let total_expenses = total_expenses.clone();
-        let balance = balance.clone();
+        let has_more = has_more.clone();
+        let cache_status = cache_status.clone();
+        let load_error = load_error.clone();
+        let refreshed_at = refreshed_at.clone();
 
         use_effect_with_deps(
             move |_| {
-                spawn_local(async move {
-                    let url = format!("{}/api/transactions", API_BASE_URL);
-                    {
-                        let mut req = Request::get(&url).credentials(RequestCredentials::Include);
-                        if let Some(window) = web_sys::window() {
-                            if let Ok(Some(storage)) = window.local_storage() {
-                                if let Ok(token_opt) = storage.get_item("access_token") {
-                                    if let Some(token) = token_opt {
-                                        req = req
-                                            .header("Authorization", &format!("Bearer {}", token));
-                                    }
-                                }
-                            }
-                        }
-
-                        if let Ok(resp) = req.send().await {
-                            if resp.ok() {
-                                if let Ok(list) = resp.json::<Vec<Transaction>>().await {
-                                    transactions.set(list);
-                                }
-                            }
-                        }
-                    }
-
-                    // fetch dashboard summary
-                    let summary_url = format!("{}/api/dashboard/summary", API_BASE_URL);
-                    {
-                        let mut req2 =
-                            Request::get(&summary_url).credentials(RequestCredentials::Include);
-                        if let Some(window) = web_sys::window() {
-                            if let Ok(Some(storage)) = window.local_storage() {
-                                if let Ok(token_opt) = storage.get_item("access_token") {
-                                    if let Some(token) = token_opt {
-                                        req2 = req2
-                                            .header("Authorization", &format!("Bearer {}", token));
-                                    }
-                                }
-                            }
-                        }
+                load_error.set(None);
+                // stale-while-revalidate: paint the cached copy, then refetch
+                let cached = load_dashboard_cache();
+                if let Some(cache) = &cached {
+                    transactions.set(cache.transactions.clone());
+                    loading.set(false);
+                    cache_status.set(Some(CacheStatus::Refreshing));
+                } else {
+                    loading.set(true);
+                }
 
-                        if let Ok(resp2) = req2.send().await {
-                            if resp2.ok() {
-                                if let Ok(json) = resp2.json::<serde_json::Value>().await {
-                                    if let Some(v) =
-                                        json.get("total_income").and_then(|x| x.as_i64())
-                                    {
-                                        total_income.set(v);
-                                    }
-                                    if let Some(v) =
-                                        json.get("total_expenses").and_then(|x| x.as_i64())
-                                    {
-                                        total_expenses.set(v);
-                                    }
-                                    if let Some(v) = json.get("balance").and_then(|x| x.as_i64()) {
-                                        balance.set(v);
-                                    }
-                                }
+                spawn_local(async move {
+                    match fetch_recent_transactions(bounds, &scope).await {
+                        Ok((list, more)) => {
+                            transactions.set(list.clone());
+                            has_more.set(more);
+                            save_dashboard_cache(&DashboardCache { transactions: list });
+                            refreshed_at.set(Some(js_sys::Date::now()));
+                            if cached.is_some() {
+                                cache_status.set(Some(CacheStatus::Updated));
                             }
                         }
+                        Err(ApiError::Cancelled) => return,
+                        Err(_) if cached.is_some() => cache_status.set(Some(CacheStatus::Offline)),
+                        Err(err) => load_error.set(Some(err)),
                     }
-
                     loading.set(false);
                 });
                 || ()
             },
-            (),
+            *load_attempt,
         );
     }
 
+    let on_refresh = {
+        let load_attempt = load_attempt.clone();
+        let refresh_summary = refresh_summary.clone();
+        Callback::from(move |_| {
+            load_attempt.set(*load_attempt + 1);
+            refresh_summary.emit(());
+        })
+    };
+
+    let on_load_more = {
+        let transactions = transactions.clone();
+        let has_more = has_more.clone();
+        let loading_more = loading_more.clone();
+        Callback::from(move |_: ()| {
+            if !*has_more || *loading_more {
+                return;
+            }
+            loading_more.set(true);
+            let transactions = transactions.clone();
+            let has_more = has_more.clone();
+            let loading_more = loading_more.clone();
+            let scope = page_scope.clone();
+            spawn_local(async move {
+                let offset = transactions.len().saturating_sub(TX_PAGE_OVERLAP);
+                match fetch_transaction_page(offset, TX_PAGE_SIZE, Some(&scope)).await {
+                    Ok(page) => {
+                        let next = if page.full_history {
+                            page.rows
+                        } else {
+                            merge_transaction_page(&transactions, page.rows)
+                        };
+                        has_more.set(!page.complete && next.len() > transactions.len());
+                        save_dashboard_cache(&DashboardCache {
+                            transactions: next.clone(),
+                        });
+                        transactions.set(next);
+                    }
+                    Err(ApiError::Cancelled) => return,
+                    // the button stays up, so the user can try again
+                    Err(_) => {}
+                }
+                loading_more.set(false);
+            });
+        })
+    };
+
     let on_toggle_add = {
         let show_add = show_add.clone();
         let form_error = form_error.clone();
@@ -397,38 +1746,75 @@ fn dashboard_page() -> Html {
         })
     };
 
+    let on_empty_add = {
+        let show_add = show_add.clone();
+        Callback::from(move |_| show_add.set(true))
+    };
+
+    let navigate_to = |page: Page| {
+        let on_navigate = props.on_navigate.clone();
+        Callback::from(move |_: MouseEvent| on_navigate.emit(page))
+    };
+
     let on_submit = {
         let form_date = form_date.clone();
         let form_description = form_description.clone();
         let form_category = form_category.clone();
         let form_amount = form_amount.clone();
+        let form_income = form_income.clone();
+        let form_note = form_note.clone();
+        let form_receipt = form_receipt.clone();
+        let form_tax = form_tax.clone();
+        let form_account = form_account.clone();
+        let form_currency = form_currency.clone();
         let transactions = transactions.clone();
         let show_add = show_add.clone();
-        let total_income = total_income.clone();
-        let total_expenses = total_expenses.clone();
-        let balance = balance.clone();
+        let refresh_summary = refresh_summary.clone();
         let form_error = form_error.clone();
         let form_success = form_success.clone();
         let saving = saving.clone();
+        let last_created = last_created.clone();
+        let undo_timer = undo_timer.clone();
+        let auto_save_offer = auto_save_offer.clone();
+        let submitting = submitting.clone();
+        let duplicate_pending = duplicate_pending.clone();
+        let known_categories = known_categories.clone();
+
+        let currency = currency.clone();
+        Callback::from(move |allow_duplicate: bool| {
+            if *submitting.borrow() {
+                return;
+            }
 
-        Callback::from(move |_| {
             let form_date = form_date.clone();
             let form_description = form_description.clone();
             let form_category = form_category.clone();
             let form_amount = form_amount.clone();
+            let form_note = form_note.clone();
+            let form_receipt = form_receipt.clone();
+            let form_tax = form_tax.clone();
             let transactions = transactions.clone();
             let show_add = show_add.clone();
-            let total_income = total_income.clone();
-            let total_expenses = total_expenses.clone();
-            let balance = balance.clone();
+            let refresh_summary = refresh_summary.clone();
             let form_error = form_error.clone();
             let form_success = form_success.clone();
             let saving = saving.clone();
+            let last_created = last_created.clone();
+            let undo_timer = undo_timer.clone();
+            let auto_save_offer = auto_save_offer.clone();
+            let submitting = submitting.clone();
+            let known_categories = known_categories.clone();
 
             let date_val = form_date.trim().to_string();
             let desc_val = form_description.trim().to_string();
-            let category_val = form_category.trim().to_string();
+            let category_val = normalize_category(&form_category);
             let amount_val = form_amount.trim().to_string();
+            let note_val = optional_text(&form_note);
+            let receipt_val = optional_text(&form_receipt);
+            let account_val = (*form_account).clone();
+            let currency_val = stored_currency(&form_currency, &currency.code);
+            let tax_val = *form_tax;
+            let income_val = *form_income;
 
             if date_val.is_empty()
                 || desc_val.is_empty()
@@ -439,117 +1825,373 @@ fn dashboard_page() -> Html {
                 return;
             }
 
-            let amount = amount_val.parse::<i64>().unwrap_or(0);
-            if amount == 0 {
-                form_error.set(Some("Amount must be a non-zero number.".to_string()));
+            let amount = match parse_entered_amount(
+                &amount_val,
+                &currency.for_code(currency_val.as_deref()),
+            ) {
+                Ok(0) => {
+                    form_error.set(Some("Amount must be non-zero.".to_string()));
+                    return;
+                }
+                Ok(amount) => signed_amount(&amount_val, amount, income_val),
+                Err(msg) => {
+                    form_error.set(Some(msg));
+                    return;
+                }
+            };
+            if !allow_duplicate
+                && is_possible_duplicate(&transactions, &date_val, &desc_val, amount)
+            {
+                duplicate_pending.set(true);
                 return;
             }
 
-            form_error.set(None);
-            form_success.set(None);
-            saving.set(true);
-
-            spawn_local(async move {
-                let url = format!("{}/api/transactions", API_BASE_URL);
-                let payload = serde_json::json!({
-                    "date": date_val.as_str(),
-                    "description": desc_val.as_str(),
-                    "category": category_val.as_str(),
-                    "amount": amount
-                });
+            let app_code = currency.code.clone();
+            start_submit(&submitting, move |claim| {
+                form_error.set(None);
+                form_success.set(None);
+                saving.set(true);
 
-                // build request (attach access token if available)
-                let mut builder = Request::post(&url).credentials(RequestCredentials::Include);
-                if let Some(window) = web_sys::window() {
-                    if let Ok(Some(storage)) = window.local_storage() {
-                        if let Ok(token_opt) = storage.get_item("access_token") {
-                            if let Some(token) = token_opt {
-                                builder =
-                                    builder.header("Authorization", &format!("Bearer {}", token));
-                            }
+                spawn_local(async move {
+                    // held until this save has finished, however it ends
+                    let _claim = claim;
+                    let url = format!("{}/api/transactions", API_BASE_URL);
+                    let payload = serde_json::json!({
+                        "date": date_val.as_str(),
+                        "description": desc_val.as_str(),
+                        "category": category_val.as_str(),
+                        "amount": amount,
+                        "note": note_val.as_deref(),
+                        "receiptUrl": receipt_val.as_deref(),
+                        "account": account_val.as_str(),
+                        "tax": tax_val,
+                        "currency": currency_val.as_deref()
+                    });
+
+                    // build request (attach access token if available)
+                    invalidate_dashboard_cache();
+                    let request = api_request(Request::post(&url)).json(&payload);
+
+                    // send request; every failure path re-enables the form
+                    let resp = match send_api(request, None).await {
+                        Ok(resp) if resp.ok() => resp,
+                        failed => {
+                            let reason = match failed {
+                                Err(ApiError::Timeout) => ApiError::Timeout.message(),
+                                _ => "Could not save the transaction.",
+                            };
+                            form_error.set(Some(reason.to_string()));
+                            saving.set(false);
+                            return;
                         }
-                    }
-                }
-
-                let builder = match builder.json(&payload) {
-                    Ok(b) => b,
-                    Err(_) => return,
-                };
-
-                // send request
-                let resp = match builder.send().await {
-                    Ok(r) => r,
-                    Err(_) => return,
-                };
-
-                if !resp.ok() {
-                    form_error.set(Some("Could not save the transaction.".to_string()));
-                    saving.set(false);
-                    return;
-                }
+                    };
 
-                if let Ok(created) = resp.json::<Transaction>().await {
-                    let mut next = (*transactions).clone();
-                    next.insert(0, created);
-                    transactions.set(next);
-                    // reset form
-                    form_date.set("".to_string());
-                    form_description.set("".to_string());
-                    form_category.set("".to_string());
-                    form_amount.set("".to_string());
-
-                    // refresh dashboard summary
-                    let summary_url = format!("{}/api/dashboard/summary", API_BASE_URL);
-                    if let Ok(resp2) = Request::get(&summary_url)
-                        .credentials(RequestCredentials::Include)
-                        .send()
-                        .await
-                    {
-                        if resp2.ok() {
-                            if let Ok(json) = resp2.json::<serde_json::Value>().await {
-                                if let Some(v) = json.get("total_income").and_then(|x| x.as_i64()) {
-                                    total_income.set(v);
-                                }
-                                if let Some(v) = json.get("total_expenses").and_then(|x| x.as_i64())
-                                {
-                                    total_expenses.set(v);
-                                }
-                                if let Some(v) = json.get("balance").and_then(|x| x.as_i64()) {
-                                    balance.set(v);
-                                }
-                            }
+                    if let Ok(created) = resp.json::<Transaction>().await {
+                        if let Some(known) = &known_categories {
+                            known.register(&created.category);
                         }
+                        if should_offer_auto_save(&created, auto_save_percent, rounding, &app_code)
+                        {
+                            auto_save_offer.set(Some(created.clone()));
+                        }
+                        alert_if_over_budget(
+                            &transactions,
+                            &created,
+                            budget_alerts,
+                            savings_as_spending,
+                        );
+                        let round_up = save_round_up(&created, round_up_increment, &app_code).await;
+                        let mut next = (*transactions).clone();
+                        next.insert(0, created.clone());
+                        if let Some(saved) = round_up.as_ref().and_then(|r| r.transaction.clone()) {
+                            next.insert(0, saved);
+                        }
+                        transactions.set(next);
+                        // reset form
+                        form_date.set(today_key());
+                        form_description.set("".to_string());
+                        form_category.set("".to_string());
+                        form_amount.set("".to_string());
+                        form_note.set("".to_string());
+                        form_receipt.set("".to_string());
+                        form_tax.set(false);
+
+                        // refresh dashboard summary
+                        refresh_summary.emit(());
+
+                        // keep the entry (and what was typed) around briefly so it can be undone
+                        last_created.set(Some(UndoEntry {
+                            transaction: created,
+                            round_up,
+                            date: date_val,
+                            description: desc_val,
+                            category: category_val,
+                            amount: amount_val,
+                            note: note_val.unwrap_or_default(),
+                            receipt: receipt_val.unwrap_or_default(),
+                            tax: tax_val,
+                        }));
+                        *undo_timer.borrow_mut() = Some(Timeout::new(UNDO_WINDOW_MS, move || {
+                            last_created.set(None);
+                        }));
+
+                        show_add.set(false);
+                        form_success.set(Some("Transaction saved.".to_string()));
+                    } else {
+                        form_error.set(Some("Could not read the saved transaction.".to_string()));
                     }
-
-                    show_add.set(false);
-                    form_success.set(Some("Transaction saved.".to_string()));
-                    saving.set(false);
-                } else {
-                    form_error.set(Some("Could not read the saved transaction.".to_string()));
                     saving.set(false);
-                }
+                });
             });
         })
     };
 
-    let mut spent_by_category: HashMap<String, i64> = HashMap::new();
-    for tx in (*transactions).iter() {
-        if tx.amount < 0 {
-            let spent = tx.amount.abs();
-            *spent_by_category.entry(tx.category.clone()).or_insert(0) += spent;
-        }
-    }
+    let on_undo = {
+        let last_created = last_created.clone();
+        let undo_timer = undo_timer.clone();
+        let transactions = transactions.clone();
+        let form_date = form_date.clone();
+        let form_description = form_description.clone();
+        let form_category = form_category.clone();
+        let form_amount = form_amount.clone();
+        let form_note = form_note.clone();
+        let form_receipt = form_receipt.clone();
+        let form_tax = form_tax.clone();
+        let show_add = show_add.clone();
+        let refresh_summary = refresh_summary.clone();
+        let form_error = form_error.clone();
+        let form_success = form_success.clone();
+        Callback::from(move |_| {
+            let Some(entry) = (*last_created).clone() else {
+                return;
+            };
+            undo_timer.borrow_mut().take();
+            last_created.set(None);
+            let Some(id) = entry.transaction.id else {
+                return;
+            };
 
-    let total_budget: i64 = budgets.iter().map(|b| b.limit).sum();
-    let budget_spent: i64 = budgets
-        .iter()
-        .map(|b| spent_by_category.get(&b.category).cloned().unwrap_or(0))
-        .sum();
-    let budget_remaining: i64 = total_budget - budget_spent;
-    let overspent_count: usize = budgets
-        .iter()
-        .filter(|b| spent_by_category.get(&b.category).cloned().unwrap_or(0) > b.limit)
-        .count();
+            let transactions = transactions.clone();
+            let form_date = form_date.clone();
+            let form_description = form_description.clone();
+            let form_category = form_category.clone();
+            let form_amount = form_amount.clone();
+            let form_note = form_note.clone();
+            let form_receipt = form_receipt.clone();
+            let form_tax = form_tax.clone();
+            let show_add = show_add.clone();
+            let refresh_summary = refresh_summary.clone();
+            let form_error = form_error.clone();
+            let form_success = form_success.clone();
+            spawn_local(async move {
+                let url = format!("{}/api/transactions/{}", API_BASE_URL, id);
+                invalidate_dashboard_cache();
+                let request = api_request(Request::delete(&url)).build();
+
+                match send_api(request, None).await {
+                    Ok(resp) if resp.ok() => {
+                        let round_up_id = entry
+                            .round_up
+                            .as_ref()
+                            .and_then(|r| r.transaction.as_ref())
+                            .and_then(|tx| tx.id);
+                        let round_up_undone = match &entry.round_up {
+                            Some(round_up) => undo_round_up(round_up).await,
+                            None => true,
+                        };
+                        let next = (*transactions)
+                            .iter()
+                            .filter(|tx| {
+                                tx.id != Some(id)
+                                    && !(round_up_undone
+                                        && round_up_id.is_some()
+                                        && tx.id == round_up_id)
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        transactions.set(next);
+                        form_date.set(entry.date);
+                        form_description.set(entry.description);
+                        form_category.set(entry.category);
+                        form_amount.set(entry.amount);
+                        form_note.set(entry.note);
+                        form_receipt.set(entry.receipt);
+                        form_tax.set(entry.tax);
+                        form_success.set(None);
+                        form_error.set((!round_up_undone).then(|| {
+                            "The transaction was undone, but its round-up is still on the goal."
+                                .to_string()
+                        }));
+                        show_add.set(true);
+
+                        refresh_summary.emit(());
+                    }
+                    _ => {
+                        form_error.set(Some("Could not undo the transaction.".to_string()));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_auto_saved = {
+        let transactions = transactions.clone();
+        let refresh_summary = refresh_summary.clone();
+        Callback::from(move |created: Transaction| {
+            let mut next = (*transactions).clone();
+            next.insert(0, created);
+            transactions.set(next);
+            refresh_summary.emit(());
+        })
+    };
+    let on_auto_save_dismiss = {
+        let auto_save_offer = auto_save_offer.clone();
+        Callback::from(move |_| auto_save_offer.set(None))
+    };
+
+    let on_toggle_transfer = {
+        let show_transfer = show_transfer.clone();
+        let transfer_error = transfer_error.clone();
+        Callback::from(move |_| {
+            show_transfer.set(!*show_transfer);
+            transfer_error.set(None);
+        })
+    };
+
+    let on_transfer = {
+        let transfer_date = transfer_date.clone();
+        let transfer_from = transfer_from.clone();
+        let transfer_to = transfer_to.clone();
+        let transfer_amount = transfer_amount.clone();
+        let transfer_error = transfer_error.clone();
+        let transferring = transferring.clone();
+        let show_transfer = show_transfer.clone();
+        let transactions = transactions.clone();
+        let refresh_summary = refresh_summary.clone();
+        let currency = currency.clone();
+        Callback::from(move |_| {
+            let date_val = transfer_date.trim().to_string();
+            let from_val = (*transfer_from).clone();
+            let to_val = (*transfer_to).clone();
+            if date_val.is_empty() || from_val.is_empty() || to_val.is_empty() {
+                transfer_error.set(Some("Please complete all fields.".to_string()));
+                return;
+            }
+            if from_val == to_val {
+                transfer_error.set(Some("Choose two different accounts.".to_string()));
+                return;
+            }
+            let amount = match parse_entered_amount(&transfer_amount, &currency) {
+                Ok(amount) if amount > 0 => amount,
+                Ok(_) => {
+                    transfer_error.set(Some("Amount must be a positive number.".to_string()));
+                    return;
+                }
+                Err(msg) => {
+                    transfer_error.set(Some(msg));
+                    return;
+                }
+            };
+
+            transfer_error.set(None);
+            transferring.set(true);
+            let transfer_amount = transfer_amount.clone();
+            let transfer_error = transfer_error.clone();
+            let transferring = transferring.clone();
+            let show_transfer = show_transfer.clone();
+            let transactions = transactions.clone();
+            let refresh_summary = refresh_summary.clone();
+            spawn_local(async move {
+                // both legs share the Transfer category so totals skip them
+                let outgoing = serde_json::json!({
+                    "date": date_val.as_str(),
+                    "description": format!("Transfer to {}", to_val),
+                    "category": TRANSFER_CATEGORY,
+                    "amount": -amount,
+                    "account": from_val.as_str()
+                });
+                let incoming = serde_json::json!({
+                    "date": date_val.as_str(),
+                    "description": format!("Transfer from {}", from_val),
+                    "category": TRANSFER_CATEGORY,
+                    "amount": amount,
+                    "account": to_val.as_str()
+                });
+
+                let Some(sent) = post_transaction(&outgoing).await else {
+                    transfer_error.set(Some("Could not save the transfer.".to_string()));
+                    transferring.set(false);
+                    return;
+                };
+                let Some(received) = post_transaction(&incoming).await else {
+                    // don't leave half a transfer behind
+                    if let Some(id) = sent.id {
+                        delete_transaction(id).await;
+                    }
+                    transfer_error.set(Some("Could not save the transfer.".to_string()));
+                    transferring.set(false);
+                    return;
+                };
+
+                let mut next = (*transactions).clone();
+                next.insert(0, sent);
+                next.insert(0, received);
+                transactions.set(next);
+                transfer_amount.set("".to_string());
+                refresh_summary.emit(());
+                transferring.set(false);
+                show_transfer.set(false);
+            });
+        })
+    };
+
+    let TransactionModalCallbacks {
+        on_select: on_select_tx,
+        on_close: on_close_tx,
+        on_updated: on_tx_updated,
+        on_deleted: on_tx_deleted,
+        on_split: on_tx_split,
+    } = transaction_modal_callbacks(&transactions, &selected_tx, &refresh_summary);
+
+    let spent_by_category = spent_by_category(&transactions, savings_as_spending);
+
+    let total_budget: i64 = budgets.iter().map(|b| b.limit).sum();
+    let budget_spent: i64 = budgets
+        .iter()
+        .map(|b| {
+            spent_by_category
+                .get(&category_key(&b.category))
+                .cloned()
+                .unwrap_or(0)
+        })
+        .sum();
+    let budget_remaining: i64 = total_budget - budget_spent;
+    let overspent_count = overspent_budgets(&budgets, &spent_by_category);
+    let (active_budgets, idle_budgets) =
+        split_zero_spend(&budgets, &spent_by_category, hide_zero_spend);
+    use_publish_nav_status(
+        (!*loading).then_some(overspent_count),
+        Some(goal_completed(&current_goal)),
+    );
+
+    // nothing recorded anywhere yet
+    let first_run = !*loading
+        && load_error.is_none()
+        && transactions.is_empty()
+        && budgets.is_empty()
+        && is_default_goal(&current_goal);
+    {
+        let on_first_run = props.on_first_run.clone();
+        use_effect_with_deps(
+            move |first_run| {
+                if *first_run {
+                    on_first_run.emit(());
+                }
+                || ()
+            },
+            first_run,
+        );
+    }
 
     let goal_saved: i64 = current_goal.contributions.iter().map(|c| c.amount).sum();
     let goal_progress = if current_goal.target_amount > 0 {
@@ -558,21 +2200,142 @@ fn dashboard_page() -> Html {
         0.0
     };
 
+    let balances = account_balances(&transactions, &accounts)
+        .into_iter()
+        .map(|(account, amount)| {
+            let code = account_currency(&account_currencies, &account, &currency.code);
+            (account, amount, code)
+        })
+        .collect::<Vec<_>>();
+    let accounts_total = app_currency_total(&balances, &currency.code);
+    let mixed_currencies = balances
+        .iter()
+        .any(|(_, _, code)| stored_currency(code, &currency.code).is_some());
+    let form_format = currency.for_code(Some(&form_currency));
+    let amount_range = (parse_bound(&min_amount), parse_bound(&max_amount));
+    let search_index = use_memo(|rows| SearchIndex::build(rows), transactions.clone());
+    let visible_transactions = (*transactions)
+        .iter()
+        .enumerate()
+        .filter(|(idx, tx)| {
+            account_filter
+                .as_deref()
+                .map(|account| account_name(tx) == account)
+                .unwrap_or(true)
+                && within_amount_range(tx, amount_range)
+                && search_index.matches(*idx, &search_query)
+                && (!*review_only || tx.flagged)
+        })
+        .map(|(_, tx)| tx.clone())
+        .collect::<Vec<_>>();
+    let visible_transactions = group_splits(&visible_transactions);
+    let is_filtered = account_filter.is_some()
+        || amount_range != (None, None)
+        || !search_query.is_empty()
+        || *review_only;
+    let flagged_count = transactions.iter().filter(|tx| tx.flagged).count();
+    // long lists only render the rows near the viewport; the rest are
+    // replaced by two spacer rows of the same estimated height
+    let tx_body_ref = use_node_ref();
+    let (window_first, window_count) = use_row_window(tx_body_ref.clone());
+    let row_count = visible_transactions.len();
+    let (first_row, rendered_rows) = if row_count > VIRTUALIZE_MIN_ROWS {
+        let count = window_count.min(row_count);
+        (window_first.min(row_count - count), count)
+    } else {
+        (0, row_count)
+    };
+    let spacer_above = first_row as f64 * TX_ROW_HEIGHT_PX;
+    let spacer_below = (row_count - first_row - rendered_rows) as f64 * TX_ROW_HEIGHT_PX;
+    // scrolling near the end of the loaded rows pulls in the next page
+    let near_end = !is_filtered && window_first + window_count >= row_count;
+    {
+        let on_load_more = on_load_more.clone();
+        use_effect_with_deps(
+            move |(near_end, more, _)| {
+                if *near_end && *more {
+                    on_load_more.emit(());
+                }
+                || ()
+            },
+            (near_end, *has_more, row_count),
+        );
+    }
+    let on_account_filter = {
+        let account_filter = account_filter.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let value = input.value();
+            account_filter.set(if value.is_empty() { None } else { Some(value) });
+        })
+    };
+    let on_review_toggle = {
+        let review_only = review_only.clone();
+        Callback::from(move |_| review_only.set(!*review_only))
+    };
+
+    // "this month so far", recomputed from the loaded list on every render
+    let month_key_now = current_month_key(bounds);
+    let month_so_far = transactions
+        .iter()
+        .any(|tx| month_key(&tx.date, bounds).as_ref() == Some(&month_key_now))
+        .then(|| month_totals(&transactions, &month_key_now, bounds));
+
     html! {
         { page_shell(
             "Dashboard",
             html! {
-                <button onclick={on_toggle_add} class="flex items-center gap-2 bg-primary text-primary-foreground px-4 py-2 rounded-xl font-bold text-sm hover:opacity-90 transition-all">
-                    { icon_plus() }
-                    { if *show_add { "Close" } else { "Add Transaction" } }
-                </button>
+                <div class="flex items-center gap-2">
+                    // refreshing and updated show through `RefreshStatus`
+                    if *cache_status == Some(CacheStatus::Offline) {
+                        <span class="text-xs text-amber-700" role="status">{"Offline — showing cached data"}</span>
+                    }
+                    <RefreshStatus refreshed_at={*refreshed_at} refreshing={*loading || *cache_status == Some(CacheStatus::Refreshing)} {on_refresh} />
+                    <button onclick={on_toggle_transfer} class="flex items-center gap-2 bg-secondary text-secondary-foreground px-4 py-2 rounded-xl font-bold text-sm hover:opacity-90 transition-all">
+                        { if *show_transfer { "Close" } else { "Transfer" } }
+                    </button>
+                    <button ref={add_toggle_ref} onclick={on_toggle_add} aria-expanded={show_add.to_string()} aria-keyshortcuts="n" title="Add Transaction (n)" class="flex items-center gap-2 bg-primary text-primary-foreground px-4 py-2 rounded-xl font-bold text-sm hover:opacity-90 transition-all">
+                        { icon_plus() }
+                        { if *show_add { "Close" } else { "Add Transaction" } }
+                    </button>
+                </div>
             },
             html! {
                 <>
-                    <div class="grid grid-cols-1 md:grid-cols-3 gap-6">
-                        <StatCard title="Total Income" amount={*total_income} icon={StatIcon::UpRight} currency_symbol={currency_symbol.clone()} />
-                        <StatCard title="Total Expenses" amount={*total_expenses} icon={StatIcon::CreditCard} currency_symbol={currency_symbol.clone()} />
-                        <StatCard title="Current Balance" amount={*balance} icon={StatIcon::Wallet} currency_symbol={currency_symbol.clone()} />
+                    { load_error_banner(*load_error, &load_attempt) }
+                    if monthly_report_enabled {
+                        <MonthlyReportCard {bounds} currency={currency.clone()} />
+                    }
+                    { month_so_far_strip(month_so_far, &currency) }
+                    <div class="grid grid-cols-1 lg:grid-cols-3 gap-3 md:gap-6">
+                        <StatCard title="Total Income" amount={summary.totals.income} icon={StatIcon::UpRight} currency={currency.clone()} />
+                        <StatCard title="Total Expenses" amount={summary.totals.expenses} icon={StatIcon::CreditCard} currency={currency.clone()} />
+                        <StatCard title="Current Balance" amount={accounts_total} icon={StatIcon::Wallet} currency={currency.clone()} />
+                    </div>
+                    { spend_ratio_gauge(month_so_far) }
+
+                    if let Some(left) = budget_left_this_month(&budgets, &transactions, bounds, savings_as_spending) {
+                        { safe_to_spend_card(left, bounds, &currency) }
+                    }
+
+                    { activity_feed_card(&activity_feed(&transactions, &current_goal.contributions), &on_select_tx, &currency) }
+
+                    <div class="bg-card rounded-[10px] p-6 border border-border">
+                        <h3 class="font-bold text-foreground text-lg mb-4">{"Accounts"}</h3>
+                        <div class="grid grid-cols-2 md:grid-cols-4 gap-3">
+                            { for balances.iter().map(|(account, amount, code)| html! {
+                                <div key={account.clone()} class="bg-secondary/50 rounded-[10px] px-4 py-3">
+                                    <p class="text-[10px] font-bold tracking-widest text-muted-foreground">{ account.clone() }</p>
+                                    <p class="text-lg font-bold text-primary"><CurrencyAmount amount={*amount} currency={currency.for_code(Some(code))} /></p>
+                                </div>
+                            }) }
+                        </div>
+                        if mixed_currencies {
+                            <p class="text-xs text-muted-foreground mt-3">{ format!("Current Balance adds up the {} accounts only; there are no exchange rates to convert the others.", currency.code) }</p>
+                        }
+                        if *has_more {
+                            <p class="text-xs text-muted-foreground mt-3">{"Balances count the transactions loaded so far; older ones load as you scroll Recent Transactions."}</p>
+                        }
                     </div>
 
                     <div class="grid grid-cols-1 lg:grid-cols-2 gap-6">
@@ -582,14 +2345,14 @@ fn dashboard_page() -> Html {
                                 <span class="text-xs text-muted-foreground">{"Managed in Saving Goal tab"}</span>
                             </div>
                             { if current_goal.target_amount == 0 && current_goal.contributions.is_empty() && current_goal.title.trim().is_empty() {
-                                html! { <p class="text-sm text-muted-foreground">{"No goal set yet."}</p> }
+                                html! { <EmptyState icon={icon_target()} message="No goal set yet." action_label="Set a goal" on_action={navigate_to(Page::Savings)} /> }
                             } else {
                                 html! {
                                     <>
                                         <p class="text-sm text-muted-foreground">{ if current_goal.title.trim().is_empty() { "Saving Goal" } else { current_goal.title.as_str() } }</p>
                                         <div class="mt-3 flex items-center justify-between text-sm">
-                                            <span class="text-muted-foreground">{ format!("Saved: {}", format_currency(goal_saved, &currency_symbol)) }</span>
-                                            <span class="text-muted-foreground">{ if current_goal.target_amount > 0 { format!("Target: {}", format_currency(current_goal.target_amount, &currency_symbol)) } else { "Target: —".to_string() } }</span>
+                                            <span class="text-muted-foreground">{"Saved: "}<CurrencyAmount amount={goal_saved} currency={currency.clone()} /></span>
+                                            <span class="text-muted-foreground">{"Target: "}{ if current_goal.target_amount > 0 { html! { <CurrencyAmount amount={current_goal.target_amount} currency={currency.clone()} /> } } else { html! {"—"} } }</span>
                                         </div>
                                         <div class="mt-2 h-2 w-full bg-secondary rounded-full overflow-hidden">
                                             <div class="h-full bg-primary" style={format!("width: {}%", (goal_progress * 100.0) as i32)}></div>
@@ -606,24 +2369,24 @@ fn dashboard_page() -> Html {
                                 <span class="text-xs text-muted-foreground">{"Based on expenses"}</span>
                             </div>
                             { if budgets.is_empty() {
-                                html! { <p class="text-sm text-muted-foreground">{"No budgets set yet."}</p> }
+                                html! { <EmptyState icon={icon_wallet()} message="No budgets set yet." action_label="Set budgets" on_action={navigate_to(Page::Budget)} /> }
                             } else {
                                 html! {
                                     <>
                                         <div class="flex items-center justify-between text-sm mb-3">
                                             <span class="text-muted-foreground">{"Remaining overall"}</span>
-                                            <span class={if budget_remaining < 0 { "text-red-600" } else { "text-foreground" }}>
-                                                { format_currency(budget_remaining.abs(), &currency_symbol) }
+                                            <span class="text-foreground">
+                                                <CurrencyAmount amount={budget_remaining} currency={currency.clone()} />
                                             </span>
                                         </div>
                                         { if overspent_count > 0 {
                                             html! { <p class="text-xs text-red-600 mb-3">{ format!("{} budget(s) over limit", overspent_count) }</p> }
                                         } else { html!{} } }
                                         <div class="space-y-2">
-                                            { for budgets.iter().map(|b| {
-                                                let spent = spent_by_category.get(&b.category).cloned().unwrap_or(0);
+                                            { for active_budgets.iter().map(|b| {
+                                                let spent = spent_by_category.get(&category_key(&b.category)).cloned().unwrap_or(0);
                                                 let remaining = b.limit - spent;
-                                                let percent = if b.limit > 0 { (spent as f64 / b.limit as f64 * 100.0).round() as i64 } else { 0 };
+                                                let percent = percent_of(spent, b.limit);
                                                 html! {
                                                     <div class="flex flex-col gap-1 text-sm">
                                                         <div class="flex items-center justify-between">
@@ -636,47 +2399,151 @@ fn dashboard_page() -> Html {
                                                             <div class="h-full bg-primary" style={format!("width: {}%", percent.min(100))}></div>
                                                         </div>
                                                         <div class="flex items-center justify-between text-xs text-muted-foreground">
-                                                            <span>{ format!("Spent: {}", format_currency(spent, &currency_symbol)) }</span>
-                                                            <span class={if remaining < 0 { "text-red-600" } else { "text-muted-foreground" }}>{ format!("Remaining: {}", format_currency(remaining.abs(), &currency_symbol)) }</span>
+                                                            <span>{"Spent: "}<CurrencyAmount amount={spent} currency={currency.clone()} /></span>
+                                                            <span>{"Remaining: "}<CurrencyAmount amount={remaining} currency={currency.clone()} /></span>
                                                         </div>
                                                     </div>
                                                 }
                                             }) }
                                         </div>
+                                        if !idle_budgets.is_empty() {
+                                            <p class="text-xs text-muted-foreground mt-3">{ format!("{} budget(s) with no spend this period hidden", idle_budgets.len()) }</p>
+                                        }
                                     </>
                                 }
                             }}
                         </div>
                     </div>
 
+                    {
+                        if let Some(entry) = &*last_created {
+                            html! {
+                                <div class="flex items-center justify-between bg-card rounded-[10px] px-6 py-3 border border-border text-sm">
+                                    <span class="text-green-600">{ format!("Saved \"{}\".", entry.transaction.description) }</span>
+                                    <button onclick={on_undo} class="font-bold text-primary hover:underline">{"Undo"}</button>
+                                </div>
+                            }
+                        } else if let Some(msg) = &*form_error {
+                            if *show_add { html! {} } else { html! { <p class="text-sm text-red-500">{ msg.clone() }</p> } }
+                        } else { html!{} }
+                    }
+
+                    if let Some(income) = &*auto_save_offer {
+                        <AutoSaveOffer
+                            income={income.clone()}
+                            percent={auto_save_percent}
+                            currency={currency.clone()}
+                            on_saved={on_auto_saved}
+                            on_dismiss={on_auto_save_dismiss}
+                        />
+                    }
+
+                    if *show_transfer {
+                        <div class="bg-card rounded-[10px] p-6 border border-border">
+                            <h3 class="font-bold text-foreground text-lg mb-3">{"Transfer Between Accounts"}</h3>
+                            <div class="grid grid-cols-1 md:grid-cols-5 gap-3">
+                                <input type="date" max={today.clone()} value={(*transfer_date).clone()} oninput={{
+                                    let transfer_date = transfer_date.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        transfer_date.set(input.value());
+                                    })
+                                }} class="p-2 border rounded" />
+                                <select aria-label="From account" onchange={{
+                                    let transfer_from = transfer_from.clone();
+                                    Callback::from(move |e: Event| {
+                                        let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                        transfer_from.set(input.value());
+                                    })
+                                }} class="p-2 border rounded">
+                                    { account_options(&accounts, &transfer_from) }
+                                </select>
+                                <select aria-label="To account" onchange={{
+                                    let transfer_to = transfer_to.clone();
+                                    Callback::from(move |e: Event| {
+                                        let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                        transfer_to.set(input.value());
+                                    })
+                                }} class="p-2 border rounded">
+                                    { account_options(&accounts, &transfer_to) }
+                                </select>
+                                <div class="flex flex-col gap-1">
+                                    <input type="text" inputmode="decimal" pattern={amount_pattern('.', currency.entry_places())} placeholder={currency.entry_placeholder(format!("Amount ({})", currency.symbol))} value={(*transfer_amount).clone()} oninput={amount_oninput(&transfer_amount, '.', currency.entry_places())} class="p-2 border rounded" />
+                                    { entry_preview(&transfer_amount, &currency) }
+                                </div>
+                                <button onclick={on_transfer} class="bg-primary text-primary-foreground px-4 rounded" disabled={*transferring}>{ if *transferring { "Saving..." } else { "Transfer" } }</button>
+                            </div>
+                            if accounts.len() < 2 {
+                                <p class="text-xs text-muted-foreground mt-2">{"Add another account in Settings to transfer between accounts."}</p>
+                            }
+                            if let Some(msg) = &*transfer_error {
+                                <p class="text-sm text-red-500 mt-2">{ msg.clone() }</p>
+                            }
+                        </div>
+                    }
+
                     {
                         if *show_add {
                             html! {
-                                <div class="bg-card rounded-[10px] p-6 mt-4 border border-border">
+                                <div class="bg-card rounded-[10px] p-4 md:p-6 mt-4 border border-border">
                                     <div class="grid grid-cols-1 md:grid-cols-4 gap-3">
-                                        <input type="date" value={(*form_date).clone()} oninput={Callback::from(move |e: InputEvent| {
+                                        <input ref={add_date_ref} type="date" aria-label="Date" max={today.clone()} value={(*form_date).clone()} oninput={Callback::from(move |e: InputEvent| {
                                             if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
                                                 form_date.set(input.value());
                                             }
-                                        })} class="p-2 border rounded" />
+                                        })} class="p-3 md:p-2 border rounded" />
                                         <input placeholder="Description" value={(*form_description).clone()} oninput={Callback::from(move |e: InputEvent| {
                                             if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
                                                 form_description.set(input.value());
                                             }
-                                        })} class="p-2 border rounded" />
-                                        <input placeholder="Category" value={(*form_category).clone()} oninput={Callback::from(move |e: InputEvent| {
+                                        })} class="p-3 md:p-2 border rounded" />
+                                        <input placeholder="Category" list={CATEGORY_LIST_ID} value={(*form_category).clone()} oninput={Callback::from(move |e: InputEvent| {
                                             if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
                                                 form_category.set(input.value());
                                             }
-                                        })} class="p-2 border rounded" />
+                                        })} class="p-3 md:p-2 border rounded" />
                                         <div class="flex gap-2">
-                                            <input placeholder={format!("Amount ({})", currency_symbol)} value={(*form_amount).clone()} oninput={Callback::from(move |e: InputEvent| {
-                                                if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
-                                                    form_amount.set(input.value());
-                                                }
-                                            })} class="p-2 border rounded flex-1" />
-                                            <button onclick={on_submit} class="bg-accent text-white px-4 rounded" disabled={*saving}>{ if *saving { "Saving..." } else { "Save" } }</button>
+                                            { direction_toggle(&form_income) }
+                                            <div class="flex flex-col gap-1 flex-1">
+                                                <input type="text" inputmode="decimal" pattern={amount_pattern('.', form_format.entry_places())} placeholder={form_format.entry_placeholder(format!("Amount ({})", form_format.symbol))} value={(*form_amount).clone()} oninput={amount_oninput(&form_amount, '.', form_format.entry_places())} class="p-3 md:p-2 border rounded" />
+                                                { entry_preview(&form_amount, &form_format) }
+                                            </div>
+                                            <button onclick={on_submit.reform(|_| false)} class="bg-primary text-primary-foreground px-4 py-3 md:py-0 rounded" disabled={*saving}>{ if *saving { "Saving..." } else { "Save" } }</button>
                                         </div>
+                                        <p class="text-xs text-muted-foreground md:col-span-2 -mt-2">{"Enter the amount without a sign and pick Expense or Income. A typed - or + overrides the toggle."}</p>
+                                        <input placeholder="Note (optional)" value={(*form_note).clone()} oninput={Callback::from(move |e: InputEvent| {
+                                            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                                                form_note.set(input.value());
+                                            }
+                                        })} class="p-3 md:p-2 border rounded md:col-span-2" />
+                                        <select onchange={{
+                                            let form_account = form_account.clone();
+                                            let form_currency = form_currency.clone();
+                                            let account_currencies = account_currencies.clone();
+                                            let app_code = currency.code.clone();
+                                            Callback::from(move |e: Event| {
+                                                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                                form_currency.set(account_currency(&account_currencies, &input.value(), &app_code));
+                                                form_account.set(input.value());
+                                            })
+                                        }} aria-label="Account" class="p-3 md:p-2 border rounded">
+                                            { account_options(&accounts, &form_account) }
+                                        </select>
+                                        <select onchange={{
+                                            let form_currency = form_currency.clone();
+                                            Callback::from(move |e: Event| {
+                                                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                                form_currency.set(input.value());
+                                            })
+                                        }} aria-label="Currency" class="p-3 md:p-2 border rounded">
+                                            { currency_code_options(&form_currency) }
+                                        </select>
+                                        <input placeholder="Receipt link or reference (optional)" value={(*form_receipt).clone()} oninput={Callback::from(move |e: InputEvent| {
+                                            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                                                form_receipt.set(input.value());
+                                            }
+                                        })} class="p-3 md:p-2 border rounded" />
+                                        { tax_checkbox(&form_tax) }
                                         {
                                             if let Some(msg) = &*form_error {
                                                 html! { <p class="text-sm text-red-500">{ msg.clone() }</p> }
@@ -695,1475 +2562,8889 @@ fn dashboard_page() -> Html {
                     <div class="bg-card rounded-[10px] shadow-sm border border-border overflow-hidden mt-4">
                         <div class="p-6 flex justify-between items-center border-b border-border">
                             <h3 class="font-bold text-foreground text-lg">{"Recent Transactions"}</h3>
+                            <div class="flex flex-wrap items-center gap-2">
+                                if is_filtered {
+                                    <span class="text-xs text-muted-foreground">{ format!("{} match(es)", visible_transactions.len()) }</span>
+                                }
+                                { search_input(&search_text, &search_query, &search_timer) }
+                                { amount_range_inputs(&min_amount, &max_amount) }
+                                <button type="button" onclick={on_review_toggle} aria-pressed={review_only.to_string()} class={classes!("flex", "items-center", "gap-2", "px-3", "py-2", "rounded", "border", "text-sm", if *review_only { "bg-primary text-primary-foreground" } else { "text-foreground" })}>
+                                    {"Needs Review"}
+                                    if flagged_count > 0 {
+                                        <span class="min-w-[20px] h-5 px-1.5 rounded-full bg-amber-500 text-black text-[11px] font-bold leading-5 text-center" aria-label={format!("{} flagged", flagged_count)}>{ flagged_count }</span>
+                                    }
+                                </button>
+                                <select onchange={on_account_filter} aria-label="Filter by account" class="p-2 border rounded text-sm">
+                                    <option value="" selected={account_filter.is_none()}>{"All accounts"}</option>
+                                    { for balances.iter().map(|(account, _, _)| html! {
+                                        <option value={account.clone()} selected={account_filter.as_deref() == Some(account.as_str())}>{ account.clone() }</option>
+                                    }) }
+                                </select>
+                            </div>
                         </div>
                         <div class="overflow-x-auto">
-                            <table class="w-full text-left border-collapse">
+                            <table class="w-full min-w-[560px] text-left border-collapse">
+                                <caption class="sr-only">{"Recent transactions"}</caption>
                                 <thead>
                                     <tr class="bg-muted/50 text-muted-foreground text-[10px] uppercase tracking-widest">
-                                        <th class="px-8 py-4 font-bold">{"Date"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Description"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Category"}</th>
-                                        <th class="px-8 py-4 font-bold text-right">{"Amount"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Date"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Description"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Category"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold text-right">{"Amount"}</th>
                                     </tr>
                                 </thead>
-                                <tbody class="divide-y divide-border">
-                                    { for (*transactions).iter().enumerate().map(|(idx, tx)| {
+                                <tbody ref={tx_body_ref} class="divide-y divide-border">
+                                    if spacer_above > 0.0 {
+                                        <tr key="spacer-above" aria-hidden="true" style={format!("height: {}px", spacer_above)}></tr>
+                                    }
+                                    { for visible_transactions.iter().enumerate().skip(first_row).take(rendered_rows).map(|(idx, tx)| {
                                         let amount_class = "px-8 py-4 text-right font-semibold text-foreground";
-                                        let amount_label = if tx.amount > 0 {
-                                            format!("+ {}", format_currency(tx.amount, &currency_symbol))
-                                        } else {
-                                            format_currency(tx.amount, &currency_symbol)
-                                        };
-
                                         html! {
-                                            <tr key={idx} class="text-sm hover:bg-muted/30 transition-colors">
+                                            <tr key={transaction_key(tx, idx)} class={classes!("text-sm", "hover:bg-muted/30", "transition-colors", "cursor-pointer", is_transfer(tx).then_some("bg-muted/60"), tx.split_group.is_some().then_some("border-l-2 border-l-secondary"))} tabindex="0" aria-haspopup="dialog" aria-label={format!("{}, {} on {}", tx.description, amount_description(tx, &currency), tx.date)} onclick={row_select_callback(&on_select_tx, tx)} onkeydown={row_keyboard_callback(&on_select_tx, tx)}>
                                                 <td class="px-8 py-4 text-muted-foreground">{ &tx.date }</td>
-                                                <td class="px-8 py-4 text-foreground">{ &tx.description }</td>
+                                                <td class="px-8 py-4 text-foreground">{ &tx.description }{ flag_badge(tx) }{ note_details(tx) }{ search_match_label(tx, &search_query) }</td>
                                                 <td class="px-8 py-4">
-                                                    <span class="bg-secondary text-secondary-foreground px-3 py-1 rounded-full text-[10px] font-bold">{ &tx.category }</span>
+                                                    <span class={classes!("px-3", "py-1", "rounded-full", "text-[10px]", "font-bold", if is_transfer(tx) { "bg-muted text-primary" } else { "bg-secondary text-secondary-foreground" })}>{ category_label(tx) }</span>
+                                                    { split_badge(tx) }
                                                 </td>
-                                                <td class={amount_class}>{ amount_label }</td>
+                                                <td class={amount_class}>{ amount_cell(tx, &currency, true) }</td>
                                             </tr>
                                         }
                                     }) }
+                                    if spacer_below > 0.0 {
+                                        <tr key="spacer-below" aria-hidden="true" style={format!("height: {}px", spacer_below)}></tr>
+                                    }
+                                    if *has_more {
+                                        <tr key="load-more">
+                                            <td colspan="4" class="px-8 py-3 text-center text-xs text-muted-foreground">
+                                                if is_filtered {
+                                                    { format!("Filters cover the {} transactions loaded so far. ", transactions.len()) }
+                                                }
+                                                <button type="button" onclick={on_load_more.reform(|_| ())} disabled={*loading_more} class="font-bold text-primary hover:underline disabled:opacity-50">
+                                                    { if *loading_more { "Loading older transactions..." } else { "Load older transactions" } }
+                                                </button>
+                                            </td>
+                                        </tr>
+                                    }
+                                    if visible_transactions.is_empty() && !*loading {
+                                        <tr>
+                                            <td colspan="4" class="px-8 py-2">
+                                                if transactions.is_empty() {
+                                                    <EmptyState icon={icon_credit_card()} message="No transactions yet." action_label="Add transaction" on_action={on_empty_add} />
+                                                } else {
+                                                    <p class="py-6 text-center text-sm text-muted-foreground">{"No transactions match these filters."}</p>
+                                                }
+                                            </td>
+                                        </tr>
+                                    }
                                 </tbody>
                             </table>
                         </div>
                     </div>
+
+                    if let Some(tx) = &*selected_tx {
+                        <TransactionModal
+                            transaction={tx.clone()}
+                            currency={currency.clone()}
+                            on_close={on_close_tx}
+                            on_updated={on_tx_updated}
+                            on_deleted={on_tx_deleted}
+                            on_duplicate={on_duplicate}
+                            on_split={on_tx_split}
+                        />
+                    }
+                    { duplicate_confirm(&duplicate_pending, &on_submit) }
                 </>
             }
         ) }
     }
 }
 
-fn page_shell(title: &'static str, actions: Html, children: Html) -> Html {
+/// How long the "Undo" action stays available after a Dashboard save.
+const UNDO_WINDOW_MS: u32 = 8_000;
+
+/// The last transaction created from the Dashboard form, plus the raw form
+/// values so an undo can put them back.
+#[derive(Clone, PartialEq)]
+struct UndoEntry {
+    transaction: Transaction,
+    /// The expense's round-up, taken back along with it.
+    round_up: Option<RoundUp>,
+    date: String,
+    description: String,
+    category: String,
+    amount: String,
+    note: String,
+    receipt: String,
+    tax: bool,
+}
+
+/// Applies the Income/Expense toggle to a parsed amount. A sign typed into
+/// the field wins over the toggle, so `-250` is still an expense and `+250`
+/// still income whichever side is selected.
+fn signed_amount(raw: &str, amount: i64, income: bool) -> i64 {
+    if raw.trim_start().starts_with(['-', '+']) || income {
+        amount
+    } else {
+        -amount
+    }
+}
+
+/// Segmented Income / Expense control deciding the sign of an unsigned amount.
+fn direction_toggle(income: &UseStateHandle<bool>) -> Html {
+    let segment = |label: &'static str, value: bool| {
+        let active = **income == value;
+        let onclick = {
+            let income = income.clone();
+            Callback::from(move |_| income.set(value))
+        };
+        let class = if active {
+            "px-3 py-3 md:py-2 text-sm md:text-xs bg-primary text-primary-foreground"
+        } else {
+            "px-3 py-3 md:py-2 text-sm md:text-xs bg-card text-muted-foreground hover:bg-muted"
+        };
+        html! {
+            <button type="button" {class} aria-pressed={active.to_string()} {onclick}>{ label }</button>
+        }
+    };
     html! {
-        <div class="p-6 max-w-7xl mx-auto">
-            <div class="flex items-center justify-between pb-4 border-b border-border">
-                <h1 class="text-2xl font-bold text-foreground">{ title }</h1>
-                { actions }
-            </div>
-            <div class="pt-5 space-y-6">
-                { children }
-            </div>
+        <div class="flex rounded border border-border overflow-hidden shrink-0" role="group" aria-label="Transaction type">
+            { segment("Expense", false) }
+            { segment("Income", true) }
         </div>
     }
 }
 
-#[function_component(BudgetPage)]
-fn budget_page() -> Html {
-    let settings = use_context::<UseStateHandle<AppSettings>>();
-    let currency_symbol = settings
-        .as_ref()
-        .map(|s| s.currency_symbol.clone())
-        .unwrap_or_else(|| "₱".to_string());
+/// The "Tax-relevant" checkbox shared by the create and edit forms.
+fn tax_checkbox(state: &UseStateHandle<bool>) -> Html {
+    let onchange = {
+        let state = state.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            state.set(input.checked());
+        })
+    };
+    html! {
+        <label class="flex items-center gap-2 text-xs text-muted-foreground">
+            <input type="checkbox" checked={**state} {onchange} />
+            {"Tax-relevant"}
+        </label>
+    }
+}
 
-    let category_totals = use_state(|| Vec::<(String, i64)>::new());
-    let total_spent = use_state(|| 0i64);
-    let loading = use_state(|| true);
+/// Guards against accidental resubmits: whether a transaction with the same
+/// date, description (case-insensitive) and amount is already loaded.
+fn is_possible_duplicate(
+    existing: &[Transaction],
+    date: &str,
+    description: &str,
+    amount: i64,
+) -> bool {
+    let day = date.get(0..10).unwrap_or(date);
+    let description = description.trim().to_lowercase();
+    existing.iter().any(|tx| {
+        tx.amount == amount
+            && tx.date.get(0..10).unwrap_or(&tx.date) == day
+            && tx.description.trim().to_lowercase() == description
+    })
+}
 
-    let budgets = use_state(load_budgets);
-    let budget_category = use_state(|| "".to_string());
-    let budget_limit = use_state(|| "".to_string());
-    let budget_error = use_state(|| None::<String>);
+/// Asks before saving a possible duplicate; `submit` is re-run with `true`
+/// to skip the check once confirmed.
+fn duplicate_confirm(open: &UseStateHandle<bool>, submit: &Callback<bool>) -> Html {
+    if !**open {
+        return html! {};
+    }
+    let on_confirm = {
+        let open = open.clone();
+        let submit = submit.clone();
+        Callback::from(move |_| {
+            open.set(false);
+            submit.emit(true);
+        })
+    };
+    let on_cancel = {
+        let open = open.clone();
+        Callback::from(move |_| open.set(false))
+    };
+    html! {
+        <ConfirmModal
+            title="Possible duplicate"
+            message="A transaction with the same date, description and amount already exists. Save it anyway?"
+            confirm_label="Save anyway"
+            {on_confirm}
+            {on_cancel}
+        />
+    }
+}
 
-    {
-        let category_totals = category_totals.clone();
-        let total_spent = total_spent.clone();
-        let loading = loading.clone();
+/// Trims free-text input, mapping blank values to `None`.
+fn optional_text(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
 
-        use_effect_with_deps(
-            move |_| {
-                spawn_local(async move {
-                    let url = format!("{}/api/transactions", API_BASE_URL);
-                    let mut req = Request::get(&url).credentials(RequestCredentials::Include);
-                    if let Some(window) = web_sys::window() {
-                        if let Ok(Some(storage)) = window.local_storage() {
-                            if let Ok(token_opt) = storage.get_item("access_token") {
-                                if let Some(token) = token_opt {
-                                    req = req.header("Authorization", &format!("Bearer {}", token));
-                                }
-                            }
-                        }
-                    }
+/// Collapsible note shown under a transaction's description in tables.
+fn note_details(tx: &Transaction) -> Html {
+    match tx.note.as_deref() {
+        Some(note) if !note.trim().is_empty() => html! {
+            <details class="mt-1 text-xs text-muted-foreground" onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}>
+                <summary class="cursor-pointer select-none">{"Note"}</summary>
+                <p class="mt-1 whitespace-pre-wrap">{ note.to_string() }</p>
+            </details>
+        },
+        _ => html! {},
+    }
+}
 
-                    if let Ok(resp) = req.send().await {
-                        if resp.ok() {
-                            if let Ok(list) = resp.json::<Vec<Transaction>>().await {
-                                let mut totals: HashMap<String, i64> = HashMap::new();
-                                let mut spent = 0i64;
-                                for tx in list.iter() {
-                                    if tx.amount < 0 {
-                                        let amt = tx.amount.abs();
-                                        spent += amt;
-                                        *totals.entry(tx.category.clone()).or_insert(0) += amt;
-                                    }
-                                }
-                                let mut totals_vec: Vec<(String, i64)> =
-                                    totals.into_iter().collect();
-                                totals_vec.sort_by(|a, b| b.1.cmp(&a.1));
-                                category_totals.set(totals_vec);
-                                total_spent.set(spent);
-                            }
-                        }
-                    }
+/// Claims of the stored access token. The token is only decoded, not
+/// verified; the server stays the authority.
+fn access_token_claims() -> Option<serde_json::Value> {
+    let token = token_store::get()?;
+    let payload = token.split('.').nth(1)?;
+    let mut base64: String = payload
+        .chars()
+        .map(|c| match c {
+            '-' => '+',
+            '_' => '/',
+            other => other,
+        })
+        .collect();
+    while !base64.len().is_multiple_of(4) {
+        base64.push('=');
+    }
+    let json = web_sys::window()?.atob(&base64).ok()?;
+    serde_json::from_str(&json).ok()
+}
 
-                    loading.set(false);
-                });
-                || ()
-            },
-            (),
-        );
+/// The signed-in user's id (`sub`), used to keep per-user caches apart.
+fn current_user_id() -> Option<String> {
+    let claims = access_token_claims()?;
+    match claims.get("sub")? {
+        serde_json::Value::String(sub) => Some(sub.clone()),
+        other => Some(other.to_string()),
     }
+}
 
-    let on_add_budget = {
-        let budgets = budgets.clone();
-        let budget_category = budget_category.clone();
-        let budget_limit = budget_limit.clone();
-        let budget_error = budget_error.clone();
-        Callback::from(move |_| {
-            let category = budget_category.trim().to_string();
-            let limit = budget_limit.trim().parse::<i64>().unwrap_or(0);
+/// Last successful Dashboard fetch, shown immediately on the next visit
+/// while fresh data loads.
+#[derive(Serialize, Deserialize)]
+struct DashboardCache {
+    transactions: Vec<Transaction>,
+}
 
-            if category.is_empty() || limit <= 0 {
-                budget_error.set(Some("Enter a category and a positive limit.".to_string()));
-                return;
-            }
+fn dashboard_cache_key() -> Option<String> {
+    current_user_id().map(|id| format!("dashboard_cache:{}", id))
+}
 
-            let mut next = (*budgets).clone();
-            if let Some(existing) = next
-                .iter_mut()
-                .find(|b| b.category.eq_ignore_ascii_case(&category))
-            {
-                existing.limit = limit;
-            } else {
-                next.push(BudgetItem { category, limit });
+fn load_dashboard_cache() -> Option<DashboardCache> {
+    let key = dashboard_cache_key()?;
+    let storage = web_sys::window()?.local_storage().ok()??;
+    let raw = storage.get_item(&key).ok()??;
+    serde_json::from_str(&raw).ok()
+}
+
+fn save_dashboard_cache(cache: &DashboardCache) {
+    if let Some(key) = dashboard_cache_key() {
+        if let Some(window) = web_sys::window() {
+            if let Ok(Some(storage)) = window.local_storage() {
+                if let Ok(raw) = serde_json::to_string(cache) {
+                    let _ = storage.set_item(&key, &raw);
+                }
             }
+        }
+    }
+}
 
-            save_budgets(&next);
-            budgets.set(next);
-            budget_category.set("".to_string());
-            budget_limit.set("".to_string());
-            budget_error.set(None);
-        })
-    };
+/// Drops the cached Dashboard data; called before any transaction write so
+/// a later visit never starts from a list that is known to be out of date.
+fn invalidate_dashboard_cache() {
+    if let Some(key) = dashboard_cache_key() {
+        if let Some(window) = web_sys::window() {
+            if let Ok(Some(storage)) = window.local_storage() {
+                let _ = storage.remove_item(&key);
+            }
+        }
+    }
+}
 
-    let mut spent_by_category: HashMap<String, i64> = HashMap::new();
-    for (cat, amt) in (*category_totals).iter() {
-        spent_by_category.insert(cat.clone(), *amt);
+#[derive(Clone, Copy, PartialEq)]
+enum CacheStatus {
+    /// Cached data is on screen while the fresh fetch runs.
+    Refreshing,
+    Updated,
+    /// The fetch failed; cached data stays on screen.
+    Offline,
+}
+
+/// Seconds an API request may run unless Settings says otherwise.
+const DEFAULT_REQUEST_TIMEOUT_SECS: u32 = 15;
+/// Timeouts offered in Settings.
+const REQUEST_TIMEOUT_CHOICES: [u32; 4] = [10, 15, 30, 60];
+
+/// Why an API request produced no usable answer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum ApiError {
+    /// Nothing came back within the configured timeout.
+    Timeout,
+    /// The component that sent it unmounted; callers just return.
+    Cancelled,
+    /// The request could not be built or the server could not be reached.
+    Network,
+    /// The server answered with an error status or an unreadable body.
+    Failed,
+    /// The server answered, but the body wasn't in a shape the app knows.
+    Malformed,
+}
+
+impl ApiError {
+    fn message(self) -> &'static str {
+        match self {
+            ApiError::Timeout => "The server took too long to respond.",
+            ApiError::Cancelled => "The request was cancelled.",
+            ApiError::Network => "Couldn't reach the server.",
+            ApiError::Failed => "The server couldn't complete the request.",
+            ApiError::Malformed => "The server's response couldn't be read.",
+        }
     }
-    html! {
-        { page_shell(
-            "Budget Overview",
-            html! {},
-            html! {
-                <>
-                    <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
-                        <div class="bg-card rounded-[10px] p-6 border border-border">
-                            <p class="text-sm text-muted-foreground mb-2">{"Total Expenses"}</p>
-                            <h3 class="text-2xl font-bold text-foreground">{ format_currency(*total_spent, &currency_symbol) }</h3>
-                            <p class="text-xs text-muted-foreground mt-2">{"Sum of all expense transactions"}</p>
-                        </div>
+}
 
-                        <div class="bg-card rounded-[10px] p-6 border border-border">
-                            <p class="text-sm text-muted-foreground mb-2">{"Top Categories"}</p>
-                            <div class="space-y-2">
-                                { if *loading {
-                                    html! { <p class="text-sm text-muted-foreground">{"Loading..."}</p> }
-                                } else if category_totals.is_empty() {
-                                    html! { <p class="text-sm text-muted-foreground">{"No expense transactions yet."}</p> }
-                                } else {
-                                    html! {
-                                        <ul class="space-y-1">
-                                            { for category_totals.iter().take(5).map(|(cat, amt)| html! {
-                                                <li class="flex items-center justify-between text-sm">
-                                                    <span class="text-foreground">{ cat.clone() }</span>
-                                                    <span class="font-semibold">{ format_currency(*amt, &currency_symbol) }</span>
-                                                </li>
-                                            }) }
-                                        </ul>
-                                    }
-                                }}
-                            </div>
-                        </div>
-                    </div>
+/// Cancels the requests sent under it once `cancel` runs, which
+/// `use_request_scope` does when the owning component unmounts, so late
+/// responses never update a page that is gone.
+#[derive(Clone, Default)]
+struct RequestScope(Rc<RequestScopeState>);
+
+#[derive(Default)]
+struct RequestScopeState {
+    cancelled: Cell<bool>,
+    /// Requests still waiting; woken by `cancel` so they stop at once.
+    waiting: RefCell<Vec<Waker>>,
+}
 
-                    <div class="bg-card rounded-[10px] p-6 border border-border">
-                        <div class="flex items-center justify-between mb-4">
-                            <h3 class="font-bold text-foreground text-lg">{"Category Budgets"}</h3>
-                            <span class="text-xs text-muted-foreground">{"Set monthly limits"}</span>
-                        </div>
-                        <div class="grid grid-cols-1 md:grid-cols-3 gap-3 mb-4">
-                            <input placeholder="Category" value={(*budget_category).clone()} oninput={Callback::from({
-                                let budget_category = budget_category.clone();
-                                move |e: InputEvent| {
-                                    if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
-                                        budget_category.set(input.value());
-                                    }
-                                }
-                            })} class="p-2 border rounded" />
-                            <input placeholder={format!("Limit ({})", currency_symbol)} value={(*budget_limit).clone()} oninput={Callback::from({
-                                let budget_limit = budget_limit.clone();
-                                move |e: InputEvent| {
-                                    if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
-                                        budget_limit.set(input.value());
-                                    }
-                                }
-                            })} class="p-2 border rounded" />
-                            <button onclick={on_add_budget} class="bg-primary text-primary-foreground px-4 rounded">{"Save Budget"}</button>
-                        </div>
-                        {
-                            if let Some(msg) = &*budget_error {
-                                html! { <p class="text-sm text-red-500 mb-3">{ msg.clone() }</p> }
-                            } else { html!{} }
-                        }
-                        <div class="space-y-2">
-                            { if budgets.is_empty() {
-                                html! { <p class="text-sm text-muted-foreground">{"No budgets yet."}</p> }
-                            } else {
-                                html! {
-                                    <div class="space-y-2">
-                                        { for budgets.iter().map(|b| {
-                                            let spent = spent_by_category.get(&b.category).cloned().unwrap_or(0);
-                                            let remaining = (b.limit - spent).max(0);
-                                            let percent = if b.limit > 0 { (spent as f64 / b.limit as f64 * 100.0).round() as i64 } else { 0 };
-                                            html! {
-                                                <div class="flex flex-col gap-1 p-3 border rounded">
-                                                    <div class="flex items-center justify-between">
-                                                        <span class="font-semibold text-foreground">{ b.category.clone() }</span>
-                                                        <span class="text-sm text-muted-foreground">{ format!("{}% used", percent) }</span>
-                                                    </div>
-                                                    <div class="flex items-center justify-between text-sm">
-                                                        <span class="text-muted-foreground">{ format!("Spent: {}", format_currency(spent, &currency_symbol)) }</span>
-                                                        <span class="text-muted-foreground">{ format!("Remaining: {}", format_currency(remaining, &currency_symbol)) }</span>
-                                                    </div>
-                                                </div>
-                                            }
-                                        }) }
-                                    </div>
-                                }
-                            }}
-                        </div>
-                    </div>
+impl RequestScope {
+    fn cancel(&self) {
+        self.0.cancelled.set(true);
+        for waker in self.0.waiting.borrow_mut().drain(..) {
+            waker.wake();
+        }
+    }
 
-                    <div>
-                        <h2 class="text-lg font-bold text-foreground mb-3">{"Expense Breakdown"}</h2>
-                        <div class="bg-card rounded-[10px] border border-border overflow-hidden">
-                            <div class="p-6">
-                                { if category_totals.is_empty() {
-                                    html! { <p class="text-sm text-muted-foreground">{"Add expense transactions from the Dashboard to see breakdowns."}</p> }
-                                } else {
-                                    html! {
-                                        <div class="space-y-2">
-                                            { for category_totals.iter().map(|(cat, amt)| html! {
-                                                <div class="flex items-center justify-between text-sm">
-                                                    <span class="text-muted-foreground">{ cat.clone() }</span>
-                                                    <span class="font-semibold text-foreground">{ format_currency(*amt, &currency_symbol) }</span>
-                                                </div>
-                                            }) }
-                                        </div>
-                                    }
-                                }}
-                            </div>
-                        </div>
-                    </div>
-                </>
-            }
-        ) }
+    /// Whether the scope is cancelled; if not, `waker` is kept for `cancel`.
+    fn poll_cancelled(&self, waker: &Waker) -> bool {
+        if self.0.cancelled.get() {
+            return true;
+        }
+        let mut waiting = self.0.waiting.borrow_mut();
+        if !waiting.iter().any(|w| w.will_wake(waker)) {
+            waiting.push(waker.clone());
+        }
+        false
+    }
+
+    fn forget(&self, waker: &Waker) {
+        self.0.waiting.borrow_mut().retain(|w| !w.will_wake(waker));
     }
 }
 
-#[function_component(IncomePage)]
-fn income_page() -> Html {
-    let settings = use_context::<UseStateHandle<AppSettings>>();
-    let currency_symbol = settings
-        .as_ref()
-        .map(|s| s.currency_symbol.clone())
-        .unwrap_or_else(|| "₱".to_string());
+/// A `RequestScope` cancelled when the calling component unmounts.
+#[hook]
+fn use_request_scope() -> RequestScope {
+    let scope = use_state(RequestScope::default);
+    {
+        let scope = (*scope).clone();
+        use_effect_with_deps(move |_| move || scope.cancel(), ());
+    }
+    (*scope).clone()
+}
 
-    let incomes = use_state(|| Vec::<Transaction>::new());
-    let loading = use_state(|| true);
+/// How often the "Refreshed … ago" label re-renders.
+const REFRESH_LABEL_TICK_MS: u32 = 30_000;
+
+/// Coarse age of a fetch for the refresh label.
+fn time_ago(elapsed_ms: f64) -> String {
+    let minutes = (elapsed_ms / 60_000.0).floor() as i64;
+    match minutes {
+        i64::MIN..=0 => "just now".to_string(),
+        1..=59 => format!("{} min ago", minutes),
+        60..=1439 => format!("{} h ago", minutes / 60),
+        _ => format!("{} d ago", minutes / 1440),
+    }
+}
 
-    let form_date = use_state(|| "".to_string());
-    let form_amount = use_state(|| "".to_string());
-    let form_category = use_state(|| "Salary".to_string());
-    let form_description = use_state(|| "".to_string());
-    let form_error = use_state(|| None::<String>);
-    let saving = use_state(|| false);
+#[derive(Properties, PartialEq)]
+struct RefreshStatusProps {
+    /// `js_sys::Date::now()` of the last successful fetch.
+    refreshed_at: Option<f64>,
+    /// A fetch is in flight; the button is disabled meanwhile.
+    refreshing: bool,
+    on_refresh: Callback<()>,
+}
 
+/// "Refreshed 3 min ago" plus a button that refetches the page's data.
+#[function_component(RefreshStatus)]
+fn refresh_status(props: &RefreshStatusProps) -> Html {
+    // bumped on a timer so the label ages without a refetch
+    let tick = use_state(|| 0u32);
     {
-        let incomes = incomes.clone();
-        let loading = loading.clone();
+        let tick = tick.clone();
         use_effect_with_deps(
             move |_| {
-                spawn_local(async move {
-                    let url = format!("{}/api/transactions", API_BASE_URL);
-                    let mut req = Request::get(&url).credentials(RequestCredentials::Include);
-                    if let Some(window) = web_sys::window() {
-                        if let Ok(Some(storage)) = window.local_storage() {
-                            if let Ok(token_opt) = storage.get_item("access_token") {
-                                if let Some(token) = token_opt {
-                                    req = req.header("Authorization", &format!("Bearer {}", token));
-                                }
-                            }
-                        }
-                    }
-
-                    if let Ok(resp) = req.send().await {
-                        if resp.ok() {
-                            if let Ok(list) = resp.json::<Vec<Transaction>>().await {
-                                let filtered = list
-                                    .into_iter()
-                                    .filter(|t| t.amount > 0)
-                                    .collect::<Vec<_>>();
-                                incomes.set(filtered);
-                            }
-                        }
-                    }
-                    loading.set(false);
-                });
-                || ()
+                let interval = Interval::new(REFRESH_LABEL_TICK_MS, move || tick.set(*tick + 1));
+                move || drop(interval)
             },
             (),
         );
     }
+    let label = match (props.refreshing, props.refreshed_at) {
+        (true, _) => "Refreshing…".to_string(),
+        (false, Some(at)) => format!("Refreshed {}", time_ago(js_sys::Date::now() - at)),
+        (false, None) => String::new(),
+    };
+    let onclick = {
+        let on_refresh = props.on_refresh.clone();
+        Callback::from(move |_| on_refresh.emit(()))
+    };
+    html! {
+        <div class="flex items-center gap-2">
+            <span class="text-xs text-muted-foreground" role="status">{ label }</span>
+            <button type="button" {onclick} disabled={props.refreshing} class="p-2 border rounded text-sm disabled:opacity-50">{"Refresh"}</button>
+        </div>
+    }
+}
 
-    let total_balance: i64 = incomes.iter().map(|item| item.amount).sum();
+/// Shown when a page's first load fails. Retry bumps `attempt`, which the
+/// page's fetch effect lists as a dependency.
+fn load_error_banner(error: Option<ApiError>, attempt: &UseStateHandle<u32>) -> Html {
+    let Some(error) = error else {
+        return html! {};
+    };
+    let onclick = {
+        let attempt = attempt.clone();
+        Callback::from(move |_| attempt.set(*attempt + 1))
+    };
+    html! {
+        <div class="bg-card rounded-lg p-4 border border-red-200 flex items-center justify-between gap-4" role="alert">
+            <p class="text-sm text-red-600">{ error.message() }</p>
+            <button type="button" {onclick} class="px-3 py-1 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{"Retry"}</button>
+        </div>
+    }
+}
 
-    let on_add = {
-        let incomes = incomes.clone();
-        let form_date = form_date.clone();
-        let form_amount = form_amount.clone();
-        let form_category = form_category.clone();
-        let form_description = form_description.clone();
-        let form_error = form_error.clone();
-        let saving = saving.clone();
-        Callback::from(move |_| {
-            let date_val = form_date.trim().to_string();
-            let desc_val = form_description.trim().to_string();
-            let cat_val = form_category.trim().to_string();
-            let amt_val = form_amount.trim().to_string();
+/// Adds the session cookie and, when one is stored, the bearer token.
+fn api_request(builder: RequestBuilder) -> RequestBuilder {
+    let builder = builder.credentials(RequestCredentials::Include);
+    match token_store::get() {
+        Some(token) => builder.header("Authorization", &format!("Bearer {}", token)),
+        None => builder,
+    }
+}
 
-            if date_val.is_empty()
-                || desc_val.is_empty()
-                || cat_val.is_empty()
-                || amt_val.is_empty()
-            {
-                form_error.set(Some("Please complete all fields.".to_string()));
-                return;
-            }
+/// Sends a request built by `api_request`, racing it against the timeout
+/// from Settings and, when given, `scope`'s cancellation. It takes the
+/// `Result` of `.build()` or `.json(..)` as-is; one that failed to build is
+/// a `Network` error. Any status comes back as `Ok`, since some callers
+/// tell 4xx from 5xx.
+async fn send_api(
+    request: Result<Request, gloo_net::Error>,
+    scope: Option<&RequestScope>,
+) -> Result<Response, ApiError> {
+    let request = request.map_err(|_| ApiError::Network)?;
+    let timeout_ms = load_settings().request_timeout_secs.max(1) * 1000;
+    let mut send = std::pin::pin!(request.send());
+    let mut timeout = TimeoutFuture::new(timeout_ms);
+    let mut waker = None;
+    let result = std::future::poll_fn(|cx| {
+        waker = Some(cx.waker().clone());
+        if scope.is_some_and(|scope| scope.poll_cancelled(cx.waker())) {
+            return Poll::Ready(Err(ApiError::Cancelled));
+        }
+        if let Poll::Ready(result) = send.as_mut().poll(cx) {
+            return Poll::Ready(result.map_err(|_| ApiError::Network));
+        }
+        if Pin::new(&mut timeout).poll(cx).is_ready() {
+            return Poll::Ready(Err(ApiError::Timeout));
+        }
+        Poll::Pending
+    })
+    .await;
+    if let (Some(scope), Some(waker)) = (scope, &waker) {
+        scope.forget(waker);
+    }
+    result
+}
 
-            let parsed = amt_val.parse::<i64>().unwrap_or(0);
-            if parsed <= 0 {
-                form_error.set(Some("Amount must be a positive number.".to_string()));
-                return;
-            }
+/// POSTs a new transaction and returns the stored row.
+async fn post_transaction(payload: &serde_json::Value) -> Option<Transaction> {
+    let url = format!("{}/api/transactions", API_BASE_URL);
+    invalidate_dashboard_cache();
+    let request = api_request(Request::post(&url)).json(payload);
+    let resp = send_api(request, None).await.ok()?;
+    if !resp.ok() {
+        return None;
+    }
+    resp.json::<Transaction>().await.ok()
+}
 
-            form_error.set(None);
-            saving.set(true);
+/// PUTs `tx` as-is and returns the stored row; rows without an id are skipped.
+async fn put_transaction(tx: &Transaction) -> Option<Transaction> {
+    let url = format!("{}/api/transactions/{}", API_BASE_URL, tx.id?);
+    invalidate_dashboard_cache();
+    let request = api_request(Request::put(&url)).json(tx);
+    let resp = send_api(request, None).await.ok()?;
+    if !resp.ok() {
+        return None;
+    }
+    resp.json::<Transaction>().await.ok()
+}
 
-            let incomes = incomes.clone();
-            let form_date = form_date.clone();
-            let form_amount = form_amount.clone();
-            let form_category = form_category.clone();
-            let form_description = form_description.clone();
-            let saving = saving.clone();
-            spawn_local(async move {
-                let url = format!("{}/api/transactions", API_BASE_URL);
-                let payload = serde_json::json!({
-                    "date": date_val.as_str(),
-                    "description": desc_val.as_str(),
-                    "category": cat_val.as_str(),
-                    "amount": parsed
-                });
+/// Loads every transaction. Pages pass their `RequestScope` so leaving the
+/// page drops the request.
+async fn fetch_transactions(scope: Option<&RequestScope>) -> Result<Vec<Transaction>, ApiError> {
+    fetch_transaction_list(&format!("{}/api/transactions", API_BASE_URL), scope).await
+}
 
-                let mut builder = Request::post(&url).credentials(RequestCredentials::Include);
-                if let Some(window) = web_sys::window() {
-                    if let Ok(Some(storage)) = window.local_storage() {
-                        if let Ok(token_opt) = storage.get_item("access_token") {
-                            if let Some(token) = token_opt {
-                                builder =
-                                    builder.header("Authorization", &format!("Bearer {}", token));
-                            }
-                        }
-                    }
-                }
+/// Rows the Dashboard asks for per page.
+const TX_PAGE_SIZE: usize = 100;
+/// Each further page starts this many rows early, so a row added or deleted
+/// since the last page can't shift one out of reach; repeats are dropped by
+/// `merge_transaction_page`.
+const TX_PAGE_OVERLAP: usize = 5;
+
+/// A page of `GET /api/transactions?offset=&limit=`, newest first.
+struct TransactionPage {
+    rows: Vec<Transaction>,
+    /// The server ignored the paging params and sent the whole history.
+    full_history: bool,
+    /// Nothing older is left to ask for.
+    complete: bool,
+}
 
-                let builder = match builder.json(&payload) {
-                    Ok(b) => b,
-                    Err(_) => return,
-                };
+async fn fetch_transaction_page(
+    offset: usize,
+    limit: usize,
+    scope: Option<&RequestScope>,
+) -> Result<TransactionPage, ApiError> {
+    let url = format!(
+        "{}/api/transactions?offset={}&limit={}",
+        API_BASE_URL, offset, limit
+    );
+    let rows = fetch_transaction_list(&url, scope).await?;
+    // a server without paging answers with everything, which is more than asked
+    let full_history = rows.len() > limit;
+    Ok(TransactionPage {
+        complete: full_history || rows.len() < limit,
+        full_history,
+        rows,
+    })
+}
 
-                if let Ok(resp) = builder.send().await {
-                    if resp.ok() {
-                        if let Ok(created) = resp.json::<Transaction>().await {
-                            let mut next = (*incomes).clone();
-                            next.insert(0, created);
-                            incomes.set(next);
-                            form_date.set("".to_string());
-                            form_amount.set("".to_string());
-                            form_category.set("Salary".to_string());
-                            form_description.set("".to_string());
-                        }
-                    }
-                }
-                saving.set(false);
-            });
-        })
-    };
+/// Loads the newest pages until they reach back past the current budget
+/// period, so the month and budget cards see all of it. Returns the rows and
+/// whether older ones are left.
+async fn fetch_recent_transactions(
+    bounds: PeriodBounds,
+    scope: &RequestScope,
+) -> Result<(Vec<Transaction>, bool), ApiError> {
+    let period = current_month_key(bounds);
+    let mut loaded = Vec::new();
+    loop {
+        let offset = loaded.len().saturating_sub(TX_PAGE_OVERLAP);
+        let page = fetch_transaction_page(offset, TX_PAGE_SIZE, Some(scope)).await?;
+        if page.full_history {
+            return Ok((page.rows, false));
+        }
+        let before = loaded.len();
+        loaded = merge_transaction_page(&loaded, page.rows);
+        // a page with nothing new would only repeat itself
+        if page.complete || loaded.len() == before {
+            return Ok((loaded, false));
+        }
+        let past_period = loaded
+            .last()
+            .and_then(|tx| month_key(&tx.date, bounds))
+            .is_some_and(|key| key < period);
+        if past_period {
+            return Ok((loaded, true));
+        }
+    }
+}
 
-    let on_clear = {
-        let form_amount = form_amount.clone();
-        let form_description = form_description.clone();
-        let form_date = form_date.clone();
-        Callback::from(move |_| {
-            form_date.set("".to_string());
-            form_amount.set("".to_string());
-            form_description.set("".to_string());
-        })
+/// Appends an older page to `loaded`, skipping rows it already holds.
+fn merge_transaction_page(loaded: &[Transaction], page: Vec<Transaction>) -> Vec<Transaction> {
+    let known: HashSet<i32> = loaded.iter().filter_map(|tx| tx.id).collect();
+    let mut merged = loaded.to_vec();
+    merged.extend(
+        page.into_iter()
+            .filter(|tx| tx.id.is_none_or(|id| !known.contains(&id))),
+    );
+    merged
+}
+
+async fn fetch_transaction_list(
+    url: &str,
+    scope: Option<&RequestScope>,
+) -> Result<Vec<Transaction>, ApiError> {
+    let resp = send_api(api_request(Request::get(url)).build(), scope).await?;
+    if !resp.ok() {
+        return Err(ApiError::Failed);
+    }
+    let body = resp.text().await.map_err(|_| ApiError::Failed)?;
+    parse_transactions(&body).map_err(|err| {
+        web_sys::console::warn_1(&format!("unreadable transactions response: {}", err).into());
+        ApiError::Malformed
+    })
+}
+
+/// Shapes the transactions endpoint may answer with: a bare array, or an
+/// object wrapping it under `items` or `data` as paginated servers do.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TransactionsResponse {
+    List(Vec<Transaction>),
+    Items { items: Vec<Transaction> },
+    Data { data: Vec<Transaction> },
+}
+
+/// Reads a transactions response body in any `TransactionsResponse` shape.
+/// Anything else, such as an error object, is an error rather than an
+/// empty list.
+fn parse_transactions(body: &str) -> Result<Vec<Transaction>, serde_json::Error> {
+    Ok(match serde_json::from_str::<TransactionsResponse>(body)? {
+        TransactionsResponse::List(list)
+        | TransactionsResponse::Items { items: list }
+        | TransactionsResponse::Data { data: list } => list,
+    })
+}
+
+async fn delete_transaction(id: i32) -> bool {
+    let url = format!("{}/api/transactions/{}", API_BASE_URL, id);
+    invalidate_dashboard_cache();
+    let request = api_request(Request::delete(&url)).build();
+    matches!(send_api(request, None).await, Ok(resp) if resp.ok())
+}
+
+/// Checks split lines of `(category, amount)` against the magnitude of the
+/// original: at least two lines, each complete, adding up exactly.
+fn validate_split(parts: &[(String, i64)], total: i64) -> Result<(), String> {
+    if parts.len() < 2 {
+        return Err("A split needs at least two lines.".to_string());
+    }
+    if parts.iter().any(|(category, _)| category.is_empty()) {
+        return Err("Every line needs a category.".to_string());
+    }
+    if parts.iter().any(|(_, amount)| *amount <= 0) {
+        return Err("Every line needs an amount above zero.".to_string());
+    }
+    let assigned: i64 = parts.iter().map(|(_, amount)| amount).sum();
+    if assigned != total {
+        return Err("The lines must add up to the original amount.".to_string());
+    }
+    Ok(())
+}
+
+/// Replaces `original` with one row per part, each carrying the original's
+/// sign and details. Parts are created first and the original deleted last;
+/// if anything fails the created parts are removed again.
+async fn split_transaction(
+    original: &Transaction,
+    parts: &[(String, i64)],
+) -> Result<Vec<Transaction>, String> {
+    let Some(id) = original.id else {
+        return Err("Only saved transactions can be split.".to_string());
     };
+    let group = format!("split-{}", id);
+    let sign = if original.amount < 0 { -1 } else { 1 };
+    let mut created = Vec::new();
+    for (category, amount) in parts {
+        let payload = serde_json::json!({
+            "date": original.date.get(0..10).unwrap_or(&original.date),
+            "description": original.description.as_str(),
+            "category": category.as_str(),
+            "amount": sign * amount,
+            "note": original.note.as_deref(),
+            "receiptUrl": original.receipt_url.as_deref(),
+            "account": account_name(original),
+            "tax": original.tax,
+            "splitGroup": group.as_str(),
+            "flagged": original.flagged,
+            "currency": original.currency.as_deref()
+        });
+        match post_transaction(&payload).await {
+            Some(tx) => created.push(tx),
+            None => {
+                remove_created(&created).await;
+                return Err("Could not save the split.".to_string());
+            }
+        }
+    }
+    if !delete_transaction(id).await {
+        remove_created(&created).await;
+        return Err("Could not replace the original transaction.".to_string());
+    }
+    Ok(created)
+}
 
-    html! {
-        { page_shell(
-            "Income Tracker",
-            html! {},
-            html! {
-                <>
-                    <div class="grid grid-cols-1 lg:grid-cols-12 gap-4 items-stretch">
-                <div class="lg:col-span-4 bg-white p-5 rounded-[10px] shadow-sm border border-white/50 flex flex-col justify-center">
-                    <div class="flex items-center gap-2 mb-1">
-                        <div class="p-1.5 bg-[#f1f5f9] rounded-lg">{ icon_wallet() }</div>
-                        <span class="text-muted-foreground text-[10px] font-bold mb-1 tracking-widest">{"Total Available Balance"}</span>
-                    </div>
-                    <h3 class="text-2xl font-bold text-[#1D617A] tracking-tight">{ format_currency(total_balance, &currency_symbol) }</h3>
-                </div>
+async fn remove_created(created: &[Transaction]) {
+    for id in created.iter().filter_map(|tx| tx.id) {
+        delete_transaction(id).await;
+    }
+}
 
-                <div class="lg:col-span-8 bg-white p-5 rounded-[10px] shadow-sm border border-white/50">
-                    <h4 class="text-[#1D617A] font-bold text-[15px] mb-3 tracking-wider">{"Add New Income"}</h4>
-                    <div class="grid grid-cols-2 md:grid-cols-4 gap-3 mb-4">
-                        <div class="space-y-1">
-                            <label class="text-[12px] font-bold text-muted-foreground">{"Date"}</label>
-                            <input type="date" value={(*form_date).clone()} oninput={{
-                                let form_date = form_date.clone();
-                                Callback::from(move |e: InputEvent| {
-                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                    form_date.set(input.value());
-                                })
-                            }} class="w-full bg-[#f1f4f9] rounded-[10px] px-3 py-2 text-[11px] text-[#173E63] border-none" />
-                        </div>
-                        <div class="space-y-1">
-                            <label class="text-[12px] font-bold text-muted-foreground">{ format!("Amount ({})", currency_symbol) }</label>
-                            <input type="number" placeholder={format!("{} 0.00", currency_symbol)} value={(*form_amount).clone()} oninput={{
-                                let form_amount = form_amount.clone();
-                                Callback::from(move |e: InputEvent| {
-                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                    form_amount.set(input.value());
-                                })
-                            }} class="w-full bg-[#f1f4f9] rounded-[10px] px-3 py-2 text-[11px] text-[#173E63] border-none" />
-                        </div>
-                        <div class="space-y-1">
-                            <label class="text-[12px] font-bold text-muted-foreground">{"Description"}</label>
-                            <input type="text" placeholder="Income source" value={(*form_description).clone()} oninput={{
-                                let form_description = form_description.clone();
-                                Callback::from(move |e: InputEvent| {
-                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                    form_description.set(input.value());
-                                })
-                            }} class="w-full bg-[#f1f4f9] rounded-[10px] px-3 py-2 text-[11px] text-[#173E63] border-none" />
-                        </div>
-                        <div class="space-y-1">
-                            <label class="text-[12px] font-bold text-muted-foreground">{"Category"}</label>
-                            <select value={(*form_category).clone()} onchange={{
-                                let form_category = form_category.clone();
-                                Callback::from(move |e: Event| {
-                                    let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
-                                    form_category.set(input.value());
-                                })
-                            }} class="w-full bg-[#f1f4f9] border-2 border-transparent rounded-[10px] px-3 py-2 text-[11px] focus:ring-2 focus:ring-[#1D617A] outline-none">
-                                <option>{"Salary"}</option>
-                                <option>{"Freelance"}</option>
-                                <option>{"Investment"}</option>
-                            </select>
-                        </div>
-                    </div>
-                    <div class="flex gap-3">
-                        <button onclick={on_add} class="flex-1 bg-[#173E63] text-white py-2 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2" disabled={*saving}>{ if *saving { "Saving..." } else { "Add Income" } }</button>
-                        <button onclick={on_clear} class="flex-1 bg-[#B2CBDE] text-[#173E63] py-2 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2">{"Clear"}</button>
-                    </div>
-                    {
-                        if let Some(msg) = &*form_error {
-                            html! { <p class="text-sm text-red-500 mt-3">{ msg.clone() }</p> }
-                        } else {
-                            html! {}
+/// Split editor for `TransactionModal`: a category and amount per line, and
+/// how much of the original is still unassigned.
+fn split_editor(
+    lines: &UseStateHandle<Vec<(String, String)>>,
+    tx: &Transaction,
+    currency: &CurrencyFormat,
+) -> Html {
+    let total = tx.amount.abs();
+    let assigned: i64 = lines
+        .iter()
+        .filter_map(|(_, amount)| parse_entered_amount(amount, currency).ok())
+        .map(i64::abs)
+        .sum();
+    let remaining = total - assigned;
+    let places = currency.entry_places();
+    html! {
+        <div class="flex flex-col gap-3">
+            <p class="text-sm text-muted-foreground">
+                { format!("Divide {} across categories. The lines replace the original transaction.", format_currency(total, currency)) }
+            </p>
+            { for lines.iter().enumerate().map(|(idx, (category, amount))| {
+                let on_category = {
+                    let lines = lines.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        let mut next = (*lines).clone();
+                        next[idx].0 = input.value();
+                        lines.set(next);
+                    })
+                };
+                let on_amount = {
+                    let lines = lines.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        let cleaned = sanitize_amount_input(&input.value(), '.', places);
+                        if cleaned != input.value() {
+                            input.set_value(&cleaned);
                         }
-                    }
-                </div>
-            </div>
-                    <div class="bg-white rounded-[10px] shadow-sm border border-white/50 overflow-hidden">
-                        <div class="p-5 border-b border-border">
-                            <h3 class="font-bold text-lg text-foreground">{"Income History"}</h3>
-                        </div>
-                        <div class="overflow-x-auto">
-                            <table class="w-full text-left border-collapse">
-                                <thead>
-                                    <tr class="bg-muted text-muted-foreground text-[10px] uppercase tracking-widest">
-                                        <th class="px-8 py-4 font-bold">{"Date"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Description"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Category"}</th>
-                                        <th class="px-8 py-4 font-bold text-right">{"Amount"}</th>
-                                    </tr>
-                                </thead>
-                                <tbody class="divide-y divide-border">
-                                    { if *loading {
-                                        html! { <tr><td colspan="4" class="px-8 py-6 text-center text-muted-foreground">{"Loading..."}</td></tr> }
-                                    } else if incomes.is_empty() {
-                                        html! { <tr><td colspan="4" class="px-8 py-6 text-center text-muted-foreground">{"No income transactions yet."}</td></tr> }
-                                    } else {
-                                        html! {
-                                            <>
-                                                { for incomes.iter().enumerate().map(|(idx, item)| html! {
-                                                    <tr key={idx} class="text-sm hover:bg-muted/40 transition-colors group">
-                                                        <td class="px-8 py-4 text-muted-foreground">{ item.date.clone() }</td>
-                                                        <td class="px-8 py-4 text-foreground">{ item.description.clone() }</td>
-                                                        <td class="px-6 py-4">
-                                                            <span class="bg-secondary text-secondary-foreground px-2.5 py-1 rounded-md text-[9px] font-bold">{ item.category.clone() }</span>
-                                                        </td>
-                                                        <td class="px-6 py-4 text-right font-semibold text-foreground">{ format!("+ {}", format_currency(item.amount, &currency_symbol)) }</td>
-                                                    </tr>
-                                                }) }
-                                            </>
-                                        }
-                                    }}
-                                </tbody>
-                            </table>
-                        </div>
+                        let mut next = (*lines).clone();
+                        next[idx].1 = cleaned;
+                        lines.set(next);
+                    })
+                };
+                let on_remove = {
+                    let lines = lines.clone();
+                    Callback::from(move |_| {
+                        let mut next = (*lines).clone();
+                        next.remove(idx);
+                        lines.set(next);
+                    })
+                };
+                html! {
+                    <div class="grid grid-cols-[1fr_1fr_auto] gap-2 items-center">
+                        <input placeholder="Category" list={CATEGORY_LIST_ID} aria-label={format!("Line {} category", idx + 1)} value={category.clone()} oninput={on_category} class="p-2 border rounded" />
+                        <input type="text" inputmode="decimal" pattern={amount_pattern('.', places)} aria-label={format!("Line {} amount", idx + 1)} placeholder={currency.entry_placeholder(format!("Amount ({})", currency.symbol))} value={amount.clone()} oninput={on_amount} class="p-2 border rounded" />
+                        <button type="button" onclick={on_remove} disabled={lines.len() <= 2} class="text-xs text-muted-foreground hover:text-red-600 disabled:opacity-40">{"Remove"}</button>
                     </div>
-                </>
-            }
-        ) }
+                }
+            }) }
+            <p class={classes!("text-xs", "font-semibold", if remaining == 0 { "text-primary" } else { "text-red-600" })}>
+                { format!("Left to assign: {}", format_currency(remaining, currency)) }
+            </p>
+        </div>
     }
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct Contribution {
-    date: String,
-    description: String,
-    amount: i64,
+/// Header checkbox toggling every row that has a server id.
+fn select_all_checkbox(selected: &UseStateHandle<HashSet<i32>>, rows: &[Transaction]) -> Html {
+    let ids: HashSet<i32> = rows.iter().filter_map(|tx| tx.id).collect();
+    let all_selected = !ids.is_empty() && ids.iter().all(|id| selected.contains(id));
+    let onclick = {
+        let selected = selected.clone();
+        Callback::from(move |_| {
+            if all_selected {
+                selected.set(HashSet::new());
+            } else {
+                selected.set(ids.clone());
+            }
+        })
+    };
+    html! {
+        <input type="checkbox" aria-label="Select all" checked={all_selected} {onclick} />
+    }
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct SavingGoalState {
-    title: String,
-    target_amount: i64,
-    target_date: String,
-    contributions: Vec<Contribution>,
+/// Row checkbox; rows without an id (not yet stored) can't be selected.
+fn row_checkbox(selected: &UseStateHandle<HashSet<i32>>, tx: &Transaction) -> Html {
+    let id = tx.id;
+    let checked = id.map(|id| selected.contains(&id)).unwrap_or(false);
+    let onclick = {
+        let selected = selected.clone();
+        Callback::from(move |_| {
+            if let Some(id) = id {
+                let mut next = (*selected).clone();
+                if !next.remove(&id) {
+                    next.insert(id);
+                }
+                selected.set(next);
+            }
+        })
+    };
+    html! {
+        <input type="checkbox" aria-label={format!("Select {}", tx.description)} checked={checked} disabled={id.is_none()} {onclick} />
+    }
 }
 
-fn load_saving_goal() -> SavingGoalState {
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(Some(raw)) = storage.get_item("saving_goal") {
-                if let Ok(goal) = serde_json::from_str::<SavingGoalState>(&raw) {
-                    return goal;
+/// Deletes every selected row, then updates the list once.
+fn bulk_delete_callback(
+    rows: &UseStateHandle<Vec<Transaction>>,
+    selected: &UseStateHandle<HashSet<i32>>,
+    deleting: &UseStateHandle<bool>,
+) -> Callback<()> {
+    let rows = rows.clone();
+    let selected = selected.clone();
+    let deleting = deleting.clone();
+    Callback::from(move |_| {
+        if selected.is_empty() || *deleting {
+            return;
+        }
+
+        deleting.set(true);
+        let ids: Vec<i32> = selected.iter().copied().collect();
+        let rows = rows.clone();
+        let selected = selected.clone();
+        let deleting = deleting.clone();
+        spawn_local(async move {
+            let mut removed = HashSet::new();
+            for id in ids {
+                if delete_transaction(id).await {
+                    removed.insert(id);
                 }
             }
+            let next = rows
+                .iter()
+                .filter(|tx| tx.id.map(|id| !removed.contains(&id)).unwrap_or(true))
+                .cloned()
+                .collect::<Vec<_>>();
+            rows.set(next);
+            // anything that failed stays selected so it can be retried
+            let remaining = selected
+                .iter()
+                .filter(|id| !removed.contains(id))
+                .copied()
+                .collect::<HashSet<_>>();
+            selected.set(remaining);
+            deleting.set(false);
+        });
+    })
+}
+
+/// Parses a budget limit typed with the user's grouping, e.g. `5,000` or
+/// `5.000,50`, and requires it to be positive.
+fn parse_limit(raw: &str, grouping: GroupingStyle) -> Result<i64, String> {
+    let (group, decimal) = grouping.separators();
+    let cleaned: String = raw
+        .trim()
+        .chars()
+        .filter(|c| *c != group && !c.is_whitespace())
+        .map(|c| if c == decimal { '.' } else { c })
+        .collect();
+    if cleaned.is_empty() {
+        return Err("Enter a limit.".to_string());
+    }
+    match parse_amount(&cleaned) {
+        Ok(limit) if limit > 0 => Ok(limit),
+        Ok(_) => Err("Limit must be a positive number.".to_string()),
+        Err(_) if has_fraction(&cleaned) => {
+            Err("Limits are saved in whole units. Leave out the decimals.".to_string())
         }
+        Err(_) => Err("Limit must be a number, like 5000.".to_string()),
     }
+}
 
-    SavingGoalState {
-        title: "New Goal".to_string(),
-        target_amount: 0,
-        target_date: "".to_string(),
-        contributions: vec![],
+/// Parses a user-entered amount such as `1500`, `-250` or `12.00`. Amounts
+/// are stored in whole units, so a fraction such as `12.50` is rejected
+/// rather than rounded.
+fn parse_amount(raw: &str) -> Result<i64, String> {
+    let units = parse_rounded_amount(raw)?;
+    if has_fraction(raw.trim()) {
+        return Err(format!(
+            "Amounts are saved in whole units, so \"{}\" can't be saved. Leave out the decimals.",
+            raw.trim()
+        ));
     }
+    Ok(units)
 }
 
-fn save_saving_goal(goal: &SavingGoalState) {
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(raw) = serde_json::to_string(goal) {
-                let _ = storage.set_item("saving_goal", &raw);
-            }
-        }
+/// Like `parse_amount`, but a fraction rounds half away from zero. For bank
+/// exports, whose amounts carry cents the app doesn't store.
+fn parse_rounded_amount(raw: &str) -> Result<i64, String> {
+    let value = raw.trim();
+    if value.is_empty() {
+        return Err("Enter an amount.".to_string());
+    }
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    if unsigned.contains(['e', 'E']) {
+        return Err(format!(
+            "\"{}\" uses scientific notation. Write the amount out in full.",
+            value
+        ));
+    }
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(format!(
+            "\"{}\" is not a number. Use digits like 1500 or -250.50.",
+            value
+        ));
+    }
+    let mut units = if whole.is_empty() {
+        0
+    } else {
+        whole
+            .parse::<i64>()
+            .map_err(|_| "That amount is too large.".to_string())?
+    };
+    if fraction.starts_with(['5', '6', '7', '8', '9']) {
+        units = units
+            .checked_add(1)
+            .ok_or_else(|| "That amount is too large.".to_string())?;
     }
+    Ok(if negative { -units } else { units })
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct BudgetItem {
-    category: String,
-    limit: i64,
+/// Rewrites whole minor units typed in cents mode as a decimal using
+/// `decimal`: `1250` → `12.50`, `-5` → `-0.05`. Anything that isn't a signed
+/// run of digits is returned trimmed, for the parser to reject.
+fn cents_to_decimal(raw: &str, decimal: char, places: u32) -> String {
+    let value = raw.trim();
+    let (sign, digits) = match value.strip_prefix(['-', '+']) {
+        Some(rest) => (&value[..1], rest),
+        None => ("", value),
+    };
+    if places == 0 || digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return value.to_string();
+    }
+    let padded = format!("{:0>width$}", digits, width = places as usize + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - places as usize);
+    format!("{}{}{}{}", sign, whole, decimal, fraction)
 }
 
-fn load_budgets() -> Vec<BudgetItem> {
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(Some(raw)) = storage.get_item("budgets") {
-                if let Ok(items) = serde_json::from_str::<Vec<BudgetItem>>(&raw) {
-                    return items;
-                }
-            }
+/// Whether a decimal such as `12.50` has a fraction other than zeros.
+fn has_fraction(decimal: &str) -> bool {
+    decimal.split_once('.').is_some_and(|(_, fraction)| {
+        fraction.chars().all(|c| c.is_ascii_digit()) && fraction.chars().any(|c| c != '0')
+    })
+}
+
+/// Parses an amount field with `parse_amount`, reading it as cents when
+/// the user enters amounts that way. Amounts are stored in whole units, so
+/// cents that don't make a whole unit are rejected rather than rounded.
+fn parse_entered_amount(raw: &str, currency: &CurrencyFormat) -> Result<i64, String> {
+    if currency.cents_entry() {
+        let decimal = cents_to_decimal(raw, '.', currency.decimal_places);
+        if has_fraction(&decimal) {
+            return Err(format!(
+                "Amounts are saved in whole units, so cents must end in {}, like {}.",
+                "0".repeat(currency.decimal_places as usize),
+                currency.entry_text(12)
+            ));
         }
+        parse_amount(&decimal)
+    } else {
+        parse_amount(raw)
     }
+}
 
-    vec![]
+/// Live reading of an amount field, e.g. "= ₱1,250.00"; blank until the
+/// field holds an amount that can be saved.
+fn entry_preview(raw: &str, currency: &CurrencyFormat) -> Html {
+    let Ok(amount) = parse_entered_amount(raw, currency) else {
+        return html! {};
+    };
+    html! {
+        <p class="text-xs text-muted-foreground" aria-live="polite">{ format!("= {}", format_currency(amount, currency)) }</p>
+    }
 }
 
-fn save_budgets(items: &Vec<BudgetItem>) {
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(raw) = serde_json::to_string(items) {
-                let _ = storage.set_item("budgets", &raw);
+/// Cleans an amount as it is typed: keeps digits, a leading sign and one
+/// `decimal` separator, and drops fraction digits past `decimal_places`.
+fn sanitize_amount_input(raw: &str, decimal: char, decimal_places: u32) -> String {
+    let mut cleaned = String::new();
+    let mut fraction_digits: Option<u32> = None;
+    for c in raw.trim().chars() {
+        match (c, fraction_digits) {
+            ('-' | '+', _) if cleaned.is_empty() => cleaned.push(c),
+            (c, None) if c == decimal && decimal_places > 0 => {
+                cleaned.push(c);
+                fraction_digits = Some(0);
+            }
+            (c, None) if c.is_ascii_digit() => cleaned.push(c),
+            (c, Some(n)) if c.is_ascii_digit() && n < decimal_places => {
+                cleaned.push(c);
+                fraction_digits = Some(n + 1);
             }
+            _ => {}
         }
     }
+    cleaned
 }
 
-#[function_component(ExpensePage)]
-fn expense_page() -> Html {
-    let settings = use_context::<UseStateHandle<AppSettings>>();
-    let currency_symbol = settings
-        .as_ref()
-        .map(|s| s.currency_symbol.clone())
-        .unwrap_or_else(|| "₱".to_string());
-    let expenses = use_state(|| Vec::<Transaction>::new());
-    let loading = use_state(|| true);
+/// `pattern` attribute matching what `sanitize_amount_input` lets through.
+fn amount_pattern(decimal: char, decimal_places: u32) -> String {
+    if decimal_places == 0 {
+        "[-+]?[0-9]*".to_string()
+    } else {
+        format!("[-+]?[0-9]*[{}]?[0-9]{{0,{}}}", decimal, decimal_places)
+    }
+}
 
-    let form_date = use_state(|| "".to_string());
-    let form_amount = use_state(|| "".to_string());
-    let form_category = use_state(|| "Transportation".to_string());
-    let form_description = use_state(|| "".to_string());
-    let form_error = use_state(|| None::<String>);
-    let saving = use_state(|| false);
+/// `oninput` for an amount field. The cleaned value is written back to the
+/// element too, since an unchanged state would not re-render it.
+fn amount_oninput(
+    state: &UseStateHandle<String>,
+    decimal: char,
+    decimal_places: u32,
+) -> Callback<InputEvent> {
+    let state = state.clone();
+    Callback::from(move |e: InputEvent| {
+        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+        let cleaned = sanitize_amount_input(&input.value(), decimal, decimal_places);
+        if cleaned != input.value() {
+            input.set_value(&cleaned);
+        }
+        state.set(cleaned);
+    })
+}
 
-    {
-        let expenses = expenses.clone();
-        let loading = loading.clone();
-        use_effect_with_deps(
-            move |_| {
-                spawn_local(async move {
-                    let url = format!("{}/api/transactions", API_BASE_URL);
-                    let mut req = Request::get(&url).credentials(RequestCredentials::Include);
-                    if let Some(window) = web_sys::window() {
-                        if let Ok(Some(storage)) = window.local_storage() {
-                            if let Ok(token_opt) = storage.get_item("access_token") {
-                                if let Some(token) = token_opt {
-                                    req = req.header("Authorization", &format!("Bearer {}", token));
-                                }
-                            }
-                        }
-                    }
+/// Parses an optional filter bound; blank or invalid input means "no bound".
+fn parse_bound(value: &str) -> Option<i64> {
+    value.trim().parse::<i64>().ok().map(i64::abs)
+}
 
-                    if let Ok(resp) = req.send().await {
-                        if resp.ok() {
-                            if let Ok(list) = resp.json::<Vec<Transaction>>().await {
-                                let filtered = list
-                                    .into_iter()
-                                    .filter(|t| t.amount < 0)
-                                    .collect::<Vec<_>>();
-                                expenses.set(filtered);
-                            }
-                        }
-                    }
-                    loading.set(false);
-                });
-                || ()
-            },
-            (),
-        );
-    }
+/// Typing pause before a transaction search is applied.
+const SEARCH_DEBOUNCE_MS: u32 = 200;
 
-    let total_expense: i64 = expenses.iter().map(|item| item.amount.abs()).sum();
+/// Fields a transaction search looks in, in the order a match is reported.
+#[derive(Clone, Copy, PartialEq)]
+enum SearchField {
+    Description,
+    Note,
+    Category,
+}
 
-    let on_add = {
-        let expenses = expenses.clone();
-        let form_date = form_date.clone();
-        let form_amount = form_amount.clone();
-        let form_category = form_category.clone();
-        let form_description = form_description.clone();
-        let form_error = form_error.clone();
-        let saving = saving.clone();
-        Callback::from(move |_| {
-            let date_val = form_date.trim().to_string();
-            let desc_val = form_description.trim().to_string();
-            let cat_val = form_category.trim().to_string();
-            let amt_val = form_amount.trim().to_string();
+impl SearchField {
+    const ALL: [SearchField; 3] = [
+        SearchField::Description,
+        SearchField::Note,
+        SearchField::Category,
+    ];
 
-            if date_val.is_empty()
-                || desc_val.is_empty()
-                || cat_val.is_empty()
-                || amt_val.is_empty()
-            {
-                form_error.set(Some("Please complete all fields.".to_string()));
-                return;
-            }
+    fn label(self) -> &'static str {
+        match self {
+            SearchField::Description => "description",
+            SearchField::Note => "note",
+            SearchField::Category => "category",
+        }
+    }
 
-            let parsed = amt_val.parse::<i64>().unwrap_or(0);
-            if parsed <= 0 {
-                form_error.set(Some("Amount must be a positive number.".to_string()));
-                return;
-            }
+    fn text(self, tx: &Transaction) -> &str {
+        match self {
+            SearchField::Description => &tx.description,
+            SearchField::Note => tx.note.as_deref().unwrap_or_default(),
+            SearchField::Category => &tx.category,
+        }
+    }
+}
 
-            form_error.set(None);
-            saving.set(true);
+/// Lowercased searchable fields per transaction, built once per loaded list
+/// so each keystroke only does substring checks over long histories.
+#[derive(PartialEq)]
+struct SearchIndex {
+    entries: Vec<[String; 3]>,
+}
 
-            let expenses = expenses.clone();
-            let form_date = form_date.clone();
-            let form_amount = form_amount.clone();
-            let form_category = form_category.clone();
-            let form_description = form_description.clone();
-            let saving = saving.clone();
-            spawn_local(async move {
-                let url = format!("{}/api/transactions", API_BASE_URL);
-                let payload = serde_json::json!({
-                    "date": date_val.as_str(),
-                    "description": desc_val.as_str(),
-                    "category": cat_val.as_str(),
-                    "amount": -parsed
-                });
+impl SearchIndex {
+    fn build(rows: &[Transaction]) -> Self {
+        SearchIndex {
+            entries: rows
+                .iter()
+                .map(|tx| SearchField::ALL.map(|field| field.text(tx).to_lowercase()))
+                .collect(),
+        }
+    }
 
-                let mut builder = Request::post(&url).credentials(RequestCredentials::Include);
-                if let Some(window) = web_sys::window() {
-                    if let Ok(Some(storage)) = window.local_storage() {
-                        if let Ok(token_opt) = storage.get_item("access_token") {
-                            if let Some(token) = token_opt {
-                                builder =
-                                    builder.header("Authorization", &format!("Bearer {}", token));
-                            }
-                        }
-                    }
-                }
+    /// Whether row `idx` of the indexed list contains `query`, which is
+    /// already lowercased. An empty query matches everything.
+    fn matches(&self, idx: usize, query: &str) -> bool {
+        query.is_empty()
+            || self
+                .entries
+                .get(idx)
+                .is_some_and(|entry| entry.iter().any(|text| text.contains(query)))
+    }
+}
 
-                let builder = match builder.json(&payload) {
-                    Ok(b) => b,
-                    Err(_) => return,
-                };
+/// First field of `tx` containing `query`, for labelling a search result.
+fn search_match(tx: &Transaction, query: &str) -> Option<SearchField> {
+    if query.is_empty() {
+        return None;
+    }
+    SearchField::ALL
+        .into_iter()
+        .find(|field| field.text(tx).to_lowercase().contains(query))
+}
 
-                if let Ok(resp) = builder.send().await {
-                    if resp.ok() {
-                        if let Ok(created) = resp.json::<Transaction>().await {
-                            let mut next = (*expenses).clone();
-                            next.insert(0, created);
-                            expenses.set(next);
-                            form_date.set("".to_string());
-                            form_amount.set("".to_string());
-                            form_category.set("Transportation".to_string());
-                            form_description.set("".to_string());
-                        }
-                    }
-                }
-                saving.set(false);
-            });
+/// Search box whose query reaches `query` only after a pause in typing.
+fn search_input(
+    input: &UseStateHandle<String>,
+    query: &UseStateHandle<String>,
+    timer: &Rc<RefCell<Option<Timeout>>>,
+) -> Html {
+    let oninput = {
+        let input = input.clone();
+        let query = query.clone();
+        let timer = timer.clone();
+        Callback::from(move |e: InputEvent| {
+            let field: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = field.value();
+            input.set(value.clone());
+            let query = query.clone();
+            // replacing the timer cancels the previous one
+            *timer.borrow_mut() = Some(Timeout::new(SEARCH_DEBOUNCE_MS, move || {
+                query.set(value.trim().to_lowercase());
+            }));
         })
     };
+    html! {
+        <input type="search" placeholder="Search description, note, category" aria-label="Search transactions" value={(**input).clone()} {oninput} class="w-56 p-2 border rounded text-sm" />
+    }
+}
 
-    let on_clear = {
-        let form_amount = form_amount.clone();
-        let form_description = form_description.clone();
-        let form_date = form_date.clone();
-        Callback::from(move |_| {
-            form_date.set("".to_string());
-            form_amount.set("".to_string());
-            form_description.set("".to_string());
-        })
-    };
+/// "in note" marker under a search result.
+fn search_match_label(tx: &Transaction, query: &str) -> Html {
+    match search_match(tx, query) {
+        Some(field) => html! {
+            <span class="block text-[10px] font-bold uppercase tracking-wider text-primary">{ format!("Matched in {}", field.label()) }</span>
+        },
+        None => html! {},
+    }
+}
+
+/// Matches on the absolute amount so one range works for income and expenses.
+fn within_amount_range(tx: &Transaction, (min, max): (Option<i64>, Option<i64>)) -> bool {
+    let amount = tx.amount.abs();
+    min.map(|min| amount >= min).unwrap_or(true) && max.map(|max| amount <= max).unwrap_or(true)
+}
 
+fn amount_range_inputs(min: &UseStateHandle<String>, max: &UseStateHandle<String>) -> Html {
+    // bounds are whole units, so no decimals
+    let on_min = amount_oninput(min, '.', 0);
+    let on_max = amount_oninput(max, '.', 0);
     html! {
-        { page_shell(
-            "Expense Tracker",
-            html! {},
-            html! {
-                <>
-                    <div class="grid grid-cols-1 lg:grid-cols-12 gap-6 items-stretch">
-                        <div class="lg:col-span-4 bg-white p-5 rounded-[10px] shadow-sm border border-white/50 flex flex-col justify-center">
-                            <div class="flex items-center gap-2 mb-1">
-                                <div class="p-1.5 bg-[#f1f5f9] rounded-lg">{ icon_credit_card() }</div>
-                                <span class="text-muted-foreground text-[10px] font-bold mb-1 tracking-widest">{"Total Expenses"}</span>
-                            </div>
-                            <h3 class="text-2xl font-bold text-[#1D617A] tracking-tight">{ format_currency(total_expense, &currency_symbol) }</h3>
-                        </div>
+        <div class="flex items-center gap-1">
+            <input type="text" inputmode="numeric" pattern="[0-9]*" placeholder="Min" aria-label="Minimum amount" value={(**min).clone()} oninput={on_min} class="w-20 p-2 border rounded text-sm" />
+            <span class="text-muted-foreground text-xs">{"–"}</span>
+            <input type="text" inputmode="numeric" pattern="[0-9]*" placeholder="Max" aria-label="Maximum amount" value={(**max).clone()} oninput={on_max} class="w-20 p-2 border rounded text-sm" />
+        </div>
+    }
+}
 
-                        <div class="lg:col-span-8 bg-white p-5 rounded-[10px] shadow-sm border border-white/50">
-                            <h4 class="text-[#1D617A] font-bold text-[15px] mb-3 tracking-wider">{"Add New Expense"}</h4>
-                            <div class="grid grid-cols-2 md:grid-cols-4 gap-3 mb-4">
-                                <div class="space-y-1">
-                                    <label class="text-[12px] font-bold text-muted-foreground">{"Date"}</label>
-                                    <input type="date" value={(*form_date).clone()} oninput={{
-                                        let form_date = form_date.clone();
-                                        Callback::from(move |e: InputEvent| {
-                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                            form_date.set(input.value());
-                                        })
-                                    }} class="w-full bg-[#f1f4f9] rounded-[10px] px-3 py-2 text-[11px] text-[#173E63] border-none" />
-                                </div>
-                                <div class="space-y-1">
-                                    <label class="text-[12px] font-bold text-muted-foreground">{ format!("Amount ({})", currency_symbol) }</label>
-                                    <input type="number" placeholder={format!("{} 0.00", currency_symbol)} value={(*form_amount).clone()} oninput={{
-                                        let form_amount = form_amount.clone();
-                                        Callback::from(move |e: InputEvent| {
-                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                            form_amount.set(input.value());
-                                        })
-                                    }} class="w-full bg-[#f1f4f9] rounded-[10px] px-3 py-2 text-[11px] text-[#173E63] border-none" />
-                                </div>
-                                <div class="space-y-1">
-                                    <label class="text-[12px] font-bold text-muted-foreground">{"Description"}</label>
-                                    <input type="text" placeholder="Expense description" value={(*form_description).clone()} oninput={{
-                                        let form_description = form_description.clone();
-                                        Callback::from(move |e: InputEvent| {
-                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                            form_description.set(input.value());
-                                        })
-                                    }} class="w-full bg-[#f1f4f9] rounded-[10px] px-3 py-2 text-[11px] text-[#173E63] border-none" />
-                                </div>
-                                <div class="space-y-1">
-                                    <label class="text-[12px] font-bold text-muted-foreground">{"Category"}</label>
-                                    <input type="text" placeholder="Category" value={(*form_category).clone()} oninput={{
-                                        let form_category = form_category.clone();
-                                        Callback::from(move |e: InputEvent| {
-                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                            form_category.set(input.value());
-                                        })
-                                    }} class="w-full bg-[#f1f4f9] rounded-[10px] px-3 py-2 text-[11px] text-[#173E63] border-none" />
-                                </div>
-                            </div>
-                            <div class="flex gap-3">
-                                <button onclick={on_add} class="flex-1 bg-[#173E63] text-white py-2 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2" disabled={*saving}>{ if *saving { "Saving..." } else { "Add Expense" } }</button>
-                                <button onclick={on_clear} class="flex-1 bg-[#B2CBDE] text-[#173E63] py-2 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2">{"Clear"}</button>
-                            </div>
-                            {
-                                if let Some(msg) = &*form_error {
-                                    html! { <p class="text-sm text-red-500 mt-3">{ msg.clone() }</p> }
-                                } else { html!{} }
-                            }
-                        </div>
-                    </div>
-                    <div class="bg-card rounded-2xl shadow-md border border-border overflow-hidden">
-                        <div class="p-5 border-b border-border">
-                            <h3 class="font-bold text-lg text-foreground">{"Expenses History"}</h3>
-                        </div>
-                        <div class="overflow-x-auto">
-                            <table class="w-full text-left border-collapse">
-                                <thead>
-                                    <tr class="bg-muted text-muted-foreground text-[10px] uppercase tracking-widest">
-                                        <th class="px-8 py-4 font-bold">{"Date"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Description"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Category"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Amount"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Action"}</th>
-                                    </tr>
-                                </thead>
-                                <tbody class="divide-y divide-border">
-                                    { if *loading {
-                                        html! { <tr><td colspan="5" class="px-8 py-6 text-center text-muted-foreground">{"Loading..."}</td></tr> }
-                                    } else if expenses.is_empty() {
-                                        html! { <tr><td colspan="5" class="px-8 py-6 text-center text-muted-foreground">{"No expense transactions yet."}</td></tr> }
-                                    } else {
-                                        html! {
-                                            <>
-                                                { for expenses.iter().enumerate().map(|(idx, item)| html! {
-                                                    <tr key={idx} class="text-sm hover:bg-muted/40 transition-colors group">
-                                                        <td class="px-8 py-4 text-muted-foreground">{ item.date.clone() }</td>
-                                                        <td class="px-8 py-4 text-foreground">{ item.description.clone() }</td>
-                                                        <td class="px-8 py-4">
-                                                            <span class="bg-secondary text-secondary-foreground px-3 py-1 rounded-full text-[10px] font-bold">{ item.category.clone() }</span>
-                                                        </td>
-                                                        <td class="px-8 py-4 font-semibold text-foreground">{ format_currency(item.amount, &currency_symbol) }</td>
-                                                        <td class="px-8 py-4"></td>
-                                                    </tr>
-                                                }) }
-                                            </>
-                                        }
-                                    }}
-                                </tbody>
-                            </table>
-                        </div>
-                    </div>
-                </>
-            }
-        ) }
+fn duplicate_button(on_duplicate: &Callback<Transaction>, tx: &Transaction) -> Html {
+    let onclick = {
+        let on_duplicate = on_duplicate.clone();
+        let tx = tx.clone();
+        Callback::from(move |_| on_duplicate.emit(tx.clone()))
+    };
+    html! {
+        <button type="button" {onclick} aria-label={format!("Duplicate {}", tx.description)} class="text-xs font-bold text-primary hover:underline">{"Duplicate"}</button>
     }
 }
 
-#[function_component(SavingsPage)]
-fn savings_page() -> Html {
-    let is_creating = use_state(|| false);
-    let settings = use_context::<UseStateHandle<AppSettings>>();
-    let currency_symbol = settings
-        .as_ref()
-        .map(|s| s.currency_symbol.clone())
-        .unwrap_or_else(|| "₱".to_string());
+/// Screen-reader wording for an amount, so direction isn't carried only by
+/// a `+`/`-` or colour: "expense of ₱500.00".
+fn amount_description(tx: &Transaction, currency: &CurrencyFormat) -> String {
+    let kind = if is_transfer(tx) {
+        "transfer"
+    } else if tx.amount < 0 {
+        "expense"
+    } else {
+        "income"
+    };
+    let currency = currency.for_code(tx.currency.as_deref());
+    format!(
+        "{} of {}",
+        kind,
+        format_currency(tx.amount.abs(), &currency)
+    )
+}
 
-    let goal = use_state(load_saving_goal);
-    let contrib_date = use_state(|| "".to_string());
-    let contrib_amount = use_state(|| "".to_string());
-    let contrib_desc = use_state(|| "".to_string());
-    let new_goal_title = use_state(|| "".to_string());
-    let new_goal_amount = use_state(|| "".to_string());
-    let new_goal_date = use_state(|| "".to_string());
+/// Shows the amount while exposing `amount_description` to assistive tech.
+fn amount_cell(tx: &Transaction, currency: &CurrencyFormat, show_plus: bool) -> Html {
+    html! {
+        <>
+            <span aria-hidden="true">
+                <CurrencyAmount amount={tx.amount} currency={currency.clone()} code={tx.currency.clone()} {show_plus} />
+            </span>
+            <span class="sr-only">{ amount_description(tx, currency) }</span>
+        </>
+    }
+}
 
-    let saved_so_far: i64 = goal.contributions.iter().map(|c| c.amount).sum();
-    let progress = if goal.target_amount > 0 {
-        saved_so_far as f64 / goal.target_amount as f64
+#[derive(Properties, PartialEq)]
+struct CurrencyAmountProps {
+    amount: i64,
+    currency: CurrencyFormat,
+    /// Prefix positive amounts with `+` (and colour them green), as the
+    /// income-style lists do.
+    #[prop_or_default]
+    show_plus: bool,
+    /// Currency the amount is stored in, shown with its own symbol when it
+    /// isn't the app currency.
+    #[prop_or_default]
+    code: Option<String>,
+}
+
+/// An amount in the user's currency format. Negative amounts (and signed
+/// positive ones) carry the sign cue from Settings, so the surrounding
+/// element only sets size and weight.
+#[function_component(CurrencyAmount)]
+fn currency_amount(props: &CurrencyAmountProps) -> Html {
+    let currency = props.currency.for_code(props.code.as_deref());
+    let text = if props.show_plus {
+        format_signed_currency(props.amount, &currency)
     } else {
-        0.0
+        format_currency(props.amount, &currency)
     };
-    let radius = 38.0;
-    let circumference = 2.0 * std::f64::consts::PI * radius;
-    let offset = circumference - progress.min(1.0) * circumference;
+    let good = if props.amount < 0 {
+        Some(false)
+    } else if props.show_plus && props.amount > 0 {
+        Some(true)
+    } else {
+        None
+    };
+    let color = good.and_then(|good| currency.sign_color(good));
+    let symbol = match good {
+        Some(good) if currency.sign_cue.shows_symbols() => {
+            html! { <span aria-hidden="true">{ if good { "▲ " } else { "▼ " } }</span> }
+        }
+        _ => html! {},
+    };
+    html! { <span class={classes!(color)}>{ symbol }{ text }</span> }
+}
 
-    let toggle_create = {
-        let is_creating = is_creating.clone();
-        let goal = goal.clone();
-        let new_goal_title = new_goal_title.clone();
-        let new_goal_amount = new_goal_amount.clone();
-        let new_goal_date = new_goal_date.clone();
+/// The "N selected / Delete selected" bar; deleting asks for confirmation first.
+fn bulk_actions_bar(
+    count: usize,
+    deleting: bool,
+    confirm_open: &UseStateHandle<bool>,
+    on_delete: &Callback<()>,
+) -> Html {
+    if count == 0 {
+        return html! {};
+    }
+    let on_request = {
+        let confirm_open = confirm_open.clone();
+        Callback::from(move |_| confirm_open.set(true))
+    };
+    let on_confirm = {
+        let confirm_open = confirm_open.clone();
+        let on_delete = on_delete.clone();
         Callback::from(move |_| {
-            if !*is_creating {
-                new_goal_title.set(goal.title.clone());
-                new_goal_amount.set(goal.target_amount.to_string());
-                new_goal_date.set(goal.target_date.clone());
-            }
-            is_creating.set(!*is_creating)
+            confirm_open.set(false);
+            on_delete.emit(());
         })
     };
-
-    let add_contribution = {
-        let goal = goal.clone();
-        let contrib_date = contrib_date.clone();
-        let contrib_amount = contrib_amount.clone();
-        let contrib_desc = contrib_desc.clone();
-        Callback::from(move |_| {
-            let parsed = contrib_amount.parse::<i64>().unwrap_or(0);
-            if parsed <= 0 {
-                return;
+    let on_cancel = {
+        let confirm_open = confirm_open.clone();
+        Callback::from(move |_| confirm_open.set(false))
+    };
+    html! {
+        <div class="flex items-center gap-3 text-sm">
+            <span class="text-muted-foreground">{ format!("{} selected", count) }</span>
+            <button type="button" onclick={on_request} disabled={deleting} class="px-3 py-1 rounded-[10px] text-xs font-bold bg-red-600 text-white">
+                { if deleting { "Deleting..." } else { "Delete selected" } }
+            </button>
+            if **confirm_open {
+                <ConfirmModal
+                    title="Delete transactions"
+                    message={format!("Delete {} selected transaction(s)? This cannot be undone.", count)}
+                    confirm_label="Delete"
+                    destructive=true
+                    {on_confirm}
+                    {on_cancel}
+                />
             }
-            let mut next_goal = (*goal).clone();
-            let entry = Contribution {
-                date: contrib_date.to_string(),
-                description: if contrib_desc.is_empty() {
-                    "Contribution".into()
-                } else {
-                    contrib_desc.to_string()
-                },
-                amount: parsed,
-            };
-            next_goal.contributions.insert(0, entry);
-            save_saving_goal(&next_goal);
-            goal.set(next_goal);
-            contrib_amount.set("".into());
-            contrib_desc.set("".into());
+        </div>
+    }
+}
 
-            // Also create a transaction so savings are reflected in totals
-            let date_val = contrib_date.to_string();
-            let desc_val = if contrib_desc.is_empty() {
-                "Savings".to_string()
-            } else {
-                contrib_desc.to_string()
-            };
-            spawn_local(async move {
-                let url = format!("{}/api/transactions", API_BASE_URL);
-                let payload = serde_json::json!({
-                    "date": date_val.as_str(),
-                    "description": desc_val.as_str(),
-                    "category": "Savings",
-                    "amount": -parsed
-                });
+/// Totals from `/api/dashboard/summary`.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+struct SummaryTotals {
+    income: i64,
+    expenses: i64,
+    balance: i64,
+}
 
-                let mut builder = Request::post(&url).credentials(RequestCredentials::Include);
-                if let Some(window) = web_sys::window() {
-                    if let Ok(Some(storage)) = window.local_storage() {
-                        if let Ok(token_opt) = storage.get_item("access_token") {
-                            if let Some(token) = token_opt {
-                                builder =
-                                    builder.header("Authorization", &format!("Bearer {}", token));
-                            }
-                        }
-                    }
-                }
+/// Fetches `/api/dashboard/summary`.
+async fn fetch_summary_totals() -> Option<SummaryTotals> {
+    let url = format!("{}/api/dashboard/summary", API_BASE_URL);
+    let resp = send_api(api_request(Request::get(&url)).build(), None)
+        .await
+        .ok()?;
+    if !resp.ok() {
+        return None;
+    }
+    let json = resp.json::<serde_json::Value>().await.ok()?;
+    Some(SummaryTotals {
+        income: json
+            .get("total_income")
+            .and_then(|x| x.as_i64())
+            .unwrap_or(0),
+        expenses: json
+            .get("total_expenses")
+            .and_then(|x| x.as_i64())
+            .unwrap_or(0),
+        balance: json.get("balance").and_then(|x| x.as_i64()).unwrap_or(0),
+    })
+}
 
-                if let Ok(builder) = builder.json(&payload) {
-                    let _ = builder.send().await;
-                }
-            });
-        })
-    };
+/// How long fetched totals are reused before a page mount fetches again.
+const SUMMARY_TTL_MS: f64 = 30_000.0;
+/// Refresh requests arriving within this window collapse into one fetch.
+const SUMMARY_DEBOUNCE_MS: u32 = 250;
 
-    let clear_contribution = {
-        let contrib_amount = contrib_amount.clone();
-        let contrib_desc = contrib_desc.clone();
-        Callback::from(move |_| {
-            contrib_amount.set("".to_string());
-            contrib_desc.set("".to_string());
+#[derive(Clone, PartialEq, Default)]
+struct SummaryState {
+    totals: Option<SummaryTotals>,
+    /// `Date.now()` of the last successful fetch; 0 when never fetched.
+    fetched_at: f64,
+}
+
+/// Summary totals shared by every page, provided by `App`.
+#[derive(Clone)]
+struct SummaryContext {
+    state: UseStateHandle<SummaryState>,
+    /// Debounce timer for `refresh`; replacing it cancels the previous one.
+    pending: Rc<RefCell<Option<Timeout>>>,
+    /// Bumped by `clear` so fetches started before a logout are dropped.
+    epoch: Rc<RefCell<u32>>,
+}
+
+impl PartialEq for SummaryContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl SummaryContext {
+    fn fetch_now(&self) {
+        fetch_summary_into(self.state.clone(), self.epoch.clone());
+    }
+
+    fn refresh(&self) {
+        let state = self.state.clone();
+        let epoch = self.epoch.clone();
+        let timeout = Timeout::new(SUMMARY_DEBOUNCE_MS, move || {
+            fetch_summary_into(state, epoch);
+        });
+        *self.pending.borrow_mut() = Some(timeout);
+    }
+
+    fn clear(&self) {
+        *self.epoch.borrow_mut() += 1;
+        self.pending.borrow_mut().take();
+        self.state.set(SummaryState::default());
+    }
+}
+
+fn fetch_summary_into(state: UseStateHandle<SummaryState>, epoch: Rc<RefCell<u32>>) {
+    let started = *epoch.borrow();
+    spawn_local(async move {
+        if let Some(totals) = fetch_summary_totals().await {
+            if *epoch.borrow() == started {
+                save_summary_cache(&totals);
+                state.set(SummaryState {
+                    totals: Some(totals),
+                    fetched_at: js_sys::Date::now(),
+                });
+            }
+        }
+    });
+}
+
+fn clear_summary_cache() {
+    if let Some(key) = summary_cache_key() {
+        if let Some(window) = web_sys::window() {
+            if let Ok(Some(storage)) = window.local_storage() {
+                let _ = storage.remove_item(&key);
+            }
+        }
+    }
+}
+
+fn summary_cache_key() -> Option<String> {
+    current_user_id().map(|id| format!("summary_cache:{}", id))
+}
+
+/// Last fetched totals for the signed-in user, shown offline.
+fn load_summary_cache() -> Option<SummaryTotals> {
+    let key = summary_cache_key()?;
+    let storage = web_sys::window()?.local_storage().ok()??;
+    let raw = storage.get_item(&key).ok()??;
+    serde_json::from_str(&raw).ok()
+}
+
+fn save_summary_cache(totals: &SummaryTotals) {
+    if let Some(key) = summary_cache_key() {
+        if let Some(window) = web_sys::window() {
+            if let Ok(Some(storage)) = window.local_storage() {
+                if let Ok(raw) = serde_json::to_string(totals) {
+                    let _ = storage.set_item(&key, &raw);
+                }
+            }
+        }
+    }
+}
+
+struct SummaryHandle {
+    totals: SummaryTotals,
+    /// Debounced refetch; call after any transaction write.
+    refresh: Callback<()>,
+}
+
+/// Shared summary totals. Fetches on mount only when the cached totals are
+/// older than `SUMMARY_TTL_MS`.
+#[hook]
+fn use_summary() -> SummaryHandle {
+    let summary = use_context::<SummaryContext>().expect("SummaryContext is provided by App");
+    {
+        let summary = summary.clone();
+        use_effect_with_deps(
+            move |_| {
+                let current = (*summary.state).clone();
+                if current.totals.is_none() {
+                    if let Some(totals) = load_summary_cache() {
+                        summary.state.set(SummaryState {
+                            totals: Some(totals),
+                            fetched_at: 0.0,
+                        });
+                    }
+                }
+                if js_sys::Date::now() - current.fetched_at > SUMMARY_TTL_MS {
+                    summary.fetch_now();
+                }
+                || ()
+            },
+            (),
+        );
+    }
+
+    let refresh = {
+        let summary = summary.clone();
+        Callback::from(move |_| summary.refresh())
+    };
+    SummaryHandle {
+        totals: summary.state.totals.unwrap_or_default(),
+        refresh,
+    }
+}
+
+fn page_shell(title: &'static str, actions: Html, children: Html) -> Html {
+    html! {
+        <div class="p-6 max-w-7xl mx-auto">
+            <div class="flex items-center justify-between pb-4 border-b border-border">
+                <h1 class="text-2xl font-bold text-foreground">{ title }</h1>
+                { actions }
+            </div>
+            <div class="pt-5 space-y-6">
+                { children }
+            </div>
+        </div>
+    }
+}
+
+/// Estimated height of one transaction table row. Spacer rows stand in for
+/// the rows outside the rendered window at this height.
+const TX_ROW_HEIGHT_PX: f64 = 53.0;
+/// Lists up to this long render every row.
+const VIRTUALIZE_MIN_ROWS: usize = 200;
+/// Extra rows rendered above and below the viewport.
+const TX_ROW_OVERSCAN: usize = 10;
+
+/// Rows `(first, count)` of `body` that fall inside the scrolling `<main>`
+/// viewport, plus overscan. `count` depends only on the viewport height, so
+/// the rendered row count stays fixed however long the list is.
+fn row_window(body: &web_sys::Element, main: &web_sys::Element) -> (usize, usize) {
+    let above =
+        (main.get_bounding_client_rect().top() - body.get_bounding_client_rect().top()).max(0.0);
+    let first = ((above / TX_ROW_HEIGHT_PX) as usize).saturating_sub(TX_ROW_OVERSCAN);
+    let visible = (main.client_height() as f64 / TX_ROW_HEIGHT_PX).ceil() as usize;
+    (first, visible + 2 * TX_ROW_OVERSCAN)
+}
+
+/// Tracks `row_window` for the table body behind `body_ref`, recomputed as
+/// the enclosing `<main>` scrolls or the window resizes. Only re-renders when
+/// the window moves by a whole row.
+#[hook]
+fn use_row_window(body_ref: NodeRef) -> (usize, usize) {
+    let window_rows = use_state_eq(|| (0, 3 * TX_ROW_OVERSCAN));
+    {
+        let window_rows = window_rows.clone();
+        use_effect_with_deps(
+            move |_| {
+                let main = body_ref
+                    .cast::<web_sys::Element>()
+                    .and_then(|body| body.closest("main").ok().flatten());
+                let listeners = main.map(|main| {
+                    let target = main.clone();
+                    let update = Rc::new(move || {
+                        if let Some(body) = body_ref.cast::<web_sys::Element>() {
+                            window_rows.set(row_window(&body, &main));
+                        }
+                    });
+                    update();
+                    let on_scroll = {
+                        let update = update.clone();
+                        EventListener::new(&target, "scroll", move |_| update())
+                    };
+                    let on_resize = web_sys::window()
+                        .map(|window| EventListener::new(&window, "resize", move |_| update()));
+                    (on_scroll, on_resize)
+                });
+                move || drop(listeners)
+            },
+            (),
+        );
+    }
+    *window_rows
+}
+
+/// Stable row key: the server id when present, otherwise the list position.
+fn transaction_key(tx: &Transaction, idx: usize) -> String {
+    match tx.id {
+        Some(id) => format!("tx-{}", id),
+        None => format!("idx-{}", idx),
+    }
+}
+
+fn row_select_callback(
+    on_select: &Callback<Transaction>,
+    tx: &Transaction,
+) -> Callback<MouseEvent> {
+    let on_select = on_select.clone();
+    let tx = tx.clone();
+    Callback::from(move |_| on_select.emit(tx.clone()))
+}
+
+fn row_keyboard_callback(
+    on_select: &Callback<Transaction>,
+    tx: &Transaction,
+) -> Callback<KeyboardEvent> {
+    let on_select = on_select.clone();
+    let tx = tx.clone();
+    Callback::from(move |e: KeyboardEvent| {
+        if e.key() == "Enter" || e.key() == " " {
+            e.prevent_default();
+            on_select.emit(tx.clone());
+        }
+    })
+}
+
+/// What a page needs to open `TransactionModal` on a row of `transactions`
+/// and keep the list in step with its edits.
+struct TransactionModalCallbacks {
+    on_select: Callback<Transaction>,
+    on_close: Callback<()>,
+    on_updated: Callback<Transaction>,
+    on_deleted: Callback<i32>,
+    on_split: Callback<(i32, Vec<Transaction>)>,
+}
+
+fn transaction_modal_callbacks(
+    transactions: &UseStateHandle<Vec<Transaction>>,
+    selected_tx: &UseStateHandle<Option<Transaction>>,
+    refresh_summary: &Callback<()>,
+) -> TransactionModalCallbacks {
+    let on_select = {
+        let selected_tx = selected_tx.clone();
+        Callback::from(move |tx: Transaction| selected_tx.set(Some(tx)))
+    };
+    let on_close = {
+        let selected_tx = selected_tx.clone();
+        Callback::from(move |_| selected_tx.set(None))
+    };
+    let on_updated = {
+        let transactions = transactions.clone();
+        let selected_tx = selected_tx.clone();
+        let refresh_summary = refresh_summary.clone();
+        Callback::from(move |updated: Transaction| {
+            let next = (*transactions)
+                .iter()
+                .map(|tx| {
+                    if tx.id == updated.id {
+                        updated.clone()
+                    } else {
+                        tx.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+            transactions.set(next);
+            selected_tx.set(Some(updated));
+            refresh_summary.emit(());
+        })
+    };
+    let on_deleted = {
+        let transactions = transactions.clone();
+        let selected_tx = selected_tx.clone();
+        let refresh_summary = refresh_summary.clone();
+        Callback::from(move |id: i32| {
+            let next = (*transactions)
+                .iter()
+                .filter(|tx| tx.id != Some(id))
+                .cloned()
+                .collect::<Vec<_>>();
+            transactions.set(next);
+            selected_tx.set(None);
+            refresh_summary.emit(());
+        })
+    };
+    let on_split = {
+        let transactions = transactions.clone();
+        let selected_tx = selected_tx.clone();
+        let refresh_summary = refresh_summary.clone();
+        Callback::from(move |(id, parts): (i32, Vec<Transaction>)| {
+            let mut next = Vec::with_capacity(transactions.len() + parts.len());
+            for tx in transactions.iter() {
+                if tx.id == Some(id) {
+                    next.extend(parts.iter().cloned());
+                } else {
+                    next.push(tx.clone());
+                }
+            }
+            transactions.set(next);
+            selected_tx.set(None);
+            refresh_summary.emit(());
         })
     };
+    TransactionModalCallbacks {
+        on_select,
+        on_close,
+        on_updated,
+        on_deleted,
+        on_split,
+    }
+}
 
-    let remove_goal = {
-        let goal = goal.clone();
-        let new_goal_title = new_goal_title.clone();
-        let new_goal_amount = new_goal_amount.clone();
-        let new_goal_date = new_goal_date.clone();
-        let is_creating = is_creating.clone();
+#[derive(Properties, PartialEq)]
+struct TransactionModalProps {
+    transaction: Transaction,
+    currency: CurrencyFormat,
+    on_close: Callback<()>,
+    on_updated: Callback<Transaction>,
+    on_deleted: Callback<i32>,
+    /// Shows a "Duplicate" action when set.
+    #[prop_or_default]
+    on_duplicate: Option<Callback<Transaction>>,
+    /// Shows a "Split" action when set; emits the replaced id and its parts.
+    #[prop_or_default]
+    on_split: Option<Callback<(i32, Vec<Transaction>)>>,
+}
+
+#[function_component(TransactionModal)]
+fn transaction_modal(props: &TransactionModalProps) -> Html {
+    let tx = &props.transaction;
+    let editing = use_state(|| false);
+    let busy = use_state(|| false);
+    let confirm_delete = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let edit_date = use_state(|| tx.date.get(0..10).unwrap_or(&tx.date).to_string());
+    let today = today_key();
+    let edit_description = use_state(|| tx.description.clone());
+    let edit_category = use_state(|| tx.category.clone());
+    // amounts are entered in the row's own currency
+    let entry_currency = props.currency.for_code(tx.currency.as_deref());
+    let edit_amount = use_state(|| entry_currency.entry_text(tx.amount));
+    let edit_note = use_state(|| tx.note.clone().unwrap_or_default());
+    let edit_receipt = use_state(|| tx.receipt_url.clone().unwrap_or_default());
+    let edit_account = use_state(|| account_name(tx).to_string());
+    let edit_tax = use_state(|| tx.tax);
+    let splitting = use_state(|| false);
+    // (category, amount) per line; starts with the whole amount on the
+    // original category and a blank line for the part being split off
+    let split_lines = use_state(|| {
+        vec![
+            (
+                tx.category.clone(),
+                entry_currency.entry_text(tx.amount.abs()),
+            ),
+            (String::new(), String::new()),
+        ]
+    });
+    let accounts = load_accounts();
+
+    {
+        let on_close = props.on_close.clone();
+        use_effect_with_deps(
+            move |_| {
+                let listener = web_sys::window().map(|window| {
+                    EventListener::new(&window, "keydown", move |event| {
+                        if let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() {
+                            if event.key() == "Escape" {
+                                on_close.emit(());
+                            }
+                        }
+                    })
+                });
+                move || drop(listener)
+            },
+            (),
+        );
+    }
+
+    let on_close = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+
+    let on_toggle_edit = {
+        let editing = editing.clone();
+        let error = error.clone();
         Callback::from(move |_| {
-            let cleared = SavingGoalState {
-                title: "".to_string(),
-                target_amount: 0,
-                target_date: "".to_string(),
-                contributions: vec![],
+            editing.set(!*editing);
+            error.set(None);
+        })
+    };
+
+    let on_toggle_split = {
+        let splitting = splitting.clone();
+        let error = error.clone();
+        Callback::from(move |_| {
+            splitting.set(!*splitting);
+            error.set(None);
+        })
+    };
+
+    let on_add_split_line = {
+        let split_lines = split_lines.clone();
+        Callback::from(move |_| {
+            let mut next = (*split_lines).clone();
+            next.push((String::new(), String::new()));
+            split_lines.set(next);
+        })
+    };
+
+    let on_save_split = {
+        let original = tx.clone();
+        let split_lines = split_lines.clone();
+        let busy = busy.clone();
+        let error = error.clone();
+        let on_split = props.on_split.clone();
+        let currency = entry_currency.clone();
+        Callback::from(move |_| {
+            let Some(on_split) = on_split.clone() else {
+                return;
             };
-            save_saving_goal(&cleared);
-            goal.set(cleared);
-            new_goal_title.set("".to_string());
-            new_goal_amount.set("".to_string());
-            new_goal_date.set("".to_string());
-            is_creating.set(false);
+            let parts = split_lines
+                .iter()
+                .map(|(category, amount)| {
+                    parse_entered_amount(amount, &currency)
+                        .map(|amount| (normalize_category(category), amount))
+                })
+                .collect::<Result<Vec<_>, String>>();
+            let parts = match parts {
+                Ok(parts) => parts,
+                Err(msg) => {
+                    error.set(Some(msg));
+                    return;
+                }
+            };
+            if let Err(msg) = validate_split(&parts, original.amount.abs()) {
+                error.set(Some(msg));
+                return;
+            }
+
+            error.set(None);
+            busy.set(true);
+            let original = original.clone();
+            let busy = busy.clone();
+            let error = error.clone();
+            spawn_local(async move {
+                match split_transaction(&original, &parts).await {
+                    Ok(created) => {
+                        if let Some(id) = original.id {
+                            on_split.emit((id, created));
+                        }
+                    }
+                    Err(msg) => {
+                        busy.set(false);
+                        error.set(Some(msg));
+                    }
+                }
+            });
         })
     };
 
-    let create_goal = {
-        let goal = goal.clone();
-        let new_goal_title = new_goal_title.clone();
-        let new_goal_amount = new_goal_amount.clone();
-        let new_goal_date = new_goal_date.clone();
-        let is_creating = is_creating.clone();
+    let on_toggle_flag = {
+        let tx = tx.clone();
+        let busy = busy.clone();
+        let error = error.clone();
+        let on_updated = props.on_updated.clone();
         Callback::from(move |_| {
-            if new_goal_title.is_empty() || new_goal_amount.is_empty() {
+            error.set(None);
+            busy.set(true);
+            let tx = tx.clone();
+            let busy = busy.clone();
+            let error = error.clone();
+            let on_updated = on_updated.clone();
+            spawn_local(async move {
+                let updated = set_flagged(&tx, !tx.flagged).await;
+                busy.set(false);
+                match updated {
+                    Some(updated) => on_updated.emit(updated),
+                    None => error.set(Some("Could not update the review flag.".to_string())),
+                }
+            });
+        })
+    };
+
+    let on_save = {
+        let id = tx.id;
+        let split_group = tx.split_group.clone();
+        let flagged = tx.flagged;
+        let tx_currency = tx.currency.clone();
+        let edit_date = edit_date.clone();
+        let edit_description = edit_description.clone();
+        let edit_category = edit_category.clone();
+        let edit_amount = edit_amount.clone();
+        let edit_note = edit_note.clone();
+        let edit_receipt = edit_receipt.clone();
+        let edit_account = edit_account.clone();
+        let edit_tax = edit_tax.clone();
+        let busy = busy.clone();
+        let error = error.clone();
+        let editing = editing.clone();
+        let on_updated = props.on_updated.clone();
+        let currency = entry_currency.clone();
+        Callback::from(move |_| {
+            let Some(id) = id else {
+                return;
+            };
+            let date_val = edit_date.trim().to_string();
+            let desc_val = edit_description.trim().to_string();
+            let category_val = normalize_category(&edit_category);
+            let note_val = optional_text(&edit_note);
+            let receipt_val = optional_text(&edit_receipt);
+            let account_val = (*edit_account).clone();
+            let tax_val = *edit_tax;
+            if date_val.is_empty() || desc_val.is_empty() || category_val.is_empty() {
+                error.set(Some("Please complete all fields.".to_string()));
                 return;
             }
-            let next_goal = SavingGoalState {
-                title: new_goal_title.to_string(),
-                target_amount: new_goal_amount.parse::<i64>().unwrap_or(0),
-                target_date: new_goal_date.to_string(),
-                contributions: vec![],
+            let amount = match parse_entered_amount(&edit_amount, &currency) {
+                Ok(0) => {
+                    error.set(Some("Amount must be a non-zero number.".to_string()));
+                    return;
+                }
+                Ok(amount) => amount,
+                Err(msg) => {
+                    error.set(Some(msg));
+                    return;
+                }
             };
-            save_saving_goal(&next_goal);
-            goal.set(next_goal);
-            is_creating.set(false);
+
+            error.set(None);
+            busy.set(true);
+            let busy = busy.clone();
+            let error = error.clone();
+            let editing = editing.clone();
+            let on_updated = on_updated.clone();
+            let split_group = split_group.clone();
+            let tx_currency = tx_currency.clone();
+            spawn_local(async move {
+                let url = format!("{}/api/transactions/{}", API_BASE_URL, id);
+                let payload = serde_json::json!({
+                    "date": date_val.as_str(),
+                    "description": desc_val.as_str(),
+                    "category": category_val.as_str(),
+                    "amount": amount,
+                    "note": note_val.as_deref(),
+                    "receiptUrl": receipt_val.as_deref(),
+                    "account": account_val.as_str(),
+                    "tax": tax_val,
+                    "splitGroup": split_group.as_deref(),
+                    "flagged": flagged,
+                    "currency": tx_currency.as_deref()
+                });
+
+                invalidate_dashboard_cache();
+                let request = api_request(Request::put(&url)).json(&payload);
+                let updated = match send_api(request, None).await {
+                    Ok(resp) if resp.ok() => resp.json::<Transaction>().await.ok(),
+                    _ => None,
+                };
+
+                busy.set(false);
+                match updated {
+                    Some(updated) => {
+                        editing.set(false);
+                        on_updated.emit(updated);
+                    }
+                    None => error.set(Some("Could not update the transaction.".to_string())),
+                }
+            });
+        })
+    };
+
+    let on_delete = {
+        let id = tx.id;
+        let busy = busy.clone();
+        let error = error.clone();
+        let on_deleted = props.on_deleted.clone();
+        let confirm_delete = confirm_delete.clone();
+        Callback::from(move |_| {
+            confirm_delete.set(false);
+            let Some(id) = id else {
+                return;
+            };
+
+            busy.set(true);
+            let busy = busy.clone();
+            let error = error.clone();
+            let on_deleted = on_deleted.clone();
+            spawn_local(async move {
+                let url = format!("{}/api/transactions/{}", API_BASE_URL, id);
+                invalidate_dashboard_cache();
+                let request = api_request(Request::delete(&url)).build();
+
+                match send_api(request, None).await {
+                    Ok(resp) if resp.ok() => on_deleted.emit(id),
+                    _ => {
+                        busy.set(false);
+                        error.set(Some("Could not delete the transaction.".to_string()));
+                    }
+                }
+            });
         })
     };
 
     html! {
-        { page_shell(
-            "Saving Goal",
-            html! {
-                <div class="flex items-center gap-2">
-                    <button onclick={toggle_create} class="bg-primary text-primary-foreground px-4 py-2 rounded-[10px] text-xs font-bold uppercase flex items-center gap-1 shadow-md hover:opacity-90 transition-all">
-                        { if *is_creating { "Cancel" } else { "New Goal" } }
-                    </button>
-                    <button onclick={remove_goal} class="bg-red-600 text-white px-4 py-2 rounded-[10px] text-xs font-bold uppercase shadow-md hover:opacity-90 transition-all">
-                        {"Remove Goal"}
-                    </button>
+        <div class="fixed inset-0 z-50 flex items-center justify-center bg-black/30" onclick={on_close.clone()}>
+            <div class="w-full max-w-lg bg-card border border-border rounded-xl shadow-lg p-6" role="dialog" aria-modal="true" aria-label="Transaction details" onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}>
+                <div class="flex items-center justify-between mb-4">
+                    <h3 class="font-bold text-foreground text-lg">{"Transaction Details"}</h3>
+                    <button type="button" class="text-xs text-muted-foreground hover:text-foreground" onclick={on_close.clone()}>{"Close"}</button>
                 </div>
-            },
-            html! {
-                <>
-                    <div class="grid grid-cols-1 lg:grid-cols-12 gap-6 items-stretch">
-                <div class="lg:col-span-5 bg-white p-6 rounded-[10px] shadow-md border border-border flex flex-col h-full">
-                    { if !*is_creating {
-                        html! {
-                            <div class="flex flex-col h-full">
-                                <div class="flex justify-between items-start mb-6">
-                                    <div class="space-y-1">
-                                        <div class="flex items-center gap-2 px-2 py-0.5 bg-[#dae3f0] w-fit rounded-full">
-                                            <span class="text-[9px] font-black text-[#173E63] uppercase tracking-wider">{"Current Goal"}</span>
-                                        </div>
-                                        <h3 class="text-xl font-black text-[#173E63] tracking-tight">{ goal.title.clone() }</h3>
-                                    </div>
-                                    <div class="text-right">
-                                        <p class="text-[9px] font-bold text-slate-400 uppercase">{"Target Date"}</p>
-                                        <div class="flex items-center gap-1 text-[#173E63] font-bold text-xs">{ if goal.target_date.is_empty() { "No Date" } else { goal.target_date.as_str() } }</div>
-                                    </div>
-                                </div>
 
-                                <div class="flex flex-1 items-center justify-around gap-4 bg-slate-50/50 rounded-2xl p-4 border border-slate-100/50">
-                                    <div class="relative flex items-center justify-center shrink-0">
-                                        <svg class="w-24 h-24 transform -rotate-90">
-                                            <circle cx="48" cy="48" r={radius.to_string()} stroke="#e2e8f0" stroke-width="8" fill="transparent" />
-                                            <circle cx="48" cy="48" r={radius.to_string()} stroke="#173E63" stroke-width="8" fill="transparent" stroke-dasharray={circumference.to_string()} stroke-dashoffset={offset.to_string()} stroke-linecap="round" />
-                                        </svg>
-                                        <div class="absolute inset-0 flex flex-col items-center justify-center">
-                                            <span class="text-lg font-black text-[#173E63]">{ format!("{}%", (progress * 100.0).round() as i32) }</span>
-                                            <span class="text-[7px] text-slate-400 font-bold uppercase tracking-tighter">{"Progress"}</span>
+                if *splitting {
+                    { split_editor(&split_lines, tx, &entry_currency) }
+                } else if *editing {
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-3">
+                        <input type="date" max={today.clone()} value={(*edit_date).clone()} oninput={{
+                            let edit_date = edit_date.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                edit_date.set(input.value());
+                            })
+                        }} class="p-2 border rounded" />
+                        <div class="flex flex-col gap-1">
+                            <input type="text" inputmode="decimal" pattern={amount_pattern('.', entry_currency.entry_places())} placeholder={entry_currency.entry_placeholder(format!("Amount ({})", entry_currency.symbol))} value={(*edit_amount).clone()} oninput={amount_oninput(&edit_amount, '.', entry_currency.entry_places())} class="p-2 border rounded" />
+                            { entry_preview(&edit_amount, &entry_currency) }
+                        </div>
+                        <input placeholder="Description" value={(*edit_description).clone()} oninput={{
+                            let edit_description = edit_description.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                edit_description.set(input.value());
+                            })
+                        }} class="p-2 border rounded" />
+                        <input placeholder="Category" list={CATEGORY_LIST_ID} value={(*edit_category).clone()} oninput={{
+                            let edit_category = edit_category.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                edit_category.set(input.value());
+                            })
+                        }} class="p-2 border rounded" />
+                        <textarea placeholder="Note (optional)" value={(*edit_note).clone()} oninput={{
+                            let edit_note = edit_note.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                                edit_note.set(input.value());
+                            })
+                        }} class="p-2 border rounded md:col-span-2" rows="2" />
+                        <select onchange={{
+                            let edit_account = edit_account.clone();
+                            Callback::from(move |e: Event| {
+                                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                edit_account.set(input.value());
+                            })
+                        }} class="p-2 border rounded">
+                            { account_options(&accounts, &edit_account) }
+                        </select>
+                        <input placeholder="Receipt link or reference (optional)" value={(*edit_receipt).clone()} oninput={{
+                            let edit_receipt = edit_receipt.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                edit_receipt.set(input.value());
+                            })
+                        }} class="p-2 border rounded" />
+                        { tax_checkbox(&edit_tax) }
+                    </div>
+                } else {
+                    <dl class="grid grid-cols-3 gap-y-3 text-sm">
+                        <dt class="text-muted-foreground">{"Date"}</dt>
+                        <dd class="col-span-2 text-foreground">{ tx.date.clone() }</dd>
+                        <dt class="text-muted-foreground">{"Description"}</dt>
+                        <dd class="col-span-2 text-foreground break-words">{ tx.description.clone() }</dd>
+                        <dt class="text-muted-foreground">{"Category"}</dt>
+                        <dd class="col-span-2 text-foreground">{ tx.category.clone() }</dd>
+                        <dt class="text-muted-foreground">{"Account"}</dt>
+                        <dd class="col-span-2 text-foreground">{ account_name(tx).to_string() }</dd>
+                        <dt class="text-muted-foreground">{"Tax"}</dt>
+                        <dd class="col-span-2 text-foreground">{ if tx.tax { "Tax-relevant" } else { "—" } }</dd>
+                        <dt class="text-muted-foreground">{"Review"}</dt>
+                        <dd class="col-span-2 text-foreground">{ if tx.flagged { "Flagged for review" } else { "—" } }</dd>
+                        <dt class="text-muted-foreground">{"Amount"}</dt>
+                        <dd class="col-span-2 font-semibold">{ amount_cell(tx, &props.currency, true) }</dd>
+                        <dt class="text-muted-foreground">{"Note"}</dt>
+                        <dd class="col-span-2 text-foreground whitespace-pre-wrap break-words">{ tx.note.clone().unwrap_or_else(|| "—".to_string()) }</dd>
+                        <dt class="text-muted-foreground">{"Receipt"}</dt>
+                        <dd class="col-span-2 text-foreground break-all">
+                            {
+                                match tx.receipt_url.as_deref() {
+                                    Some(receipt) if receipt.starts_with("http://") || receipt.starts_with("https://") => html! {
+                                        <a href={receipt.to_string()} target="_blank" rel="noopener noreferrer" class="text-primary underline">{ receipt.to_string() }</a>
+                                    },
+                                    Some(receipt) => html! { receipt.to_string() },
+                                    None => html! { "—" },
+                                }
+                            }
+                        </dd>
+                    </dl>
+                }
+
+                if let Some(msg) = &*error {
+                    <p class="text-sm text-red-500 mt-3">{ msg.clone() }</p>
+                }
+
+                if tx.id.is_some() {
+                    <div class="flex justify-end gap-2 mt-6">
+                        if *splitting {
+                            <button type="button" onclick={on_add_split_line} class="mr-auto px-4 py-2 rounded-[10px] text-xs font-bold bg-muted text-primary">{"Add line"}</button>
+                            <button type="button" onclick={on_toggle_split} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{"Cancel"}</button>
+                            <button type="button" onclick={on_save_split} disabled={*busy} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{ if *busy { "Splitting..." } else { "Save split" } }</button>
+                        } else if *editing {
+                            <button type="button" onclick={on_toggle_edit} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{"Cancel"}</button>
+                            <button type="button" onclick={on_save} disabled={*busy} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{ if *busy { "Saving..." } else { "Save" } }</button>
+                        } else {
+                            <button type="button" onclick={{
+                                let confirm_delete = confirm_delete.clone();
+                                Callback::from(move |_| confirm_delete.set(true))
+                            }} disabled={*busy} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-red-600 text-white">{ if *busy { "Deleting..." } else { "Delete" } }</button>
+                            if let Some(on_duplicate) = &props.on_duplicate {
+                                <button type="button" onclick={{
+                                    let on_duplicate = on_duplicate.clone();
+                                    let tx = tx.clone();
+                                    Callback::from(move |_| on_duplicate.emit(tx.clone()))
+                                }} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{"Duplicate"}</button>
+                            }
+                            <button type="button" onclick={on_toggle_flag} disabled={*busy} aria-pressed={tx.flagged.to_string()} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{ if tx.flagged { "Clear flag" } else { "Flag for review" } }</button>
+                            if props.on_split.is_some() && !is_transfer(tx) {
+                                <button type="button" onclick={on_toggle_split} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{"Split"}</button>
+                            }
+                            <button type="button" onclick={on_toggle_edit} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{"Edit"}</button>
+                        }
+                    </div>
+                }
+                if *confirm_delete {
+                    <ConfirmModal
+                        title="Delete transaction"
+                        message="Delete this transaction? This cannot be undone."
+                        confirm_label="Delete"
+                        destructive=true
+                        on_confirm={on_delete}
+                        on_cancel={{
+                            let confirm_delete = confirm_delete.clone();
+                            Callback::from(move |_| confirm_delete.set(false))
+                        }}
+                    />
+                }
+            </div>
+        </div>
+    }
+}
+
+#[function_component(BudgetPage)]
+fn budget_page() -> Html {
+    let currency = use_currency_format();
+    let bounds = use_period_bounds();
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    let savings_as_spending = settings.as_ref().is_none_or(|s| s.savings_as_spending);
+    let hide_zero_spend = settings.as_ref().is_some_and(|s| s.hide_zero_spend);
+    let rounding = settings
+        .as_ref()
+        .map(|s| s.rounding_mode)
+        .unwrap_or_default();
+
+    let category_totals = use_state(Vec::<(String, i64)>::new);
+    let total_spent = use_state(|| 0i64);
+    let loading = use_state(|| true);
+    let scope = use_request_scope();
+    let load_error = use_state(|| None::<ApiError>);
+    let load_attempt = use_state(|| 0u32);
+    let refreshed_at = use_state(|| None::<f64>);
+
+    let budgets = use_state(load_budgets);
+    let budget_sort = use_state(BudgetSort::default);
+    let over_only = use_state(|| false);
+    let budget_category = use_state(|| "".to_string());
+    let budget_category_ref = use_node_ref();
+    let budget_limit = use_state(|| "".to_string());
+    use_unsaved_form(
+        "add-budget",
+        !budget_category.trim().is_empty() || !budget_limit.trim().is_empty(),
+    );
+    let budget_error = use_state(|| None::<String>);
+    let limit_error = use_state(|| None::<String>);
+    let suggestions = use_state(Vec::<(String, i64)>::new);
+    let history = use_state(Vec::<Transaction>::new);
+    let suggestion_basis = use_state(|| None::<String>);
+
+    {
+        let category_totals = category_totals.clone();
+        let total_spent = total_spent.clone();
+        let suggestions = suggestions.clone();
+        let history = history.clone();
+        let loading = loading.clone();
+        let load_error = load_error.clone();
+        let refreshed_at = refreshed_at.clone();
+
+        use_effect_with_deps(
+            move |&(bounds, savings_as_spending, rounding, _)| {
+                load_error.set(None);
+                loading.set(true);
+                spawn_local(async move {
+                    match fetch_transactions(Some(&scope)).await {
+                        Ok(list) => {
+                            let expenses = list
+                                .iter()
+                                .filter(|tx| is_spending(tx, savings_as_spending))
+                                .map(|tx| (tx.category.as_str(), tx.amount.abs()));
+                            let mut totals_vec = sum_by_category(expenses);
+                            let spent = totals_vec.iter().map(|(_, amt)| amt).sum();
+                            totals_vec.sort_by_key(|b| std::cmp::Reverse(b.1));
+                            category_totals.set(totals_vec);
+                            total_spent.set(spent);
+                            suggestions.set(suggested_limits(
+                                &list,
+                                &current_month_key(bounds),
+                                bounds,
+                                savings_as_spending,
+                                rounding,
+                            ));
+                            history.set(list);
+                            refreshed_at.set(Some(js_sys::Date::now()));
+                        }
+                        Err(ApiError::Cancelled) => return,
+                        Err(err) => load_error.set(Some(err)),
+                    }
+
+                    loading.set(false);
+                });
+                || ()
+            },
+            (bounds, savings_as_spending, rounding, *load_attempt),
+        );
+    }
+
+    let on_add_budget = {
+        let budgets = budgets.clone();
+        let budget_category = budget_category.clone();
+        let budget_limit = budget_limit.clone();
+        let budget_error = budget_error.clone();
+        let limit_error = limit_error.clone();
+        let currency = currency.clone();
+        Callback::from(move |_| {
+            let category = normalize_category(&budget_category);
+            // both fields report their own problem, so check each before bailing
+            budget_error.set(category.is_empty().then(|| "Enter a category.".to_string()));
+            let (_, decimal) = currency.grouping.separators();
+            let entered = if currency.cents_entry() {
+                cents_to_decimal(&budget_limit, decimal, currency.decimal_places)
+            } else {
+                (*budget_limit).clone()
+            };
+            let limit = match parse_limit(&entered, currency.grouping) {
+                Ok(limit) => {
+                    limit_error.set(None);
+                    limit
+                }
+                Err(msg) => {
+                    limit_error.set(Some(msg));
+                    return;
+                }
+            };
+            if category.is_empty() {
+                return;
+            }
+
+            let mut next = (*budgets).clone();
+            if let Some(existing) = next
+                .iter_mut()
+                .find(|b| category_key(&b.category) == category_key(&category))
+            {
+                existing.set_limit(limit);
+            } else {
+                next.push(BudgetItem {
+                    category,
+                    limit,
+                    history: vec![],
+                });
+            }
+
+            save_budgets(&next);
+            budgets.set(next);
+            budget_category.set("".to_string());
+            budget_limit.set("".to_string());
+            budget_error.set(None);
+        })
+    };
+
+    let on_suggest = {
+        let budget_category = budget_category.clone();
+        let budget_limit = budget_limit.clone();
+        let suggestion_basis = suggestion_basis.clone();
+        let currency = currency.clone();
+        Callback::from(move |(category, average): (String, i64)| {
+            suggestion_basis.set(Some(format!(
+                "{}: avg of last 3 months: {}",
+                category,
+                format_currency(average, &currency)
+            )));
+            budget_category.set(category);
+            budget_limit.set(currency.entry_text(average));
+        })
+    };
+
+    let mut spent_by_category: HashMap<String, i64> = HashMap::new();
+    for (cat, amt) in (*category_totals).iter() {
+        spent_by_category.insert(category_key(cat), *amt);
+    }
+    let (active_budgets, idle_budgets) =
+        split_zero_spend(&budgets, &spent_by_category, hide_zero_spend);
+    let shown_budgets = sort_budgets(
+        &active_budgets,
+        &spent_by_category,
+        *budget_sort,
+        *over_only,
+    );
+    let on_budget_sort_change = {
+        let budget_sort = budget_sort.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            budget_sort.set(BudgetSort::from_key(&input.value()));
+        })
+    };
+    let on_over_only_toggle = {
+        let over_only = over_only.clone();
+        Callback::from(move |_| over_only.set(!*over_only))
+    };
+    use_publish_nav_status(
+        (!*loading).then(|| overspent_budgets(&budgets, &spent_by_category)),
+        None,
+    );
+    let trend_months = recent_month_keys(&current_month_key(bounds), 6);
+    html! {
+        { page_shell(
+            "Budget Overview",
+            html! {
+                <RefreshStatus refreshed_at={*refreshed_at} refreshing={*loading} on_refresh={{
+                    let load_attempt = load_attempt.clone();
+                    Callback::from(move |_| load_attempt.set(*load_attempt + 1))
+                }} />
+            },
+            html! {
+                <>
+                    { load_error_banner(*load_error, &load_attempt) }
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
+                        <div class="bg-card rounded-[10px] p-6 border border-border">
+                            <p class="text-sm text-muted-foreground mb-2">{"Total Expenses"}</p>
+                            <h3 class="text-2xl font-bold text-foreground"><CurrencyAmount amount={*total_spent} currency={currency.clone()} /></h3>
+                            <p class="text-xs text-muted-foreground mt-2">{"Sum of all expense transactions"}</p>
+                        </div>
+
+                        <div class="bg-card rounded-[10px] p-6 border border-border">
+                            <p class="text-sm text-muted-foreground mb-2">{"Top Categories"}</p>
+                            <div class="space-y-2">
+                                { if *loading {
+                                    html! { <p class="text-sm text-muted-foreground">{"Loading..."}</p> }
+                                } else if category_totals.is_empty() {
+                                    html! { <p class="text-sm text-muted-foreground">{"No expense transactions yet."}</p> }
+                                } else {
+                                    html! {
+                                        <ul class="space-y-1">
+                                            { for category_totals.iter().take(5).map(|(cat, amt)| html! {
+                                                <li class="flex items-center justify-between text-sm">
+                                                    <span class="text-foreground">{ cat.clone() }</span>
+                                                    <span class="font-semibold"><CurrencyAmount amount={*amt} currency={currency.clone()} /></span>
+                                                </li>
+                                            }) }
+                                        </ul>
+                                    }
+                                }}
+                            </div>
+                        </div>
+                    </div>
+
+                    <div class="bg-card rounded-[10px] p-6 border border-border">
+                        <div class="flex items-center justify-between mb-4">
+                            <h3 class="font-bold text-foreground text-lg">{"Category Budgets"}</h3>
+                            <span class="text-xs text-muted-foreground">{"Set monthly limits"}</span>
+                        </div>
+                        <div class="grid grid-cols-1 md:grid-cols-3 gap-3 mb-4">
+                            <input ref={budget_category_ref.clone()} placeholder="Category" list={CATEGORY_LIST_ID} value={(*budget_category).clone()} oninput={Callback::from({
+                                let budget_category = budget_category.clone();
+                                move |e: InputEvent| {
+                                    if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                                        budget_category.set(input.value());
+                                    }
+                                }
+                            })} class="p-2 border rounded" />
+                            <div class="flex flex-col gap-1">
+                                <input type="text" inputmode="decimal" pattern={amount_pattern(currency.grouping.separators().1, currency.entry_places())} placeholder={currency.entry_placeholder(format!("Limit ({})", currency.symbol))} value={(*budget_limit).clone()} aria-invalid={limit_error.is_some().to_string()} oninput={Callback::from({
+                                    let on_limit = amount_oninput(&budget_limit, currency.grouping.separators().1, currency.entry_places());
+                                    let limit_error = limit_error.clone();
+                                    move |e: InputEvent| {
+                                        on_limit.emit(e);
+                                        limit_error.set(None);
+                                    }
+                                })} class={classes!("p-2", "border", "rounded", limit_error.is_some().then_some("border-red-500"))} />
+                                if let Some(msg) = &*limit_error {
+                                    <p class="text-xs text-red-500" role="alert">{ msg.clone() }</p>
+                                } else {
+                                    { entry_preview(&budget_limit.replace(currency.grouping.separators().1, "."), &currency) }
+                                }
+                            </div>
+                            <button onclick={on_add_budget} class="bg-primary text-primary-foreground px-4 rounded">{"Save Budget"}</button>
+                        </div>
+                        if let Some(basis) = &*suggestion_basis {
+                            <p class="text-xs text-muted-foreground mb-3">{ format!("Suggested limit based on {}", basis) }</p>
+                        }
+                        if !suggestions.is_empty() {
+                            <div class="mb-4">
+                                <p class="text-xs font-bold text-muted-foreground tracking-widest mb-2">{"Suggestions"}</p>
+                                <div class="flex flex-wrap gap-2">
+                                    { for suggestions.iter().map(|(category, average)| {
+                                        let onclick = {
+                                            let on_suggest = on_suggest.clone();
+                                            let pick = (category.clone(), *average);
+                                            Callback::from(move |_| on_suggest.emit(pick.clone()))
+                                        };
+                                        html! {
+                                            <button type="button" {onclick} title={format!("avg of last 3 months: {}", format_currency(*average, &currency))} class="px-3 py-1 rounded-full border border-border text-xs text-foreground hover:bg-muted/40">
+                                                { format!("Suggest {} · {}", category, format_currency(*average, &currency)) }
+                                            </button>
+                                        }
+                                    }) }
+                                </div>
+                            </div>
+                        }
+                        {
+                            if let Some(msg) = &*budget_error {
+                                html! { <p class="text-sm text-red-500 mb-3">{ msg.clone() }</p> }
+                            } else { html!{} }
+                        }
+                        <div class="space-y-2">
+                            { if budgets.is_empty() {
+                                html! { <EmptyState icon={icon_wallet()} message="No budgets yet." action_label="Add a budget" on_action={focus_input_callback(&budget_category_ref)} /> }
+                            } else {
+                                html! {
+                                    <div class="space-y-2">
+                                        <div class="flex flex-wrap items-center justify-between gap-3 text-sm">
+                                            <label class="flex items-center gap-2 text-foreground">
+                                                <input type="checkbox" checked={*over_only} onclick={on_over_only_toggle} />
+                                                {"Over limit only"}
+                                            </label>
+                                            <label class="flex items-center gap-2 text-muted-foreground">
+                                                {"Sort by"}
+                                                <select onchange={on_budget_sort_change} class="p-1 border rounded text-foreground bg-input">
+                                                    { for BudgetSort::ALL.into_iter().map(|sort| html! {
+                                                        <option value={sort.key()} selected={sort == *budget_sort}>{ sort.label() }</option>
+                                                    }) }
+                                                </select>
+                                            </label>
+                                        </div>
+                                        if *over_only && shown_budgets.is_empty() {
+                                            <p class="text-sm text-muted-foreground">{"No budgets are over their limit."}</p>
+                                        }
+                                        { for shown_budgets.iter().map(|b| {
+                                            let spent = spent_by_category.get(&category_key(&b.category)).cloned().unwrap_or(0);
+                                            let remaining = (b.limit - spent).max(0);
+                                            let percent = percent_of(spent, b.limit);
+                                            html! {
+                                                <div class="flex flex-col gap-1 p-3 border rounded">
+                                                    <div class="flex items-center justify-between">
+                                                        <span class="font-semibold text-foreground">{ b.category.clone() }</span>
+                                                        <div class="flex items-center gap-3">
+                                                            { sparkline(&category_month_series(&history, &b.category, &trend_months, bounds, savings_as_spending)) }
+                                                            <span class="text-sm text-muted-foreground">{ format!("{}% used", percent) }</span>
+                                                        </div>
+                                                    </div>
+                                                    <div class="flex items-center justify-between text-sm">
+                                                        <span class="text-muted-foreground">{"Spent: "}<CurrencyAmount amount={spent} currency={currency.clone()} /></span>
+                                                        <span class="text-muted-foreground">{"Remaining: "}<CurrencyAmount amount={remaining} currency={currency.clone()} /></span>
+                                                    </div>
+                                                    { limit_history(&b.history, &currency) }
+                                                </div>
+                                            }
+                                        }) }
+                                        if !*over_only && !idle_budgets.is_empty() {
+                                            <div class="p-3 border border-dashed rounded">
+                                                <p class="text-xs text-muted-foreground mb-2">{"No spend this period — pick one to edit its limit"}</p>
+                                                <div class="flex flex-wrap gap-2">
+                                                    { for idle_budgets.iter().map(|b| {
+                                                        let onclick = {
+                                                            let budget_category = budget_category.clone();
+                                                            let budget_limit = budget_limit.clone();
+                                                            let suggestion_basis = suggestion_basis.clone();
+                                                            let pick = (b.category.clone(), currency.entry_text(b.limit));
+                                                            let budget_category_ref = budget_category_ref.clone();
+                                                            Callback::from(move |_| {
+                                                                suggestion_basis.set(None);
+                                                                budget_category.set(pick.0.clone());
+                                                                budget_limit.set(pick.1.clone());
+                                                                focus_node(&budget_category_ref);
+                                                            })
+                                                        };
+                                                        html! {
+                                                            <button type="button" {onclick} class="px-3 py-1 rounded-full border border-border text-xs text-foreground hover:bg-muted/40">
+                                                                { format!("{} · {}", b.category, format_currency(b.limit, &currency)) }
+                                                            </button>
+                                                        }
+                                                    }) }
+                                                </div>
+                                            </div>
+                                        }
+                                    </div>
+                                }
+                            }}
+                        </div>
+                    </div>
+
+                    <div>
+                        <h2 class="text-lg font-bold text-foreground mb-3">{"Expense Breakdown"}</h2>
+                        <div class="bg-card rounded-[10px] border border-border overflow-hidden">
+                            <div class="p-6">
+                                { if category_totals.is_empty() {
+                                    html! { <p class="text-sm text-muted-foreground">{"Add expense transactions from the Dashboard to see breakdowns."}</p> }
+                                } else {
+                                    html! {
+                                        <div class="space-y-2">
+                                            { for category_totals.iter().map(|(cat, amt)| {
+                                                let share = percent_of(*amt, *total_spent);
+                                                // larger categories are drawn more saturated
+                                                let largest = category_totals.first().map(|(_, top)| *top).unwrap_or(0);
+                                                let intensity = 0.35 + 0.65 * percent_of(*amt, largest) as f64 / 100.0;
+                                                html! {
+                                                    <div class="flex items-center gap-3 text-sm">
+                                                        <span class="w-28 md:w-36 shrink-0 truncate text-muted-foreground" title={cat.clone()}>{ cat.clone() }</span>
+                                                        <div class="flex-1 h-3 bg-secondary rounded-full overflow-hidden" role="img" aria-label={format!("{}: {}% of expenses", cat, share)}>
+                                                            <div class="h-full rounded-full" style={format!("width: {}%; background-color: {}; opacity: {:.2}", share.min(100), category_color(cat), intensity)}></div>
+                                                        </div>
+                                                        <span class="shrink-0 font-semibold text-foreground text-right"><CurrencyAmount amount={*amt} currency={currency.clone()} />{ format!(" · {}%", share) }</span>
+                                                    </div>
+                                                }
+                                            }) }
                                         </div>
+                                    }
+                                }}
+                            </div>
+                        </div>
+                    </div>
+                </>
+            }
+        ) }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct IncomePageProps {
+    /// Opens the Dashboard add form pre-filled from a row.
+    on_duplicate: Callback<Transaction>,
+}
+
+#[function_component(IncomePage)]
+fn income_page(props: &IncomePageProps) -> Html {
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    let currency = use_currency_format();
+
+    let auto_save_percent = settings.as_ref().map(|s| s.auto_save_percent).unwrap_or(0);
+    let rounding = settings
+        .as_ref()
+        .map(|s| s.rounding_mode)
+        .unwrap_or_default();
+
+    let incomes = use_state(Vec::<Transaction>::new);
+    let amount_ref = use_node_ref();
+    let loading = use_state(|| true);
+    let scope = use_request_scope();
+    let load_error = use_state(|| None::<ApiError>);
+    let load_attempt = use_state(|| 0u32);
+    let refreshed_at = use_state(|| None::<f64>);
+    let selected = use_state(HashSet::<i32>::new);
+    let deleting = use_state(|| false);
+    let auto_save_offer = use_state(|| None::<Transaction>);
+
+    // date inputs default to, and are capped at, today
+    let today = today_key();
+    let form_date = use_state(today_key);
+    let form_amount = use_state(|| "".to_string());
+    let form_category = use_state(|| load_last_category("income", DEFAULT_INCOME_CATEGORY));
+    let form_description = use_state(|| "".to_string());
+    let form_note = use_state(|| "".to_string());
+    let accounts = use_state(load_accounts);
+    let form_account = {
+        let first = accounts.first().cloned().unwrap_or_default();
+        use_state(move || first)
+    };
+    let form_tax = use_state(|| false);
+    let form_error = use_state(|| None::<String>);
+    let saving = use_state(|| false);
+
+    {
+        let incomes = incomes.clone();
+        let loading = loading.clone();
+        let load_error = load_error.clone();
+        let refreshed_at = refreshed_at.clone();
+        use_effect_with_deps(
+            move |_| {
+                load_error.set(None);
+                loading.set(true);
+                spawn_local(async move {
+                    match fetch_transactions(Some(&scope)).await {
+                        Ok(list) => {
+                            let filtered = list
+                                .into_iter()
+                                .filter(|t| t.amount > 0 && !is_transfer(t))
+                                .collect::<Vec<_>>();
+                            incomes.set(filtered);
+                            refreshed_at.set(Some(js_sys::Date::now()));
+                        }
+                        Err(ApiError::Cancelled) => return,
+                        Err(err) => load_error.set(Some(err)),
+                    }
+                    loading.set(false);
+                });
+                || ()
+            },
+            *load_attempt,
+        );
+    }
+
+    let total_balance: i64 = incomes.iter().map(|item| item.amount).sum();
+
+    // per-source totals and counts, largest first (mirrors the Expense Breakdown)
+    let counts = incomes
+        .iter()
+        .fold(HashMap::<String, usize>::new(), |mut counts, item| {
+            *counts.entry(category_key(&item.category)).or_insert(0) += 1;
+            counts
+        });
+    let mut sources: Vec<(String, i64, usize)> = sum_by_category(
+        incomes
+            .iter()
+            .map(|item| (item.category.as_str(), item.amount)),
+    )
+    .into_iter()
+    .map(|(category, amount)| {
+        let count = counts.get(&category_key(&category)).copied().unwrap_or(0);
+        (category, amount, count)
+    })
+    .collect();
+    sources.sort_by_key(|b| std::cmp::Reverse(b.1));
+    let on_delete_selected = bulk_delete_callback(&incomes, &selected, &deleting);
+    let confirm_bulk_delete = use_state(|| false);
+    let duplicate_pending = use_state(|| false);
+
+    let on_add = {
+        let incomes = incomes.clone();
+        let auto_save_offer = auto_save_offer.clone();
+        let form_date = form_date.clone();
+        let form_amount = form_amount.clone();
+        let form_category = form_category.clone();
+        let form_description = form_description.clone();
+        let form_note = form_note.clone();
+        let form_account = form_account.clone();
+        let form_tax = form_tax.clone();
+        let form_error = form_error.clone();
+        let saving = saving.clone();
+        let duplicate_pending = duplicate_pending.clone();
+        let currency = currency.clone();
+        Callback::from(move |allow_duplicate: bool| {
+            let date_val = form_date.trim().to_string();
+            let desc_val = form_description.trim().to_string();
+            let cat_val = normalize_category(&form_category);
+            let amt_val = form_amount.trim().to_string();
+            let note_val = optional_text(&form_note);
+            let account_val = (*form_account).clone();
+            let currency_val = stored_currency(
+                &account_currency(&load_account_currencies(), &account_val, &currency.code),
+                &currency.code,
+            );
+            let tax_val = *form_tax;
+
+            if date_val.is_empty()
+                || desc_val.is_empty()
+                || cat_val.is_empty()
+                || amt_val.is_empty()
+            {
+                form_error.set(Some("Please complete all fields.".to_string()));
+                return;
+            }
+
+            let parsed =
+                match parse_entered_amount(&amt_val, &currency.for_code(currency_val.as_deref())) {
+                    Ok(amount) if amount > 0 => amount,
+                    Ok(_) => {
+                        form_error.set(Some("Amount must be a positive number.".to_string()));
+                        return;
+                    }
+                    Err(msg) => {
+                        form_error.set(Some(msg));
+                        return;
+                    }
+                };
+            if !allow_duplicate && is_possible_duplicate(&incomes, &date_val, &desc_val, parsed) {
+                duplicate_pending.set(true);
+                return;
+            }
+
+            form_error.set(None);
+            saving.set(true);
+
+            let incomes = incomes.clone();
+            let auto_save_offer = auto_save_offer.clone();
+            let form_date = form_date.clone();
+            let form_amount = form_amount.clone();
+            let form_description = form_description.clone();
+            let form_note = form_note.clone();
+            let form_tax = form_tax.clone();
+            let saving = saving.clone();
+            let app_code = currency.code.clone();
+            spawn_local(async move {
+                let url = format!("{}/api/transactions", API_BASE_URL);
+                let payload = serde_json::json!({
+                    "date": date_val.as_str(),
+                    "description": desc_val.as_str(),
+                    "category": cat_val.as_str(),
+                    "amount": parsed,
+                    "note": note_val.as_deref(),
+                    "account": account_val.as_str(),
+                    "tax": tax_val,
+                    "currency": currency_val.as_deref()
+                });
+
+                invalidate_dashboard_cache();
+                let request = api_request(Request::post(&url)).json(&payload);
+
+                if let Ok(resp) = send_api(request, None).await {
+                    if resp.ok() {
+                        if let Ok(created) = resp.json::<Transaction>().await {
+                            if should_offer_auto_save(
+                                &created,
+                                auto_save_percent,
+                                rounding,
+                                &app_code,
+                            ) {
+                                auto_save_offer.set(Some(created.clone()));
+                            }
+                            let mut next = (*incomes).clone();
+                            next.insert(0, created);
+                            incomes.set(next);
+                            form_date.set(today_key());
+                            form_amount.set("".to_string());
+                            save_last_category("income", &cat_val);
+                            form_description.set("".to_string());
+                            form_note.set("".to_string());
+                            form_tax.set(false);
+                        }
+                    }
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let on_clear = {
+        let form_amount = form_amount.clone();
+        let form_description = form_description.clone();
+        let form_date = form_date.clone();
+        let form_note = form_note.clone();
+        let form_tax = form_tax.clone();
+        Callback::from(move |_| {
+            form_date.set(today_key());
+            form_amount.set("".to_string());
+            form_description.set("".to_string());
+            form_note.set("".to_string());
+            form_tax.set(false);
+        })
+    };
+
+    html! {
+        { page_shell(
+            "Income Tracker",
+            html! {
+                <RefreshStatus refreshed_at={*refreshed_at} refreshing={*loading} on_refresh={{
+                    let load_attempt = load_attempt.clone();
+                    Callback::from(move |_| load_attempt.set(*load_attempt + 1))
+                }} />
+            },
+            html! {
+                <>
+                    { load_error_banner(*load_error, &load_attempt) }
+                    <div class="grid grid-cols-1 lg:grid-cols-12 gap-4 items-stretch">
+                <div class="lg:col-span-4 bg-card p-5 rounded-[10px] shadow-sm border border-border flex flex-col justify-center">
+                    <div class="flex items-center gap-2 mb-1">
+                        <div class="p-1.5 bg-muted rounded-lg">{ icon_wallet() }</div>
+                        <span class="text-muted-foreground text-[10px] font-bold mb-1 tracking-widest">{"Total Available Balance"}</span>
+                    </div>
+                    <h3 class="text-2xl font-bold text-primary tracking-tight"><CurrencyAmount amount={total_balance} currency={currency.clone()} /></h3>
+                </div>
+
+                <div class="lg:col-span-8 bg-card p-5 rounded-[10px] shadow-sm border border-border">
+                    <h4 class="text-primary font-bold text-[15px] mb-3 tracking-wider">{"Add New Income"}</h4>
+                    <div class="grid grid-cols-1 sm:grid-cols-2 md:grid-cols-4 gap-3 mb-4">
+                        <div class="space-y-1">
+                            <label class="text-[12px] font-bold text-muted-foreground">{"Date"}</label>
+                            <input type="date" max={today.clone()} value={(*form_date).clone()} oninput={{
+                                let form_date = form_date.clone();
+                                Callback::from(move |e: InputEvent| {
+                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                    form_date.set(input.value());
+                                })
+                            }} class="w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                        </div>
+                        <div class="space-y-1">
+                            <label class="text-[12px] font-bold text-muted-foreground">{ format!("Amount ({})", currency.symbol) }</label>
+                            <input ref={amount_ref.clone()} type="text" inputmode="decimal" pattern={amount_pattern('.', currency.entry_places())} placeholder={currency.entry_placeholder(format_currency(0, &currency))} value={(*form_amount).clone()} oninput={amount_oninput(&form_amount, '.', currency.entry_places())} class="w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                            { entry_preview(&form_amount, &currency) }
+                        </div>
+                        <div class="space-y-1">
+                            <label class="text-[12px] font-bold text-muted-foreground">{"Description"}</label>
+                            <input type="text" placeholder="Income source" value={(*form_description).clone()} oninput={{
+                                let form_description = form_description.clone();
+                                Callback::from(move |e: InputEvent| {
+                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                    form_description.set(input.value());
+                                })
+                            }} class="w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                        </div>
+                        <div class="space-y-1">
+                            <div class="flex items-center justify-between">
+                                <label class="text-[12px] font-bold text-muted-foreground">{"Category"}</label>
+                                { category_reset_button("income", DEFAULT_INCOME_CATEGORY, &form_category) }
+                            </div>
+                            <select onchange={{
+                                let form_category = form_category.clone();
+                                Callback::from(move |e: Event| {
+                                    let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                    form_category.set(input.value());
+                                })
+                            }} class="w-full bg-muted border-2 border-transparent rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] focus:ring-2 focus:ring-ring outline-none">
+                                { for INCOME_CATEGORIES.iter().map(|category| html! {
+                                    <option selected={form_category.as_str() == *category}>{ *category }</option>
+                                }) }
+                            </select>
+                        </div>
+                    </div>
+                    <div class="grid grid-cols-1 md:grid-cols-3 gap-3 mb-4">
+                        <select onchange={{
+                            let form_account = form_account.clone();
+                            Callback::from(move |e: Event| {
+                                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                form_account.set(input.value());
+                            })
+                        }} aria-label="Account" class="w-full bg-muted border-2 border-transparent rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] focus:ring-2 focus:ring-ring outline-none">
+                            { account_options(&accounts, &form_account) }
+                        </select>
+                        <input type="text" placeholder="Note (optional)" value={(*form_note).clone()} oninput={{
+                            let form_note = form_note.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                form_note.set(input.value());
+                            })
+                        }} class="md:col-span-2 w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                        { tax_checkbox(&form_tax) }
+                    </div>
+                    <div class="flex gap-3">
+                        <button onclick={on_add.reform(|_| false)} class="flex-1 bg-primary text-primary-foreground py-3 md:py-2 rounded-[10px] text-xs md:text-[10px] font-bold flex items-center justify-center gap-2" disabled={*saving}>{ if *saving { "Saving..." } else { "Add Income" } }</button>
+                        <button onclick={on_clear} class="flex-1 bg-secondary text-primary py-3 md:py-2 rounded-[10px] text-xs md:text-[10px] font-bold flex items-center justify-center gap-2">{"Clear"}</button>
+                    </div>
+                    {
+                        if let Some(msg) = &*form_error {
+                            html! { <p class="text-sm text-red-500 mt-3">{ msg.clone() }</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </div>
+                    if let Some(income) = &*auto_save_offer {
+                        <AutoSaveOffer
+                            income={income.clone()}
+                            percent={auto_save_percent}
+                            currency={currency.clone()}
+                            on_saved={{
+                                let load_attempt = load_attempt.clone();
+                                Callback::from(move |_| load_attempt.set(*load_attempt + 1))
+                            }}
+                            on_dismiss={{
+                                let auto_save_offer = auto_save_offer.clone();
+                                Callback::from(move |_| auto_save_offer.set(None))
+                            }}
+                        />
+                    }
+                    <div class="bg-card rounded-[10px] shadow-sm border border-border overflow-hidden">
+                        <div class="p-5 border-b border-border flex items-center justify-between">
+                            <h3 class="font-bold text-lg text-foreground">{"Income History"}</h3>
+                            { bulk_actions_bar(selected.len(), *deleting, &confirm_bulk_delete, &on_delete_selected) }
+                            { duplicate_confirm(&duplicate_pending, &on_add) }
+                        </div>
+                        <div class="overflow-auto max-h-[70vh]">
+                            <table class="w-full min-w-[640px] text-left border-collapse">
+                                <caption class="sr-only">{"Income history"}</caption>
+                                <thead class="sticky top-0 z-10">
+                                    <tr class="bg-muted text-muted-foreground text-[10px] uppercase tracking-widest">
+                                        <th scope="col" class="pl-8 py-4 w-8">{ select_all_checkbox(&selected, &incomes) }</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Date"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Description"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Category"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold text-right">{"Amount"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Action"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody class="divide-y divide-border">
+                                    { if *loading {
+                                        html! { <tr><td colspan="6" class="px-8 py-6 text-center text-muted-foreground">{"Loading..."}</td></tr> }
+                                    } else if incomes.is_empty() {
+                                        html! { <tr><td colspan="6" class="px-8 py-2"><EmptyState icon={icon_trending_up()} message="No income transactions yet." action_label="Add income" on_action={focus_input_callback(&amount_ref)} /></td></tr> }
+                                    } else {
+                                        html! {
+                                            <>
+                                                { for incomes.iter().enumerate().map(|(idx, item)| html! {
+                                                    <tr key={transaction_key(item, idx)} class="text-sm hover:bg-muted/40 transition-colors group">
+                                                        <td class="pl-8 py-4">{ row_checkbox(&selected, item) }</td>
+                                                        <td class="px-8 py-4 text-muted-foreground">{ item.date.clone() }</td>
+                                                        <td class="px-8 py-4 text-foreground">{ item.description.clone() }{ note_details(item) }</td>
+                                                        <td class="px-6 py-4">
+                                                            <span class="bg-secondary text-secondary-foreground px-2.5 py-1 rounded-md text-[9px] font-bold">{ item.category.clone() }</span>
+                                                        </td>
+                                                        <td class="px-6 py-4 text-right font-semibold text-foreground">{ amount_cell(item, &currency, true) }</td>
+                                                        <td class="px-8 py-4">{ duplicate_button(&props.on_duplicate, item) }</td>
+                                                    </tr>
+                                                }) }
+                                            </>
+                                        }
+                                    }}
+                                </tbody>
+                            </table>
+                        </div>
+                    </div>
+
+                    <div>
+                        <h2 class="text-lg font-bold text-foreground mb-3">{"Income Breakdown"}</h2>
+                        <div class="bg-card rounded-[10px] border border-border overflow-hidden">
+                            <div class="p-6">
+                                { if sources.is_empty() {
+                                    html! { <p class="text-sm text-muted-foreground">{"Add income to see where it comes from."}</p> }
+                                } else {
+                                    html! {
+                                        <div class="space-y-3">
+                                            { for sources.iter().map(|(category, amount, count)| {
+                                                let share = percent_of(*amount, total_balance);
+                                                html! {
+                                                    <div class="flex flex-col gap-1 text-sm">
+                                                        <div class="flex items-center justify-between">
+                                                            <span class="text-foreground">{ format!("{} ({})", category, count) }</span>
+                                                            <span class="font-semibold text-foreground"><CurrencyAmount amount={*amount} currency={currency.clone()} />{ format!(" · {}%", share) }</span>
+                                                        </div>
+                                                        <div class="h-2 w-full bg-secondary rounded-full overflow-hidden">
+                                                            <div class="h-full bg-primary" style={format!("width: {}%", share.min(100))}></div>
+                                                        </div>
+                                                    </div>
+                                                }
+                                            }) }
+                                        </div>
+                                    }
+                                }}
+                            </div>
+                        </div>
+                    </div>
+                </>
+            }
+        ) }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+struct Contribution {
+    date: String,
+    description: String,
+    amount: i64,
+    /// Id of the goal this funds; empty for contributions recorded before
+    /// goals had ids.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    goal_id: String,
+    /// Spare change from rounding up an expense; see `save_round_up`.
+    #[serde(default)]
+    round_up: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+struct SavingGoalState {
+    /// Stamped on the goal's contributions. Empty for a goal saved before
+    /// ids existed.
+    #[serde(default)]
+    id: String,
+    title: String,
+    target_amount: i64,
+    target_date: String,
+    contributions: Vec<Contribution>,
+    #[serde(default)]
+    cadence: SavingCadence,
+}
+
+/// How often the user plans to contribute to the goal.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum SavingCadence {
+    #[default]
+    None,
+    Weekly,
+    Monthly,
+}
+
+impl SavingCadence {
+    const ALL: [SavingCadence; 3] = [
+        SavingCadence::None,
+        SavingCadence::Weekly,
+        SavingCadence::Monthly,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            SavingCadence::None => "none",
+            SavingCadence::Weekly => "weekly",
+            SavingCadence::Monthly => "monthly",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SavingCadence::None => "No schedule",
+            SavingCadence::Weekly => "Weekly",
+            SavingCadence::Monthly => "Monthly",
+        }
+    }
+
+    fn from_key(key: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.key() == key)
+            .unwrap_or_default()
+    }
+
+    /// Identifies the period `date` (`YYYY-MM-DD`) falls in: its month
+    /// period, or the first day of its week. `None` without a cadence.
+    fn period_key(self, date: &str, bounds: PeriodBounds) -> Option<String> {
+        match self {
+            SavingCadence::None => None,
+            SavingCadence::Monthly => month_key(date, bounds),
+            SavingCadence::Weekly => {
+                let (year, month, day) = parse_ymd(date)?;
+                let start = week_start_of(days_from_civil(year, month, day), bounds.week_start);
+                let (y, m, d) = civil_from_days(start);
+                Some(format!("{:04}-{:02}-{:02}", y, m, d))
+            }
+        }
+    }
+}
+
+fn load_saving_goal() -> SavingGoalState {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(Some(raw)) = storage.get_item("saving_goal") {
+                if let Ok(goal) = serde_json::from_str::<SavingGoalState>(&raw) {
+                    return goal;
+                }
+            }
+        }
+    }
+
+    SavingGoalState {
+        id: "".to_string(),
+        title: "New Goal".to_string(),
+        target_amount: 0,
+        target_date: "".to_string(),
+        contributions: vec![],
+        cadence: SavingCadence::None,
+    }
+}
+
+/// The placeholder `load_saving_goal` returns before the user sets a goal.
+fn is_default_goal(goal: &SavingGoalState) -> bool {
+    goal.target_amount == 0 && goal.contributions.is_empty()
+}
+
+fn save_saving_goal(goal: &SavingGoalState) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(raw) = serde_json::to_string(goal) {
+                let _ = storage.set_item("saving_goal", &raw);
+            }
+        }
+    }
+}
+
+/// Whether the goal has a target and contributions have reached it.
+fn goal_completed(goal: &SavingGoalState) -> bool {
+    goal.target_amount > 0
+        && goal.contributions.iter().map(|c| c.amount).sum::<i64>() >= goal.target_amount
+}
+
+/// Notifies the user when the goal has a cadence and nothing has been
+/// contributed in the current period. Fires at most once per period.
+fn remind_goal_contribution(settings: &AppSettings) {
+    if !settings.saving_alerts || !notifications_supported() {
+        return;
+    }
+    if web_sys::Notification::permission() != web_sys::NotificationPermission::Granted {
+        return;
+    }
+    let goal = load_saving_goal();
+    let bounds = PeriodBounds::from_settings(settings);
+    let Some(period) = goal.cadence.period_key(&today_key(), bounds) else {
+        return;
+    };
+    if goal.target_amount <= 0 || goal_completed(&goal) {
+        return;
+    }
+    let contributed = goal
+        .contributions
+        .iter()
+        .any(|c| goal.cadence.period_key(&c.date, bounds).as_ref() == Some(&period));
+    if contributed {
+        return;
+    }
+
+    let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+        return;
+    };
+    if storage
+        .get_item("goal_reminder_sent")
+        .ok()
+        .flatten()
+        .as_deref()
+        == Some(period.as_str())
+    {
+        return;
+    }
+    let options = web_sys::NotificationOptions::new();
+    options.set_body(&format!(
+        "You haven't added to \"{}\" {}.",
+        goal.title,
+        if goal.cadence == SavingCadence::Weekly {
+            "this week"
+        } else {
+            "this month"
+        }
+    ));
+    if web_sys::Notification::new_with_options("Saving goal reminder", &options).is_ok() {
+        let _ = storage.set_item("goal_reminder_sent", &period);
+    }
+}
+
+/// Id for a newly created goal.
+fn new_goal_id() -> String {
+    format!("goal-{}", js_sys::Date::now() as u64)
+}
+
+/// Records a contribution on the stored goal and returns the updated goal.
+fn add_goal_contribution(mut entry: Contribution) -> SavingGoalState {
+    let mut goal = load_saving_goal();
+    entry.goal_id = goal.id.clone();
+    goal.contributions.insert(0, entry);
+    save_saving_goal(&goal);
+    goal
+}
+
+/// Entries shown in the Dashboard's "Recent Activity" feed.
+const ACTIVITY_FEED_LIMIT: usize = 8;
+
+enum ActivityItem {
+    Transaction(Transaction),
+    /// A saving-goal contribution; stands in for its offsetting `Savings`
+    /// transaction when one is found.
+    Contribution(Contribution),
+}
+
+impl ActivityItem {
+    fn day(&self) -> &str {
+        let date = match self {
+            ActivityItem::Transaction(tx) => &tx.date,
+            ActivityItem::Contribution(entry) => &entry.date,
+        };
+        date.get(0..10).unwrap_or(date)
+    }
+}
+
+/// Pairs each contribution with its offsetting `Savings` transaction (same
+/// day, negated amount), each transaction used at most once. Returns the
+/// matched transaction index per contribution and which transactions were
+/// matched.
+fn match_contributions(
+    transactions: &[Transaction],
+    contributions: &[Contribution],
+) -> (Vec<Option<usize>>, Vec<bool>) {
+    let mut consumed = vec![false; transactions.len()];
+    let matches = contributions
+        .iter()
+        .map(|entry| {
+            let offset = transactions.iter().enumerate().position(|(i, tx)| {
+                !consumed[i]
+                    && tx.category == SAVINGS_CATEGORY
+                    && tx.amount == -entry.amount
+                    && tx.date.get(0..10) == entry.date.get(0..10)
+            });
+            if let Some(i) = offset {
+                consumed[i] = true;
+            }
+            offset
+        })
+        .collect();
+    (matches, consumed)
+}
+
+/// Latest transactions and goal contributions as one timeline, newest first.
+/// Each contribution hides one `Savings` transaction with the same day and
+/// amount, since `savings_payload` records the same event on the server.
+fn activity_feed(
+    transactions: &[Transaction],
+    contributions: &[Contribution],
+) -> Vec<ActivityItem> {
+    let (_, consumed) = match_contributions(transactions, contributions);
+
+    let mut items: Vec<ActivityItem> = transactions
+        .iter()
+        .zip(consumed)
+        .filter(|(_, consumed)| !consumed)
+        .map(|(tx, _)| ActivityItem::Transaction(tx.clone()))
+        .chain(
+            contributions
+                .iter()
+                .rev()
+                .cloned()
+                .map(ActivityItem::Contribution),
+        )
+        .collect();
+    // stable, so same-day entries keep their newest-first order
+    items.sort_by(|a, b| b.day().cmp(a.day()));
+    items.truncate(ACTIVITY_FEED_LIMIT);
+    items
+}
+
+fn activity_feed_card(
+    items: &[ActivityItem],
+    on_select_tx: &Callback<Transaction>,
+    currency: &CurrencyFormat,
+) -> Html {
+    html! {
+        <div class="bg-card rounded-[10px] p-6 border border-border">
+            <h3 class="font-bold text-foreground text-lg mb-4">{"Recent Activity"}</h3>
+            if items.is_empty() {
+                <p class="text-sm text-muted-foreground">{"Nothing recorded yet."}</p>
+            } else {
+                <ul class="divide-y divide-border">
+                    { for items.iter().enumerate().map(|(idx, item)| match item {
+                        ActivityItem::Transaction(tx) => html! {
+                            <li key={format!("tx-{}", transaction_key(tx, idx))} class="flex items-center justify-between gap-3 py-2 text-sm cursor-pointer hover:bg-muted/30" tabindex="0" aria-haspopup="dialog" onclick={row_select_callback(on_select_tx, tx)} onkeydown={row_keyboard_callback(on_select_tx, tx)}>
+                                <div class="min-w-0">
+                                    <p class="text-foreground truncate">{ &tx.description }</p>
+                                    <p class="text-xs text-muted-foreground">{ format!("{} · {}", item.day(), category_label(tx)) }</p>
+                                </div>
+                                <span class="font-semibold shrink-0">{ amount_cell(tx, currency, true) }</span>
+                            </li>
+                        },
+                        ActivityItem::Contribution(entry) => html! {
+                            <li key={format!("contribution-{}", idx)} class="flex items-center justify-between gap-3 py-2 text-sm">
+                                <div class="min-w-0">
+                                    <p class="text-foreground truncate">{ &entry.description }</p>
+                                    <p class="text-xs text-muted-foreground">
+                                        { item.day() }{" · "}
+                                        <span class="px-2 py-0.5 rounded-full bg-muted text-primary text-[10px] font-bold">{"Savings"}</span>
+                                    </p>
+                                </div>
+                                <span class="font-semibold text-primary shrink-0">
+                                    <span aria-hidden="true">{"→ "}<CurrencyAmount amount={entry.amount} currency={currency.clone()} /></span>
+                                    <span class="sr-only">{ format!("saved {}", format_currency(entry.amount, currency)) }</span>
+                                </span>
+                            </li>
+                        },
+                    }) }
+                </ul>
+            }
+        </div>
+    }
+}
+
+/// Drift between the goal's locally stored contributions and the backend's
+/// `Savings` transactions.
+#[derive(Clone, PartialEq, Default)]
+struct SavingsCheck {
+    /// Contributions with no offsetting transaction.
+    missing: Vec<Contribution>,
+    /// `Savings` expenses on or after the first contribution that no
+    /// contribution accounts for.
+    untracked: Vec<Transaction>,
+}
+
+impl SavingsCheck {
+    fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.untracked.is_empty()
+    }
+}
+
+/// Compares `goal` with the loaded transactions. Older `Savings` rows, and
+/// rows posted for a differently titled goal, are left alone since they
+/// belong to a goal that was since replaced.
+fn check_savings(goal: &SavingGoalState, transactions: &[Transaction]) -> SavingsCheck {
+    let (matches, consumed) = match_contributions(transactions, &goal.contributions);
+    // queued ones are already on their way; re-posting them would double up
+    let pending = load_pending_savings();
+    let missing = goal
+        .contributions
+        .iter()
+        .zip(&matches)
+        .filter(|(entry, matched)| matched.is_none() && !is_pending_savings(entry, &pending))
+        .map(|(entry, _)| entry.clone())
+        .collect();
+    let first_day = goal
+        .contributions
+        .iter()
+        .filter_map(|c| c.date.get(0..10))
+        .min();
+    let untracked = match first_day {
+        Some(first_day) => transactions
+            .iter()
+            .zip(consumed)
+            .filter(|(tx, consumed)| {
+                !consumed
+                    && tx.category == SAVINGS_CATEGORY
+                    && tx.amount < 0
+                    && tx.date.get(0..10).is_some_and(|day| day >= first_day)
+                    && savings_goal_title(tx).is_none_or(|title| title == goal.title.trim())
+            })
+            .map(|(tx, _)| tx.clone())
+            .collect(),
+        None => vec![],
+    };
+    SavingsCheck { missing, untracked }
+}
+
+/// Savings transactions whose POST failed, retried by
+/// `flush_pending_savings`. Stored so a reload doesn't lose them.
+fn load_pending_savings() -> Vec<serde_json::Value> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item("pending_savings").ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn save_pending_savings(payloads: &[serde_json::Value]) {
+    let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+        return;
+    };
+    if payloads.is_empty() {
+        let _ = storage.remove_item("pending_savings");
+    } else if let Ok(raw) = serde_json::to_string(payloads) {
+        let _ = storage.set_item("pending_savings", &raw);
+    }
+}
+
+fn queue_savings_post(payload: serde_json::Value) {
+    let mut pending = load_pending_savings();
+    pending.push(payload);
+    save_pending_savings(&pending);
+}
+
+/// Re-posts queued Savings transactions and returns how many went through.
+/// The queue is taken up front, so overlapping flushes never post one twice;
+/// failures are put back.
+async fn flush_pending_savings() -> usize {
+    let pending = load_pending_savings();
+    if pending.is_empty() {
+        return 0;
+    }
+    save_pending_savings(&[]);
+    let mut failed = Vec::new();
+    for payload in &pending {
+        if post_transaction(payload).await.is_none() {
+            failed.push(payload.clone());
+        }
+    }
+    let posted = pending.len() - failed.len();
+    if !failed.is_empty() {
+        failed.extend(load_pending_savings());
+        save_pending_savings(&failed);
+    }
+    posted
+}
+
+/// Whether `entry`'s Savings transaction is waiting in the retry queue.
+fn is_pending_savings(entry: &Contribution, pending: &[serde_json::Value]) -> bool {
+    pending.iter().any(|payload| {
+        payload.get("date").and_then(|d| d.as_str()) == Some(entry.date.as_str())
+            && payload.get("amount").and_then(|a| a.as_i64()) == Some(-entry.amount)
+    })
+}
+
+/// Offsetting `Savings` expense that keeps a contribution reflected in totals.
+/// The description leads with the goal's title so reports can tell goals
+/// apart; see `savings_goal_title`.
+fn savings_payload(
+    date: &str,
+    goal_title: &str,
+    description: &str,
+    amount: i64,
+) -> serde_json::Value {
+    let description = match goal_title.trim() {
+        "" => description.to_string(),
+        title => format!("{}: {}", title, description),
+    };
+    serde_json::json!({
+        "date": date,
+        "description": description,
+        "category": SAVINGS_CATEGORY,
+        "amount": -amount
+    })
+}
+
+/// Goal a `Savings` transaction was posted for, read back from the title
+/// `savings_payload` puts in front of the description. `None` for rows
+/// posted before descriptions carried the goal.
+fn savings_goal_title(tx: &Transaction) -> Option<&str> {
+    tx.description
+        .split_once(": ")
+        .map(|(title, _)| title.trim())
+        .filter(|title| !title.is_empty())
+}
+
+/// Total moved into savings per goal, largest first. Rows without a goal
+/// title are grouped under `None`.
+fn savings_by_goal(transactions: &[Transaction]) -> Vec<(Option<String>, i64)> {
+    let mut totals: Vec<(Option<String>, i64)> = Vec::new();
+    for tx in transactions.iter().filter(|tx| is_savings(tx)) {
+        let goal = savings_goal_title(tx).map(str::to_string);
+        match totals.iter_mut().find(|(existing, _)| *existing == goal) {
+            Some(row) => row.1 -= tx.amount,
+            None => totals.push((goal, -tx.amount)),
+        }
+    }
+    totals.sort_by_key(|row| std::cmp::Reverse(row.1));
+    totals
+}
+
+/// Share of an income to auto-save, rounded to a whole unit by `rounding`.
+fn auto_save_amount(income: i64, percent: u32, rounding: RoundingMode) -> i64 {
+    round_ratio(income * percent as i64, 100, rounding)
+}
+
+/// Whether a row is kept in the app currency. The goal and its `Savings`
+/// rows are, and there are no exchange rates to convert others with.
+fn in_app_currency(tx: &Transaction, app_code: &str) -> bool {
+    tx.currency
+        .as_deref()
+        .is_none_or(|code| stored_currency(code, app_code).is_none())
+}
+
+/// Whether a newly created transaction should prompt the auto-save offer.
+/// Incomes in another currency never do; see `in_app_currency`.
+fn should_offer_auto_save(
+    tx: &Transaction,
+    percent: u32,
+    rounding: RoundingMode,
+    app_code: &str,
+) -> bool {
+    percent > 0
+        && tx.amount > 0
+        && in_app_currency(tx, app_code)
+        && !is_transfer(tx)
+        && load_saving_goal().target_amount > 0
+        && auto_save_amount(tx.amount, percent, rounding) > 0
+}
+
+#[derive(Properties, PartialEq)]
+struct AutoSaveOfferProps {
+    income: Transaction,
+    percent: u32,
+    currency: CurrencyFormat,
+    /// Emitted with the offsetting `Savings` transaction once it is stored.
+    on_saved: Callback<Transaction>,
+    on_dismiss: Callback<()>,
+}
+
+/// Asks before moving part of a new income into the saving goal, so the
+/// opt-in auto-save never records anything silently.
+#[function_component(AutoSaveOffer)]
+fn auto_save_offer(props: &AutoSaveOfferProps) -> Html {
+    let goal = load_saving_goal();
+    let rounding = use_context::<UseStateHandle<AppSettings>>()
+        .map(|s| s.rounding_mode)
+        .unwrap_or_default();
+    let amount = auto_save_amount(props.income.amount, props.percent, rounding);
+    let busy = use_state(|| false);
+    let error = use_state(|| None::<String>);
+
+    let on_accept = {
+        let busy = busy.clone();
+        let error = error.clone();
+        let date = props
+            .income
+            .date
+            .get(0..10)
+            .unwrap_or(&props.income.date)
+            .to_string();
+        let on_saved = props.on_saved.clone();
+        let on_dismiss = props.on_dismiss.clone();
+        Callback::from(move |_| {
+            if *busy {
+                return;
+            }
+            busy.set(true);
+            error.set(None);
+            let payload = savings_payload(&date, &load_saving_goal().title, "Auto-save", amount);
+            let date = date.clone();
+            let busy = busy.clone();
+            let error = error.clone();
+            let on_saved = on_saved.clone();
+            let on_dismiss = on_dismiss.clone();
+            spawn_local(async move {
+                // the contribution is only recorded once the server has the
+                // offsetting transaction, so the two never disagree
+                let Some(created) = post_transaction(&payload).await else {
+                    busy.set(false);
+                    error.set(Some("Could not save to the goal. Try again.".to_string()));
+                    return;
+                };
+                add_goal_contribution(Contribution {
+                    date,
+                    description: "Auto-save from income".to_string(),
+                    amount,
+                    goal_id: "".to_string(),
+                    round_up: false,
+                });
+                on_saved.emit(created);
+                on_dismiss.emit(());
+            });
+        })
+    };
+    let on_skip = {
+        let on_dismiss = props.on_dismiss.clone();
+        Callback::from(move |_| on_dismiss.emit(()))
+    };
+
+    html! {
+        <div class="flex flex-wrap items-center justify-between gap-3 bg-card rounded-[10px] px-6 py-3 border border-border text-sm">
+            <span class="text-foreground">
+                { format!("Save {}% ({}) of this income to \"{}\"?", props.percent, format_currency(amount, &props.currency), goal.title) }
+            </span>
+            if let Some(msg) = &*error {
+                <p class="text-xs text-red-500" role="alert">{ msg.clone() }</p>
+            }
+            <div class="flex gap-2">
+                <button type="button" onclick={on_skip} class="px-3 py-1 rounded-[10px] text-xs font-bold bg-secondary text-primary">{"Skip"}</button>
+                <button type="button" onclick={on_accept} disabled={*busy} class="px-3 py-1 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{ if *busy { "Saving..." } else { "Save it" } }</button>
+            </div>
+        </div>
+    }
+}
+
+/// Spare change from rounding an expense up to the next multiple of
+/// `increment`; 0 when it already is one or rounding up is off.
+fn round_up_amount(expense: i64, increment: i64) -> i64 {
+    if increment <= 0 {
+        return 0;
+    }
+    match expense.abs() % increment {
+        0 => 0,
+        remainder => increment - remainder,
+    }
+}
+
+/// What `save_round_up` stored, kept so undoing the expense can take it back.
+#[derive(Clone, PartialEq)]
+struct RoundUp {
+    /// The contribution as recorded on the goal.
+    contribution: Contribution,
+    /// The posted `Savings` transaction; `None` while `payload` is queued.
+    transaction: Option<Transaction>,
+    payload: serde_json::Value,
+}
+
+/// Removes a round-up's contribution from `goal` and, when its post never
+/// went through, its payload from the retry queue. Matched by value, since
+/// entries added since then shift positions.
+fn forget_round_up(
+    round_up: &RoundUp,
+    goal: &mut SavingGoalState,
+    pending: &mut Vec<serde_json::Value>,
+) {
+    if let Some(pos) = goal
+        .contributions
+        .iter()
+        .position(|c| *c == round_up.contribution)
+    {
+        goal.contributions.remove(pos);
+    }
+    if round_up.transaction.is_none() {
+        if let Some(pos) = pending.iter().position(|p| *p == round_up.payload) {
+            pending.remove(pos);
+        }
+    }
+}
+
+/// Takes back a round-up: deletes its `Savings` transaction and drops the
+/// contribution. Returns false, leaving the contribution, if the delete fails.
+async fn undo_round_up(round_up: &RoundUp) -> bool {
+    if let Some(id) = round_up.transaction.as_ref().and_then(|tx| tx.id) {
+        if !delete_transaction(id).await {
+            return false;
+        }
+    }
+    let mut goal = load_saving_goal();
+    let mut pending = load_pending_savings();
+    forget_round_up(round_up, &mut goal, &mut pending);
+    save_saving_goal(&goal);
+    save_pending_savings(&pending);
+    true
+}
+
+/// Saves the round-up of a new expense to the goal: a contribution plus its
+/// offsetting `Savings` transaction, like any other contribution. A failed
+/// post is queued for retry instead. Expenses in another currency are
+/// skipped; see `in_app_currency`.
+async fn save_round_up(expense: &Transaction, increment: i64, app_code: &str) -> Option<RoundUp> {
+    if expense.amount >= 0
+        || is_transfer(expense)
+        || is_savings(expense)
+        || !in_app_currency(expense, app_code)
+    {
+        return None;
+    }
+    let amount = round_up_amount(expense.amount, increment);
+    if amount == 0 || load_saving_goal().target_amount <= 0 {
+        return None;
+    }
+    let date = expense.date.get(0..10).unwrap_or(&expense.date).to_string();
+    let goal = add_goal_contribution(Contribution {
+        date: date.clone(),
+        description: format!("Round-up: {}", expense.description),
+        amount,
+        goal_id: "".to_string(),
+        round_up: true,
+    });
+    let contribution = goal.contributions.first()?.clone();
+    let payload = savings_payload(&date, &goal.title, "Round-up", amount);
+    let created = post_transaction(&payload).await;
+    if created.is_none() {
+        queue_savings_post(payload.clone());
+    }
+    Some(RoundUp {
+        contribution,
+        transaction: created,
+        payload,
+    })
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+struct BudgetItem {
+    category: String,
+    limit: i64,
+    /// Past limit changes, newest first; stored with the budgets.
+    #[serde(default)]
+    history: Vec<LimitChange>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+struct LimitChange {
+    date: String,
+    old_limit: i64,
+    new_limit: i64,
+}
+
+/// Limit changes kept per budget; older ones are dropped.
+const BUDGET_HISTORY_LIMIT: usize = 12;
+
+impl BudgetItem {
+    /// Changes the limit, recording the old value when it differs.
+    fn set_limit(&mut self, limit: i64) {
+        if limit == self.limit {
+            return;
+        }
+        self.history.insert(
+            0,
+            LimitChange {
+                date: today_key(),
+                old_limit: self.limit,
+                new_limit: limit,
+            },
+        );
+        self.history.truncate(BUDGET_HISTORY_LIMIT);
+        self.limit = limit;
+    }
+}
+
+/// Splits budgets into those with spend this period and those without, in
+/// their original order. Nothing is split off unless `hide_zero_spend` is set.
+fn split_zero_spend<'a>(
+    budgets: &'a [BudgetItem],
+    spent_by_category: &HashMap<String, i64>,
+    hide_zero_spend: bool,
+) -> (Vec<&'a BudgetItem>, Vec<&'a BudgetItem>) {
+    budgets.iter().partition(|b| {
+        !hide_zero_spend
+            || spent_by_category
+                .get(&category_key(&b.category))
+                .is_some_and(|spent| *spent != 0)
+    })
+}
+
+/// Order of the Budget page's budget list.
+#[derive(Clone, Copy, PartialEq, Default)]
+enum BudgetSort {
+    #[default]
+    OverLimitFirst,
+    PercentUsed,
+    Remaining,
+    Alphabetical,
+}
+
+impl BudgetSort {
+    const ALL: [BudgetSort; 4] = [
+        BudgetSort::OverLimitFirst,
+        BudgetSort::PercentUsed,
+        BudgetSort::Remaining,
+        BudgetSort::Alphabetical,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            BudgetSort::OverLimitFirst => "over_limit",
+            BudgetSort::PercentUsed => "percent_used",
+            BudgetSort::Remaining => "remaining",
+            BudgetSort::Alphabetical => "alphabetical",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            BudgetSort::OverLimitFirst => "Over limit first",
+            BudgetSort::PercentUsed => "% used",
+            BudgetSort::Remaining => "Remaining",
+            BudgetSort::Alphabetical => "A–Z",
+        }
+    }
+
+    fn from_key(key: &str) -> BudgetSort {
+        BudgetSort::ALL
+            .into_iter()
+            .find(|sort| sort.key() == key)
+            .unwrap_or_default()
+    }
+}
+
+/// Orders budgets by `sort`, keeping only over-limit ones when `over_only`.
+/// Percentages are compared exactly rather than after rounding; ties fall
+/// back to the category name.
+fn sort_budgets<'a>(
+    budgets: &[&'a BudgetItem],
+    spent_by_category: &HashMap<String, i64>,
+    sort: BudgetSort,
+    over_only: bool,
+) -> Vec<&'a BudgetItem> {
+    let spent_of = |b: &BudgetItem| {
+        spent_by_category
+            .get(&category_key(&b.category))
+            .cloned()
+            .unwrap_or(0)
+    };
+    // spent / limit, with a non-positive limit treated as fully used
+    let used = |b: &BudgetItem| (spent_of(b) as i128, b.limit.max(1) as i128);
+    let mut rows: Vec<&BudgetItem> = budgets
+        .iter()
+        .copied()
+        .filter(|b| !over_only || spent_of(b) > b.limit)
+        .collect();
+    rows.sort_by(|x, y| {
+        let by_name = || category_key(&x.category).cmp(&category_key(&y.category));
+        let by_used = || {
+            let ((xs, xl), (ys, yl)) = (used(x), used(y));
+            (ys * xl).cmp(&(xs * yl))
+        };
+        match sort {
+            BudgetSort::OverLimitFirst => (spent_of(y) > y.limit)
+                .cmp(&(spent_of(x) > x.limit))
+                .then_with(by_used)
+                .then_with(by_name),
+            BudgetSort::PercentUsed => by_used().then_with(by_name),
+            BudgetSort::Remaining => (x.limit - spent_of(x))
+                .cmp(&(y.limit - spent_of(y)))
+                .then_with(by_name),
+            BudgetSort::Alphabetical => by_name(),
+        }
+    });
+    rows
+}
+
+/// How many budgets have spent past their limit.
+fn overspent_budgets(budgets: &[BudgetItem], spent_by_category: &HashMap<String, i64>) -> usize {
+    budgets
+        .iter()
+        .filter(|b| {
+            spent_by_category
+                .get(&category_key(&b.category))
+                .cloned()
+                .unwrap_or(0)
+                > b.limit
+        })
+        .count()
+}
+
+/// Category that goal contributions post their offsetting expense under.
+const SAVINGS_CATEGORY: &str = "Savings";
+
+/// Money moved to savings rather than spent.
+fn is_savings(tx: &Transaction) -> bool {
+    tx.amount < 0 && category_key(&tx.category) == category_key(SAVINGS_CATEGORY)
+}
+
+/// An expense that counts toward totals and budgets. Transfers never do;
+/// savings only when `savings_as_spending` is set.
+fn is_spending(tx: &Transaction, savings_as_spending: bool) -> bool {
+    tx.amount < 0 && !is_transfer(tx) && (savings_as_spending || !is_savings(tx))
+}
+
+/// Total spent per `category_key`, ignoring income and transfers.
+fn spent_by_category(
+    transactions: &[Transaction],
+    savings_as_spending: bool,
+) -> HashMap<String, i64> {
+    let mut spent: HashMap<String, i64> = HashMap::new();
+    for tx in transactions {
+        if is_spending(tx, savings_as_spending) {
+            *spent.entry(category_key(&tx.category)).or_insert(0) += tx.amount.abs();
+        }
+    }
+    spent
+}
+
+/// Trims a free-text category and collapses inner whitespace. The user's
+/// capitalization is kept; matching goes through `category_key`.
+fn normalize_category(raw: &str) -> String {
+    raw.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `part` as a rounded percentage of `whole`; 0 when `whole` is not positive.
+fn percent_of(part: i64, whole: i64) -> i64 {
+    if whole > 0 {
+        (part as f64 / whole as f64 * 100.0).round() as i64
+    } else {
+        0
+    }
+}
+
+/// Colors handed out to categories in charts.
+const CATEGORY_PALETTE: [&str; 8] = [
+    "#1D617A", "#173E63", "#E07A5F", "#3D9970", "#B8860B", "#8E44AD", "#C0392B", "#2E86AB",
+];
+
+/// A stable color for `category`, so it looks the same on every visit.
+fn category_color(category: &str) -> &'static str {
+    let hash = category_key(category)
+        .bytes()
+        .fold(0u32, |hash, b| hash.wrapping_mul(31).wrapping_add(b as u32));
+    CATEGORY_PALETTE[hash as usize % CATEGORY_PALETTE.len()]
+}
+
+/// Case-insensitive key for a category, so "food", " Food" and "FOOD" merge.
+fn category_key(category: &str) -> String {
+    normalize_category(category).to_lowercase()
+}
+
+/// Key for a payee taken from a free-text description, merged the same way
+/// as categories: "Jollibee" and "jollibee " are one merchant.
+fn merchant_key(description: &str) -> String {
+    category_key(description)
+}
+
+/// Rows in the Top Merchants report.
+const TOP_MERCHANTS: usize = 10;
+
+/// Spending grouped by merchant as `(label, total, count)`, largest total
+/// first. The label is the first spelling seen, with whitespace tidied.
+fn top_merchants(
+    transactions: &[Transaction],
+    savings_as_spending: bool,
+) -> Vec<(String, i64, usize)> {
+    let mut totals: Vec<(String, i64, usize)> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for tx in transactions
+        .iter()
+        .filter(|tx| is_spending(tx, savings_as_spending))
+    {
+        let key = merchant_key(&tx.description);
+        if key.is_empty() {
+            continue;
+        }
+        match index.get(&key) {
+            Some(&i) => {
+                totals[i].1 += tx.amount.abs();
+                totals[i].2 += 1;
+            }
+            None => {
+                index.insert(key, totals.len());
+                totals.push((normalize_category(&tx.description), tx.amount.abs(), 1));
+            }
+        }
+    }
+    totals.sort_by_key(|m| std::cmp::Reverse(m.1));
+    totals.truncate(TOP_MERCHANTS);
+    totals
+}
+
+/// Distinct categories and how many transactions use each, most used first.
+/// Transfers are skipped since their category is fixed.
+fn category_counts(transactions: &[Transaction]) -> Vec<(String, i64)> {
+    let mut counts = sum_by_category(
+        transactions
+            .iter()
+            .filter(|tx| !is_transfer(tx))
+            .map(|tx| (tx.category.as_str(), 1)),
+    );
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Expandable list of a budget's past limit changes; nothing when the limit
+/// was never changed.
+fn limit_history(history: &[LimitChange], currency: &CurrencyFormat) -> Html {
+    if history.is_empty() {
+        return html! {};
+    }
+    html! {
+        <details class="text-xs text-muted-foreground">
+            <summary class="cursor-pointer select-none">{ format!("Limit history ({})", history.len()) }</summary>
+            <ul class="mt-1 space-y-1">
+                { for history.iter().map(|change| html! {
+                    <li class="flex items-center justify-between">
+                        <span>{ change.date.clone() }</span>
+                        <span>
+                            <CurrencyAmount amount={change.old_limit} currency={currency.clone()} />
+                            {" → "}
+                            <CurrencyAmount amount={change.new_limit} currency={currency.clone()} />
+                        </span>
+                    </li>
+                }) }
+            </ul>
+        </details>
+    }
+}
+
+/// Rows filed under `from`, in any spelling, moved to `to`. Transfers keep
+/// their category.
+fn recategorized(transactions: &[Transaction], from: &str, to: &str) -> Vec<Transaction> {
+    let from_key = category_key(from);
+    transactions
+        .iter()
+        .filter(|tx| !is_transfer(tx) && category_key(&tx.category) == from_key)
+        .map(|tx| Transaction {
+            category: to.to_string(),
+            ..tx.clone()
+        })
+        .collect()
+}
+
+/// Points budgets for `from` at `to`. If `to` already has a budget, that one
+/// is kept and `from`'s is dropped, so a merge never leaves two.
+fn merge_budget_categories(mut budgets: Vec<BudgetItem>, from: &str, to: &str) -> Vec<BudgetItem> {
+    let from_key = category_key(from);
+    let to_key = category_key(to);
+    if from_key != to_key && budgets.iter().any(|b| category_key(&b.category) == to_key) {
+        budgets.retain(|b| category_key(&b.category) != from_key);
+    } else {
+        for budget in budgets
+            .iter_mut()
+            .filter(|b| category_key(&b.category) == from_key)
+        {
+            budget.category = to.to_string();
+        }
+    }
+    budgets
+}
+
+fn reassign_budgets(from: &str, to: &str) {
+    save_budgets(&merge_budget_categories(load_budgets(), from, to));
+}
+
+/// Sums amounts per category, merging spellings that share a `category_key`.
+/// Each total is labelled with the first spelling seen, in input order.
+fn sum_by_category<'a>(items: impl IntoIterator<Item = (&'a str, i64)>) -> Vec<(String, i64)> {
+    let mut totals: Vec<(String, i64)> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for (category, amount) in items {
+        match index.get(&category_key(category)) {
+            Some(&i) => totals[i].1 += amount,
+            None => {
+                index.insert(category_key(category), totals.len());
+                totals.push((normalize_category(category), amount));
+            }
+        }
+    }
+    totals
+}
+
+fn notifications_supported() -> bool {
+    web_sys::window()
+        .map(|w| js_sys::Reflect::has(&w, &JsValue::from_str("Notification")).unwrap_or(false))
+        .unwrap_or(false)
+}
+
+/// Asks for notification permission when it hasn't been decided yet; a
+/// refusal just sets `blocked` so the page can show a hint.
+fn request_notification_permission(blocked: &UseStateHandle<bool>) {
+    if !notifications_supported()
+        || web_sys::Notification::permission() != web_sys::NotificationPermission::Default
+    {
+        return;
+    }
+    if let Ok(promise) = web_sys::Notification::request_permission() {
+        let blocked = blocked.clone();
+        spawn_local(async move {
+            let result = JsFuture::from(promise)
+                .await
+                .ok()
+                .and_then(|v| v.as_string());
+            blocked.set(result.as_deref() == Some("denied"));
+        });
+    }
+}
+
+/// Fires a browser notification when `created` is the expense that takes its
+/// category past the budget limit. Each category alerts at most once per
+/// browser session (tracked in sessionStorage).
+fn alert_if_over_budget(
+    existing: &[Transaction],
+    created: &Transaction,
+    enabled: bool,
+    savings_as_spending: bool,
+) {
+    if !enabled || !is_spending(created, savings_as_spending) || !notifications_supported() {
+        return;
+    }
+    if web_sys::Notification::permission() != web_sys::NotificationPermission::Granted {
+        return;
+    }
+    let key = category_key(&created.category);
+    let Some(budget) = load_budgets()
+        .into_iter()
+        .find(|b| category_key(&b.category) == key)
+    else {
+        return;
+    };
+    let before = spent_by_category(existing, savings_as_spending)
+        .get(&key)
+        .copied()
+        .unwrap_or(0);
+    let after = before + created.amount.abs();
+    if before > budget.limit || after <= budget.limit {
+        return;
+    }
+
+    let Some(storage) = web_sys::window().and_then(|w| w.session_storage().ok().flatten()) else {
+        return;
+    };
+    let mut alerted: Vec<String> = storage
+        .get_item("budget_alerts_sent")
+        .ok()
+        .flatten()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default();
+    if alerted.contains(&key) {
+        return;
+    }
+
+    let options = web_sys::NotificationOptions::new();
+    options.set_body(&format!(
+        "You've spent more than your {} budget.",
+        created.category
+    ));
+    if web_sys::Notification::new_with_options("Budget exceeded", &options).is_ok() {
+        alerted.push(key);
+        if let Ok(raw) = serde_json::to_string(&alerted) {
+            let _ = storage.set_item("budget_alerts_sent", &raw);
+        }
+    }
+}
+
+fn load_budgets() -> Vec<BudgetItem> {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(Some(raw)) = storage.get_item("budgets") {
+                if let Ok(items) = serde_json::from_str::<Vec<BudgetItem>>(&raw) {
+                    return items;
+                }
+            }
+        }
+    }
+
+    vec![]
+}
+
+fn save_budgets(items: &Vec<BudgetItem>) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(raw) = serde_json::to_string(items) {
+                let _ = storage.set_item("budgets", &raw);
+            }
+        }
+    }
+}
+
+/// Account used for transactions saved before accounts existed.
+const DEFAULT_ACCOUNT: &str = "Default";
+
+/// What the user must type before "Delete all my data" is enabled.
+const RESET_CONFIRM_PHRASE: &str = "DELETE";
+
+/// localStorage entries holding the user's own data and preferences. The
+/// session token and layout state (`sidebar_collapsed`) are kept.
+const USER_DATA_KEYS: [&str; 13] = [
+    "settings",
+    "budgets",
+    "saving_goal",
+    "pending_savings",
+    "goal_reminder_sent",
+    "monthly_report_shown",
+    "notifications_read",
+    "onboarding",
+    "accounts",
+    "account_currencies",
+    "import_mappings",
+    "last_category:income",
+    "last_category:expense",
+];
+
+/// Removes everything `USER_DATA_KEYS` lists plus the per-user caches.
+fn clear_local_user_data() {
+    invalidate_dashboard_cache();
+    clear_summary_cache();
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            for key in USER_DATA_KEYS {
+                let _ = storage.remove_item(key);
+            }
+        }
+        if let Ok(Some(storage)) = window.session_storage() {
+            let _ = storage.remove_item("budget_alerts_sent");
+        }
+    }
+}
+
+const INCOME_CATEGORIES: [&str; 3] = ["Salary", "Freelance", "Investment"];
+const DEFAULT_INCOME_CATEGORY: &str = "Salary";
+const DEFAULT_EXPENSE_CATEGORY: &str = "Transportation";
+
+/// The category last saved from `form` ("income" or "expense"), so repeated
+/// entries in one category don't need re-picking.
+fn load_last_category(form: &str, default: &str) -> String {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| {
+            storage
+                .get_item(&format!("last_category:{}", form))
+                .ok()
+                .flatten()
+        })
+        .filter(|category| !category.trim().is_empty())
+        .unwrap_or_else(|| default.to_string())
+}
+
+fn save_last_category(form: &str, category: &str) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            let _ = storage.set_item(&format!("last_category:{}", form), category);
+        }
+    }
+}
+
+fn clear_last_category(form: &str) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            let _ = storage.remove_item(&format!("last_category:{}", form));
+        }
+    }
+}
+
+/// "Reset to default" link shown beside a category field once it differs
+/// from `default`.
+fn category_reset_button(
+    form: &'static str,
+    default: &'static str,
+    state: &UseStateHandle<String>,
+) -> Html {
+    if state.as_str() == default {
+        return html! {};
+    }
+    let onclick = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            clear_last_category(form);
+            state.set(default.to_string());
+        })
+    };
+    html! {
+        <button type="button" {onclick} class="text-[10px] font-bold text-primary hover:underline">{"Reset to default"}</button>
+    }
+}
+
+fn load_accounts() -> Vec<String> {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(Some(raw)) = storage.get_item("accounts") {
+                if let Ok(items) = serde_json::from_str::<Vec<String>>(&raw) {
+                    if !items.is_empty() {
+                        return items;
+                    }
+                }
+            }
+        }
+    }
+
+    vec![DEFAULT_ACCOUNT.to_string()]
+}
+
+fn save_accounts(items: &[String]) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(raw) = serde_json::to_string(items) {
+                let _ = storage.set_item("accounts", &raw);
+            }
+        }
+    }
+}
+
+/// Currency each account records in, by account name. Accounts missing from
+/// the map use the app currency.
+fn load_account_currencies() -> HashMap<String, String> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item("account_currencies").ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn save_account_currencies(currencies: &HashMap<String, String>) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(raw) = serde_json::to_string(currencies) {
+                let _ = storage.set_item("account_currencies", &raw);
+            }
+        }
+    }
+}
+
+fn account_currency(currencies: &HashMap<String, String>, account: &str, app_code: &str) -> String {
+    currencies
+        .get(account)
+        .cloned()
+        .unwrap_or_else(|| app_code.to_string())
+}
+
+/// The `currency` a new row stores: `None` when it is the app currency, as on
+/// rows saved before currencies existed.
+fn stored_currency(code: &str, app_code: &str) -> Option<String> {
+    (!code.eq_ignore_ascii_case(app_code)).then(|| code.to_string())
+}
+
+/// Sums `(account, amount, code)` balances kept in the app currency. There
+/// are no exchange rates to convert with, so other currencies are left out.
+fn app_currency_total(balances: &[(String, i64, String)], app_code: &str) -> i64 {
+    balances
+        .iter()
+        .filter(|(_, _, code)| stored_currency(code, app_code).is_none())
+        .map(|(_, amount, _)| amount)
+        .sum()
+}
+
+/// `<option>`s for every known currency, keeping `selected` (e.g. a custom
+/// one) even when it isn't in the list.
+fn currency_code_options(selected: &str) -> Html {
+    let missing = !CURRENCIES.iter().any(|(code, _, _)| *code == selected);
+    html! {
+        <>
+            if missing {
+                <option value={selected.to_string()} selected=true>{ selected.to_string() }</option>
+            }
+            { for CURRENCIES.iter().map(|(code, _, _)| html! {
+                <option value={*code} selected={*code == selected}>{ *code }</option>
+            }) }
+        </>
+    }
+}
+
+fn account_name(tx: &Transaction) -> &str {
+    match tx.account.as_deref() {
+        Some(account) if !account.trim().is_empty() => account,
+        _ => DEFAULT_ACCOUNT,
+    }
+}
+
+/// Balance per account: every managed account, plus any account that only
+/// appears on transactions (e.g. one removed from the list later).
+fn account_balances(transactions: &[Transaction], accounts: &[String]) -> Vec<(String, i64)> {
+    let mut balances: Vec<(String, i64)> = accounts.iter().map(|a| (a.clone(), 0)).collect();
+    for tx in transactions {
+        let name = account_name(tx);
+        match balances.iter_mut().find(|(account, _)| account == name) {
+            Some((_, total)) => *total += tx.amount,
+            None => balances.push((name.to_string(), tx.amount)),
+        }
+    }
+    balances
+}
+
+/// Category used for both legs of a transfer between the user's own accounts.
+const TRANSFER_CATEGORY: &str = "Transfer";
+
+/// Transfers move money between accounts, so they are never income or expense.
+fn is_transfer(tx: &Transaction) -> bool {
+    category_key(&tx.category) == category_key(TRANSFER_CATEGORY)
+}
+
+fn category_label(tx: &Transaction) -> String {
+    if is_transfer(tx) {
+        format!("⇄ {}", tx.category)
+    } else {
+        tx.category.clone()
+    }
+}
+
+/// Moves the parts of each split up next to the first of them, so a split
+/// purchase reads as one block; every other row keeps its place.
+fn group_splits(rows: &[Transaction]) -> Vec<Transaction> {
+    let mut placed = vec![false; rows.len()];
+    let mut grouped = Vec::with_capacity(rows.len());
+    for (idx, tx) in rows.iter().enumerate() {
+        if placed[idx] {
+            continue;
+        }
+        grouped.push(tx.clone());
+        let Some(group) = tx.split_group.as_deref() else {
+            continue;
+        };
+        for (later, part) in rows.iter().enumerate().skip(idx + 1) {
+            if !placed[later] && part.split_group.as_deref() == Some(group) {
+                placed[later] = true;
+                grouped.push(part.clone());
+            }
+        }
+    }
+    grouped
+}
+
+/// Flips the review flag on a saved row and returns the stored row.
+async fn set_flagged(tx: &Transaction, flagged: bool) -> Option<Transaction> {
+    put_transaction(&Transaction {
+        flagged,
+        ..tx.clone()
+    })
+    .await
+}
+
+/// Small marker shown next to the description of a row flagged for review.
+fn flag_badge(tx: &Transaction) -> Html {
+    if tx.flagged {
+        html! { <span class="ml-2 text-[10px] font-bold text-amber-700" title="Flagged for review">{"⚑ Review"}</span> }
+    } else {
+        html! {}
+    }
+}
+
+/// Small marker shown next to the category of a split part.
+fn split_badge(tx: &Transaction) -> Html {
+    if tx.split_group.is_some() {
+        html! { <span class="ml-2 text-[10px] font-bold text-primary" title="Part of a split transaction">{"⑂ Split"}</span> }
+    } else {
+        html! {}
+    }
+}
+
+fn account_options(accounts: &[String], selected: &str) -> Html {
+    let missing = !accounts.iter().any(|a| a == selected);
+    html! {
+        <>
+            { for accounts.iter().map(|account| html! {
+                <option value={account.clone()} selected={account == selected}>{ account.clone() }</option>
+            }) }
+            if missing {
+                <option value={selected.to_string()} selected=true>{ selected.to_string() }</option>
+            }
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ExpensePageProps {
+    /// Opens the Dashboard add form pre-filled from a row.
+    on_duplicate: Callback<Transaction>,
+}
+
+#[function_component(ExpensePage)]
+fn expense_page(props: &ExpensePageProps) -> Html {
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    let currency = use_currency_format();
+    let budget_alerts = settings.as_ref().is_none_or(|s| s.budget_alerts);
+    let savings_as_spending = settings.as_ref().is_none_or(|s| s.savings_as_spending);
+    let round_up_increment = round_up_setting(settings.as_ref());
+    let known_categories = use_context::<KnownCategories>();
+    let expenses = use_state(Vec::<Transaction>::new);
+    let loading = use_state(|| true);
+    let scope = use_request_scope();
+    let load_error = use_state(|| None::<ApiError>);
+    let load_attempt = use_state(|| 0u32);
+    let refreshed_at = use_state(|| None::<f64>);
+    let selected = use_state(HashSet::<i32>::new);
+    let amount_ref = use_node_ref();
+    let deleting = use_state(|| false);
+
+    // date inputs default to, and are capped at, today
+    let today = today_key();
+    let form_date = use_state(today_key);
+    let form_amount = use_state(|| "".to_string());
+    let form_category = use_state(|| load_last_category("expense", DEFAULT_EXPENSE_CATEGORY));
+    let form_description = use_state(|| "".to_string());
+    let form_note = use_state(|| "".to_string());
+    let accounts = use_state(load_accounts);
+    let form_account = {
+        let first = accounts.first().cloned().unwrap_or_default();
+        use_state(move || first)
+    };
+    let form_tax = use_state(|| false);
+    let form_error = use_state(|| None::<String>);
+    let saving = use_state(|| false);
+
+    {
+        let expenses = expenses.clone();
+        let loading = loading.clone();
+        let load_error = load_error.clone();
+        let refreshed_at = refreshed_at.clone();
+        use_effect_with_deps(
+            move |_| {
+                load_error.set(None);
+                loading.set(true);
+                spawn_local(async move {
+                    match fetch_transactions(Some(&scope)).await {
+                        Ok(list) => {
+                            let filtered = list
+                                .into_iter()
+                                .filter(|t| t.amount < 0 && !is_transfer(t))
+                                .collect::<Vec<_>>();
+                            expenses.set(filtered);
+                            refreshed_at.set(Some(js_sys::Date::now()));
+                        }
+                        Err(ApiError::Cancelled) => return,
+                        Err(err) => load_error.set(Some(err)),
+                    }
+                    loading.set(false);
+                });
+                || ()
+            },
+            *load_attempt,
+        );
+    }
+
+    let total_expense: i64 = expenses
+        .iter()
+        .filter(|item| is_spending(item, savings_as_spending))
+        .map(|item| item.amount.abs())
+        .sum();
+    // shown beside the total when savings are left out of it
+    let moved_to_savings: i64 = expenses
+        .iter()
+        .filter(|item| is_savings(item))
+        .map(|item| item.amount.abs())
+        .sum();
+    let on_delete_selected = bulk_delete_callback(&expenses, &selected, &deleting);
+    let confirm_bulk_delete = use_state(|| false);
+    let duplicate_pending = use_state(|| false);
+
+    let on_add = {
+        let expenses = expenses.clone();
+        let form_date = form_date.clone();
+        let form_amount = form_amount.clone();
+        let form_category = form_category.clone();
+        let form_description = form_description.clone();
+        let form_note = form_note.clone();
+        let form_account = form_account.clone();
+        let form_tax = form_tax.clone();
+        let form_error = form_error.clone();
+        let saving = saving.clone();
+        let duplicate_pending = duplicate_pending.clone();
+        let known_categories = known_categories.clone();
+        let currency = currency.clone();
+        Callback::from(move |allow_duplicate: bool| {
+            let date_val = form_date.trim().to_string();
+            let desc_val = form_description.trim().to_string();
+            let cat_val = normalize_category(&form_category);
+            let amt_val = form_amount.trim().to_string();
+            let note_val = optional_text(&form_note);
+            let account_val = (*form_account).clone();
+            let currency_val = stored_currency(
+                &account_currency(&load_account_currencies(), &account_val, &currency.code),
+                &currency.code,
+            );
+            let tax_val = *form_tax;
+
+            if date_val.is_empty()
+                || desc_val.is_empty()
+                || cat_val.is_empty()
+                || amt_val.is_empty()
+            {
+                form_error.set(Some("Please complete all fields.".to_string()));
+                return;
+            }
+
+            let parsed =
+                match parse_entered_amount(&amt_val, &currency.for_code(currency_val.as_deref())) {
+                    Ok(amount) if amount > 0 => amount,
+                    Ok(_) => {
+                        form_error.set(Some("Amount must be a positive number.".to_string()));
+                        return;
+                    }
+                    Err(msg) => {
+                        form_error.set(Some(msg));
+                        return;
+                    }
+                };
+            if !allow_duplicate && is_possible_duplicate(&expenses, &date_val, &desc_val, -parsed) {
+                duplicate_pending.set(true);
+                return;
+            }
+
+            form_error.set(None);
+            saving.set(true);
+
+            let expenses = expenses.clone();
+            let form_date = form_date.clone();
+            let form_amount = form_amount.clone();
+            let form_description = form_description.clone();
+            let form_note = form_note.clone();
+            let form_tax = form_tax.clone();
+            let saving = saving.clone();
+            let known_categories = known_categories.clone();
+            let app_code = currency.code.clone();
+            spawn_local(async move {
+                let url = format!("{}/api/transactions", API_BASE_URL);
+                let payload = serde_json::json!({
+                    "date": date_val.as_str(),
+                    "description": desc_val.as_str(),
+                    "category": cat_val.as_str(),
+                    "amount": -parsed,
+                    "note": note_val.as_deref(),
+                    "account": account_val.as_str(),
+                    "tax": tax_val,
+                    "currency": currency_val.as_deref()
+                });
+
+                invalidate_dashboard_cache();
+                let request = api_request(Request::post(&url)).json(&payload);
+
+                if let Ok(resp) = send_api(request, None).await {
+                    if resp.ok() {
+                        if let Ok(created) = resp.json::<Transaction>().await {
+                            if let Some(known) = &known_categories {
+                                known.register(&created.category);
+                            }
+                            alert_if_over_budget(
+                                &expenses,
+                                &created,
+                                budget_alerts,
+                                savings_as_spending,
+                            );
+                            let round_up =
+                                save_round_up(&created, round_up_increment, &app_code).await;
+                            let mut next = (*expenses).clone();
+                            next.insert(0, created);
+                            if let Some(saved) = round_up.and_then(|r| r.transaction) {
+                                next.insert(0, saved);
+                            }
+                            expenses.set(next);
+                            form_date.set(today_key());
+                            form_amount.set("".to_string());
+                            save_last_category("expense", &cat_val);
+                            form_description.set("".to_string());
+                            form_note.set("".to_string());
+                            form_tax.set(false);
+                        }
+                    }
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let on_clear = {
+        let form_amount = form_amount.clone();
+        let form_description = form_description.clone();
+        let form_date = form_date.clone();
+        let form_note = form_note.clone();
+        let form_tax = form_tax.clone();
+        Callback::from(move |_| {
+            form_date.set(today_key());
+            form_amount.set("".to_string());
+            form_description.set("".to_string());
+            form_note.set("".to_string());
+            form_tax.set(false);
+        })
+    };
+
+    html! {
+        { page_shell(
+            "Expense Tracker",
+            html! {
+                <RefreshStatus refreshed_at={*refreshed_at} refreshing={*loading} on_refresh={{
+                    let load_attempt = load_attempt.clone();
+                    Callback::from(move |_| load_attempt.set(*load_attempt + 1))
+                }} />
+            },
+            html! {
+                <>
+                    { load_error_banner(*load_error, &load_attempt) }
+                    <div class="grid grid-cols-1 lg:grid-cols-12 gap-6 items-stretch">
+                        <div class="lg:col-span-4 bg-card p-5 rounded-[10px] shadow-sm border border-border flex flex-col justify-center">
+                            <div class="flex items-center gap-2 mb-1">
+                                <div class="p-1.5 bg-muted rounded-lg">{ icon_credit_card() }</div>
+                                <span class="text-muted-foreground text-[10px] font-bold mb-1 tracking-widest">{"Total Expenses"}</span>
+                            </div>
+                            <h3 class="text-2xl font-bold text-primary tracking-tight"><CurrencyAmount amount={total_expense} currency={currency.clone()} /></h3>
+                            if !savings_as_spending && moved_to_savings > 0 {
+                                <p class="text-xs text-muted-foreground mt-1">
+                                    {"Plus "}<CurrencyAmount amount={moved_to_savings} currency={currency.clone()} />{" moved to savings, not counted"}
+                                </p>
+                            }
+                        </div>
+
+                        <div class="lg:col-span-8 bg-card p-5 rounded-[10px] shadow-sm border border-border">
+                            <h4 class="text-primary font-bold text-[15px] mb-3 tracking-wider">{"Add New Expense"}</h4>
+                            <div class="grid grid-cols-1 sm:grid-cols-2 md:grid-cols-4 gap-3 mb-4">
+                                <div class="space-y-1">
+                                    <label class="text-[12px] font-bold text-muted-foreground">{"Date"}</label>
+                                    <input type="date" max={today.clone()} value={(*form_date).clone()} oninput={{
+                                        let form_date = form_date.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                            form_date.set(input.value());
+                                        })
+                                    }} class="w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                                </div>
+                                <div class="space-y-1">
+                                    <label class="text-[12px] font-bold text-muted-foreground">{ format!("Amount ({})", currency.symbol) }</label>
+                                    <input ref={amount_ref.clone()} type="text" inputmode="decimal" pattern={amount_pattern('.', currency.entry_places())} placeholder={currency.entry_placeholder(format_currency(0, &currency))} value={(*form_amount).clone()} oninput={amount_oninput(&form_amount, '.', currency.entry_places())} class="w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                                    { entry_preview(&form_amount, &currency) }
+                                </div>
+                                <div class="space-y-1">
+                                    <label class="text-[12px] font-bold text-muted-foreground">{"Description"}</label>
+                                    <input type="text" placeholder="Expense description" value={(*form_description).clone()} oninput={{
+                                        let form_description = form_description.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                            form_description.set(input.value());
+                                        })
+                                    }} class="w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                                </div>
+                                <div class="space-y-1">
+                                    <div class="flex items-center justify-between">
+                                        <label class="text-[12px] font-bold text-muted-foreground">{"Category"}</label>
+                                        { category_reset_button("expense", DEFAULT_EXPENSE_CATEGORY, &form_category) }
+                                    </div>
+                                    <input type="text" placeholder="Category" list={CATEGORY_LIST_ID} value={(*form_category).clone()} oninput={{
+                                        let form_category = form_category.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                            form_category.set(input.value());
+                                        })
+                                    }} class="w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                                </div>
+                            </div>
+                            <div class="grid grid-cols-1 md:grid-cols-3 gap-3 mb-4">
+                                <select onchange={{
+                                    let form_account = form_account.clone();
+                                    Callback::from(move |e: Event| {
+                                        let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                        form_account.set(input.value());
+                                    })
+                                }} aria-label="Account" class="w-full bg-muted border-2 border-transparent rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] focus:ring-2 focus:ring-ring outline-none">
+                                    { account_options(&accounts, &form_account) }
+                                </select>
+                                <input type="text" placeholder="Note (optional)" value={(*form_note).clone()} oninput={{
+                                    let form_note = form_note.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        form_note.set(input.value());
+                                    })
+                                }} class="md:col-span-2 w-full bg-muted rounded-[10px] px-3 py-3 md:py-2 text-sm md:text-[11px] text-primary border-none" />
+                                { tax_checkbox(&form_tax) }
+                            </div>
+                            <div class="flex gap-3">
+                                <button onclick={on_add.reform(|_| false)} class="flex-1 bg-primary text-primary-foreground py-3 md:py-2 rounded-[10px] text-xs md:text-[10px] font-bold flex items-center justify-center gap-2" disabled={*saving}>{ if *saving { "Saving..." } else { "Add Expense" } }</button>
+                                <button onclick={on_clear} class="flex-1 bg-secondary text-primary py-3 md:py-2 rounded-[10px] text-xs md:text-[10px] font-bold flex items-center justify-center gap-2">{"Clear"}</button>
+                            </div>
+                            {
+                                if let Some(msg) = &*form_error {
+                                    html! { <p class="text-sm text-red-500 mt-3">{ msg.clone() }</p> }
+                                } else { html!{} }
+                            }
+                        </div>
+                    </div>
+                    <div class="bg-card rounded-2xl shadow-md border border-border overflow-hidden">
+                        <div class="p-5 border-b border-border flex items-center justify-between">
+                            <h3 class="font-bold text-lg text-foreground">{"Expenses History"}</h3>
+                            { bulk_actions_bar(selected.len(), *deleting, &confirm_bulk_delete, &on_delete_selected) }
+                            { duplicate_confirm(&duplicate_pending, &on_add) }
+                        </div>
+                        <div class="overflow-auto max-h-[70vh]">
+                            <table class="w-full min-w-[640px] text-left border-collapse">
+                                <caption class="sr-only">{"Expense history"}</caption>
+                                <thead class="sticky top-0 z-10">
+                                    <tr class="bg-muted text-muted-foreground text-[10px] uppercase tracking-widest">
+                                        <th scope="col" class="pl-8 py-4 w-8">{ select_all_checkbox(&selected, &expenses) }</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Date"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Description"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Category"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Amount"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Action"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody class="divide-y divide-border">
+                                    { if *loading {
+                                        html! { <tr><td colspan="6" class="px-8 py-6 text-center text-muted-foreground">{"Loading..."}</td></tr> }
+                                    } else if expenses.is_empty() {
+                                        html! { <tr><td colspan="6" class="px-8 py-2"><EmptyState icon={icon_credit_card()} message="No expense transactions yet." action_label="Add expense" on_action={focus_input_callback(&amount_ref)} /></td></tr> }
+                                    } else {
+                                        html! {
+                                            <>
+                                                { for group_splits(&expenses).iter().enumerate().map(|(idx, item)| html! {
+                                                    <tr key={transaction_key(item, idx)} class={classes!("text-sm", "hover:bg-muted/40", "transition-colors", "group", item.split_group.is_some().then_some("border-l-2 border-l-secondary"))}>
+                                                        <td class="pl-8 py-4">{ row_checkbox(&selected, item) }</td>
+                                                        <td class="px-8 py-4 text-muted-foreground">{ item.date.clone() }</td>
+                                                        <td class="px-8 py-4 text-foreground">{ item.description.clone() }{ note_details(item) }</td>
+                                                        <td class="px-8 py-4">
+                                                            if is_savings(item) {
+                                                                <span class="bg-muted text-primary border border-secondary px-3 py-1 rounded-full text-[10px] font-bold" title="Money moved to your saving goal">{ format!("⇢ {}", item.category) }</span>
+                                                            } else {
+                                                                <span class="bg-secondary text-secondary-foreground px-3 py-1 rounded-full text-[10px] font-bold">{ item.category.clone() }</span>
+                                                            }
+                                                            { split_badge(item) }
+                                                        </td>
+                                                        <td class="px-8 py-4 font-semibold text-foreground">{ amount_cell(item, &currency, false) }</td>
+                                                        <td class="px-8 py-4">{ duplicate_button(&props.on_duplicate, item) }</td>
+                                                    </tr>
+                                                }) }
+                                            </>
+                                        }
+                                    }}
+                                </tbody>
+                            </table>
+                        </div>
+                    </div>
+                </>
+            }
+        ) }
+    }
+}
+
+/// How long the "will retry" notice stays up after a contribution's
+/// transaction fails to post.
+const CONTRIBUTION_NOTICE_MS: u32 = 5_000;
+
+#[function_component(SavingsPage)]
+fn savings_page() -> Html {
+    let is_creating = use_state(|| false);
+    let confirm_remove = use_state(|| false);
+    let currency = use_currency_format();
+
+    let goal = use_state(load_saving_goal);
+    let goal_is_empty = goal.title.trim().is_empty() && goal.target_amount == 0;
+    let rounding = use_context::<UseStateHandle<AppSettings>>()
+        .map(|s| s.rounding_mode)
+        .unwrap_or_default();
+
+    let on_start_goal = {
+        let is_creating = is_creating.clone();
+        Callback::from(move |_| is_creating.set(true))
+    };
+    let today = today_key();
+    let contrib_date = use_state(today_key);
+    let contrib_amount = use_state(|| "".to_string());
+    let contrib_desc = use_state(|| "".to_string());
+    let new_goal_title = use_state(|| "".to_string());
+    let new_goal_amount = use_state(|| "".to_string());
+    let new_goal_date = use_state(|| "".to_string());
+    let new_goal_cadence = use_state(|| SavingCadence::None);
+    // the new-goal form opens prefilled from the current goal, so only
+    // changes to those values count as unsaved
+    use_unsaved_form(
+        "new-goal",
+        *is_creating
+            && (*new_goal_title != goal.title
+                || *new_goal_amount != currency.entry_text(goal.target_amount)
+                || *new_goal_date != goal.target_date),
+    );
+    use_unsaved_form(
+        "contribution",
+        !contrib_amount.trim().is_empty() || !contrib_desc.trim().is_empty(),
+    );
+    // in-place edit of the current goal; contributions are left alone
+    let editing_goal = use_state(|| false);
+    let edit_title = use_state(|| "".to_string());
+    let edit_amount = use_state(|| "".to_string());
+    let edit_date = use_state(|| "".to_string());
+    let edit_error = use_state(|| None::<String>);
+
+    // compare local contributions with the backend once per load, and again
+    // after each fix
+    let scope = use_request_scope();
+    let savings_check = use_state(|| None::<SavingsCheck>);
+    let goal_totals = use_state(Vec::<(Option<String>, i64)>::new);
+    let check_attempt = use_state(|| 0u32);
+    let fixing = use_state(|| false);
+    {
+        let savings_check = savings_check.clone();
+        let goal_totals = goal_totals.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    // on failure there is nothing to compare against, so no report
+                    if let Ok(list) = fetch_transactions(Some(&scope)).await {
+                        savings_check.set(Some(check_savings(&load_saving_goal(), &list)));
+                        goal_totals.set(savings_by_goal(&list));
+                    }
+                });
+                || ()
+            },
+            *check_attempt,
+        );
+    }
+
+    let refresh_summary = use_summary().refresh.clone();
+    let on_repost_missing = {
+        let savings_check = savings_check.clone();
+        let check_attempt = check_attempt.clone();
+        let fixing = fixing.clone();
+        let refresh_summary = refresh_summary.clone();
+        Callback::from(move |_| {
+            let Some(check) = (*savings_check).clone() else {
+                return;
+            };
+            let check_attempt = check_attempt.clone();
+            let fixing = fixing.clone();
+            let refresh_summary = refresh_summary.clone();
+            fixing.set(true);
+            spawn_local(async move {
+                let title = load_saving_goal().title;
+                for entry in check.missing {
+                    let payload =
+                        savings_payload(&entry.date, &title, &entry.description, entry.amount);
+                    let _ = post_transaction(&payload).await;
+                }
+                refresh_summary.emit(());
+                fixing.set(false);
+                check_attempt.set(*check_attempt + 1);
+            });
+        })
+    };
+
+    let on_remove_missing = {
+        let goal = goal.clone();
+        let savings_check = savings_check.clone();
+        let check_attempt = check_attempt.clone();
+        Callback::from(move |_| {
+            let Some(check) = &*savings_check else {
+                return;
+            };
+            // by value rather than position, since contributions added after
+            // the check shift the list
+            let mut next = load_saving_goal();
+            for entry in &check.missing {
+                if let Some(pos) = next.contributions.iter().position(|c| c == entry) {
+                    next.contributions.remove(pos);
+                }
+            }
+            save_saving_goal(&next);
+            goal.set(next);
+            check_attempt.set(*check_attempt + 1);
+        })
+    };
+
+    let on_track_untracked = {
+        let goal = goal.clone();
+        let savings_check = savings_check.clone();
+        let check_attempt = check_attempt.clone();
+        Callback::from(move |_| {
+            let Some(check) = &*savings_check else {
+                return;
+            };
+            let mut next = load_saving_goal();
+            let goal_id = next.id.clone();
+            next.contributions
+                .extend(check.untracked.iter().map(|tx| Contribution {
+                    date: tx.date.get(0..10).unwrap_or(&tx.date).to_string(),
+                    description: tx.description.clone(),
+                    amount: -tx.amount,
+                    goal_id: goal_id.clone(),
+                    round_up: false,
+                }));
+            // newest first, like contributions added by hand
+            next.contributions.sort_by(|a, b| b.date.cmp(&a.date));
+            save_saving_goal(&next);
+            goal.set(next);
+            check_attempt.set(*check_attempt + 1);
+        })
+    };
+
+    let saved_so_far: i64 = goal.contributions.iter().map(|c| c.amount).sum();
+    let saved_from_round_ups: i64 = goal
+        .contributions
+        .iter()
+        .filter(|c| c.round_up)
+        .map(|c| c.amount)
+        .sum();
+    use_publish_nav_status(None, Some(goal_completed(&goal)));
+    let progress = if goal.target_amount > 0 {
+        saved_so_far as f64 / goal.target_amount as f64
+    } else {
+        0.0
+    };
+    let radius = 38.0;
+    let (circumference, offset) = ring_dash(radius, progress);
+
+    let toggle_create = {
+        let is_creating = is_creating.clone();
+        let goal = goal.clone();
+        let new_goal_title = new_goal_title.clone();
+        let new_goal_amount = new_goal_amount.clone();
+        let new_goal_date = new_goal_date.clone();
+        let new_goal_cadence = new_goal_cadence.clone();
+        let editing_goal = editing_goal.clone();
+        let currency = currency.clone();
+        Callback::from(move |_| {
+            editing_goal.set(false);
+            if !*is_creating {
+                new_goal_title.set(goal.title.clone());
+                new_goal_amount.set(currency.entry_text(goal.target_amount));
+                new_goal_date.set(goal.target_date.clone());
+                new_goal_cadence.set(goal.cadence);
+            }
+            is_creating.set(!*is_creating)
+        })
+    };
+
+    let post_failed = use_state(|| false);
+    let post_failed_timer = use_mut_ref(|| None::<Timeout>);
+    let add_contribution = {
+        let goal = goal.clone();
+        let contrib_date = contrib_date.clone();
+        let contrib_amount = contrib_amount.clone();
+        let contrib_desc = contrib_desc.clone();
+        let refresh_summary = refresh_summary.clone();
+        let post_failed = post_failed.clone();
+        let post_failed_timer = post_failed_timer.clone();
+        let currency = currency.clone();
+        Callback::from(move |_| {
+            let parsed = parse_entered_amount(&contrib_amount, &currency).unwrap_or(0);
+            if parsed <= 0 {
+                return;
+            }
+            let entry = Contribution {
+                date: contrib_date.to_string(),
+                description: if contrib_desc.is_empty() {
+                    "Contribution".into()
+                } else {
+                    contrib_desc.to_string()
+                },
+                amount: parsed,
+                goal_id: "".to_string(),
+                round_up: false,
+            };
+            let desc_val = if contrib_desc.is_empty() {
+                "Savings".to_string()
+            } else {
+                contrib_desc.to_string()
+            };
+            let payload = savings_payload(&entry.date, &goal.title, &desc_val, parsed);
+            goal.set(add_goal_contribution(entry));
+            contrib_amount.set("".into());
+            contrib_desc.set("".into());
+
+            // Also create a transaction so savings are reflected in totals;
+            // a failed POST is queued and retried rather than dropped
+            let refresh_summary = refresh_summary.clone();
+            let post_failed = post_failed.clone();
+            let post_failed_timer = post_failed_timer.clone();
+            spawn_local(async move {
+                if post_transaction(&payload).await.is_some() {
+                    refresh_summary.emit(());
+                    return;
+                }
+                queue_savings_post(payload);
+                post_failed.set(true);
+                let post_failed = post_failed.clone();
+                *post_failed_timer.borrow_mut() =
+                    Some(Timeout::new(CONTRIBUTION_NOTICE_MS, move || {
+                        post_failed.set(false)
+                    }));
+            });
+        })
+    };
+
+    let clear_contribution = {
+        let contrib_amount = contrib_amount.clone();
+        let contrib_desc = contrib_desc.clone();
+        Callback::from(move |_| {
+            contrib_amount.set("".to_string());
+            contrib_desc.set("".to_string());
+        })
+    };
+
+    let remove_goal = {
+        let goal = goal.clone();
+        let new_goal_title = new_goal_title.clone();
+        let new_goal_amount = new_goal_amount.clone();
+        let new_goal_date = new_goal_date.clone();
+        let is_creating = is_creating.clone();
+        let confirm_remove = confirm_remove.clone();
+        Callback::from(move |_| {
+            confirm_remove.set(false);
+            let cleared = SavingGoalState {
+                id: "".to_string(),
+                title: "".to_string(),
+                target_amount: 0,
+                target_date: "".to_string(),
+                contributions: vec![],
+                cadence: SavingCadence::None,
+            };
+            save_saving_goal(&cleared);
+            goal.set(cleared);
+            new_goal_title.set("".to_string());
+            new_goal_amount.set("".to_string());
+            new_goal_date.set("".to_string());
+            is_creating.set(false);
+        })
+    };
+
+    let on_edit_goal = {
+        let goal = goal.clone();
+        let editing_goal = editing_goal.clone();
+        let edit_title = edit_title.clone();
+        let edit_amount = edit_amount.clone();
+        let edit_date = edit_date.clone();
+        let edit_error = edit_error.clone();
+        let currency = currency.clone();
+        Callback::from(move |_| {
+            edit_title.set(goal.title.clone());
+            edit_amount.set(currency.entry_text(goal.target_amount));
+            edit_date.set(goal.target_date.clone());
+            edit_error.set(None);
+            editing_goal.set(true);
+        })
+    };
+
+    let on_save_goal_edit = {
+        let goal = goal.clone();
+        let editing_goal = editing_goal.clone();
+        let edit_title = edit_title.clone();
+        let edit_amount = edit_amount.clone();
+        let edit_date = edit_date.clone();
+        let edit_error = edit_error.clone();
+        let currency = currency.clone();
+        Callback::from(move |_| {
+            let title = edit_title.trim().to_string();
+            if title.is_empty() {
+                edit_error.set(Some("Give the goal a name.".to_string()));
+                return;
+            }
+            let target_amount = match parse_entered_amount(&edit_amount, &currency) {
+                Ok(amount) if amount > 0 => amount,
+                Ok(_) => {
+                    edit_error.set(Some("Target must be a positive number.".to_string()));
+                    return;
+                }
+                Err(msg) => {
+                    edit_error.set(Some(msg));
+                    return;
+                }
+            };
+            let next_goal = SavingGoalState {
+                title,
+                target_amount,
+                target_date: edit_date.to_string(),
+                ..(*goal).clone()
+            };
+            save_saving_goal(&next_goal);
+            goal.set(next_goal);
+            editing_goal.set(false);
+        })
+    };
+
+    let create_goal = {
+        let goal = goal.clone();
+        let new_goal_title = new_goal_title.clone();
+        let new_goal_amount = new_goal_amount.clone();
+        let new_goal_date = new_goal_date.clone();
+        let new_goal_cadence = new_goal_cadence.clone();
+        let is_creating = is_creating.clone();
+        let currency = currency.clone();
+        Callback::from(move |_| {
+            if new_goal_title.is_empty() || new_goal_amount.is_empty() {
+                return;
+            }
+            let next_goal = SavingGoalState {
+                id: new_goal_id(),
+                title: new_goal_title.to_string(),
+                target_amount: parse_entered_amount(&new_goal_amount, &currency).unwrap_or(0),
+                target_date: new_goal_date.to_string(),
+                contributions: vec![],
+                cadence: *new_goal_cadence,
+            };
+            save_saving_goal(&next_goal);
+            goal.set(next_goal);
+            is_creating.set(false);
+        })
+    };
+
+    html! {
+        { page_shell(
+            "Saving Goal",
+            html! {
+                <div class="flex items-center gap-2">
+                    <button onclick={toggle_create} class="bg-primary text-primary-foreground px-4 py-2 rounded-[10px] text-xs font-bold uppercase flex items-center gap-1 shadow-md hover:opacity-90 transition-all">
+                        { if *is_creating { "Cancel" } else { "New Goal" } }
+                    </button>
+                    <button onclick={{
+                        let confirm_remove = confirm_remove.clone();
+                        Callback::from(move |_| confirm_remove.set(true))
+                    }} class="bg-red-600 text-white px-4 py-2 rounded-[10px] text-xs font-bold uppercase shadow-md hover:opacity-90 transition-all">
+                        {"Remove Goal"}
+                    </button>
+                    if *confirm_remove {
+                        <ConfirmModal
+                            title="Remove goal"
+                            message="Remove this saving goal and its contribution history?"
+                            confirm_label="Remove"
+                            destructive=true
+                            on_confirm={remove_goal}
+                            on_cancel={{
+                                let confirm_remove = confirm_remove.clone();
+                                Callback::from(move |_| confirm_remove.set(false))
+                            }}
+                        />
+                    }
+                </div>
+            },
+            html! {
+                <>
+                    <div class="grid grid-cols-1 lg:grid-cols-12 gap-6 items-stretch">
+                <div class="lg:col-span-5 bg-card p-6 rounded-[10px] shadow-md border border-border flex flex-col h-full">
+                    { if !*is_creating && goal_is_empty {
+                        html! {
+                            <div class="flex flex-1 items-center justify-center">
+                                <EmptyState icon={icon_target()} message="No saving goal yet." action_label="Create a goal" on_action={on_start_goal} />
+                            </div>
+                        }
+                    } else if !*is_creating {
+                        html! {
+                            <div class="flex flex-col h-full">
+                                if *editing_goal {
+                                    <div class="space-y-3 mb-6">
+                                        <div class="space-y-1">
+                                            <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Goal Name"}</label>
+                                            <input type="text" value={(*edit_title).clone()} oninput={{
+                                                let edit_title = edit_title.clone();
+                                                Callback::from(move |e: InputEvent| {
+                                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                                    edit_title.set(input.value());
+                                                })
+                                            }} class="w-full bg-muted border-none rounded-xl p-2.5 text-xs font-bold text-primary outline-none" />
+                                        </div>
+                                        <div class="grid grid-cols-2 gap-3">
+                                            <div class="space-y-1">
+                                                <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Target Amount"}</label>
+                                                <input type="text" inputmode="decimal" pattern={amount_pattern('.', currency.entry_places())} placeholder={currency.entry_placeholder(String::new())} value={(*edit_amount).clone()} oninput={amount_oninput(&edit_amount, '.', currency.entry_places())} class="w-full bg-muted border-none rounded-xl p-2.5 text-xs font-bold text-primary outline-none" />
+                                                { entry_preview(&edit_amount, &currency) }
+                                            </div>
+                                            <div class="space-y-1">
+                                                <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Target Date"}</label>
+                                                <input type="date" value={(*edit_date).clone()} oninput={{
+                                                    let edit_date = edit_date.clone();
+                                                    Callback::from(move |e: InputEvent| {
+                                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                                        edit_date.set(input.value());
+                                                    })
+                                                }} class="w-full bg-muted border-none rounded-xl p-2.5 text-xs font-bold text-primary outline-none" />
+                                            </div>
+                                        </div>
+                                        if let Some(msg) = &*edit_error {
+                                            <p class="text-xs text-red-500" role="alert">{ msg.clone() }</p>
+                                        }
+                                        <div class="flex gap-2">
+                                            <button type="button" onclick={on_save_goal_edit} class="flex-1 bg-primary text-primary-foreground py-2 rounded-[10px] text-[10px] font-bold">{"Save"}</button>
+                                            <button type="button" onclick={{
+                                                let editing_goal = editing_goal.clone();
+                                                Callback::from(move |_| editing_goal.set(false))
+                                            }} class="flex-1 bg-muted text-primary py-2 rounded-[10px] text-[10px] font-bold">{"Cancel"}</button>
+                                        </div>
+                                    </div>
+                                } else {
+                                    <div class="flex justify-between items-start mb-6">
+                                        <div class="space-y-1">
+                                            <div class="flex items-center gap-2">
+                                                <div class="flex items-center gap-2 px-2 py-0.5 bg-secondary w-fit rounded-full">
+                                                    <span class="text-[9px] font-black text-primary uppercase tracking-wider">{"Current Goal"}</span>
+                                                </div>
+                                                <button type="button" onclick={on_edit_goal} aria-label="Edit goal" class="text-[10px] font-bold text-primary hover:underline">{"Edit"}</button>
+                                            </div>
+                                            <h3 class="text-xl font-black text-primary tracking-tight">{ goal.title.clone() }</h3>
+                                        </div>
+                                        <div class="text-right">
+                                            <p class="text-[9px] font-bold text-slate-400 uppercase">{"Target Date"}</p>
+                                            <div class="flex items-center gap-1 text-primary font-bold text-xs">{ if goal.target_date.is_empty() { "No Date" } else { goal.target_date.as_str() } }</div>
+                                        </div>
+                                    </div>
+                                }
+
+                                <div class="flex flex-1 items-center justify-around gap-4 bg-slate-50/50 rounded-2xl p-4 border border-slate-100/50">
+                                    <div class="relative flex items-center justify-center shrink-0">
+                                        <svg class="w-24 h-24 transform -rotate-90">
+                                            <circle cx="48" cy="48" r={radius.to_string()} stroke="#e2e8f0" stroke-width="8" fill="transparent" />
+                                            <circle cx="48" cy="48" r={radius.to_string()} stroke="#173E63" stroke-width="8" fill="transparent" stroke-dasharray={circumference.to_string()} stroke-dashoffset={offset.to_string()} stroke-linecap="round" />
+                                        </svg>
+                                        <div class="absolute inset-0 flex flex-col items-center justify-center">
+                                            <span class="text-lg font-black text-primary">{ format!("{}%", (progress * 100.0).round() as i32) }</span>
+                                            <span class="text-[7px] text-slate-400 font-bold uppercase tracking-tighter">{"Progress"}</span>
+                                        </div>
+                                    </div>
+                                    <div class="space-y-3">
+                                        <div>
+                                            <p class="text-[12px] font-bold text-slate-400 mb-0.5 tracking-widest">{"Amount Saved"}</p>
+                                            <p class="text-lg font-black text-primary leading-none"><CurrencyAmount amount={saved_so_far} currency={currency.clone()} /></p>
+                                        </div>
+                                        <div>
+                                            <p class="text-[10px] font-bold text-slate-400 mb-0.5 tracking-widest">{"Goal Target"}</p>
+                                            <p class="text-sm font-black text-primary/70 leading-none"><CurrencyAmount amount={goal.target_amount} currency={currency.clone()} /></p>
+                                        </div>
+                                        if saved_from_round_ups > 0 {
+                                            <div>
+                                                <p class="text-[10px] font-bold text-slate-400 mb-0.5 tracking-widest">{"From Round-ups"}</p>
+                                                <p class="text-sm font-black text-primary leading-none"><CurrencyAmount amount={saved_from_round_ups} currency={currency.clone()} /></p>
+                                            </div>
+                                        }
+                                    </div>
+                                </div>
+                                {
+                                    if goal.target_amount > 0 && saved_so_far >= goal.target_amount {
+                                        html! {
+                                            <div class="mt-4 p-3 rounded-lg bg-green-50 border border-green-200 text-green-700 text-xs font-bold">
+                                                {"Goal reached! You can set a new goal or keep saving."}
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div class="flex flex-col h-full space-y-3">
+                                <h4 class="text-primary font-black text-[13px] mb-2 uppercase tracking-wide border-b border-slate-50 pb-2 text-center">{"Setup New Goal"}</h4>
+                                <div class="flex-grow space-y-3">
+                                    <div class="space-y-1">
+                                        <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Goal Name"}</label>
+                                        <input type="text" value={(*new_goal_title).clone()} oninput={{
+                                            let new_goal_title = new_goal_title.clone();
+                                            Callback::from(move |e: InputEvent| {
+                                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                                new_goal_title.set(input.value());
+                                            })
+                                        }} class="w-full bg-muted border-none rounded-xl p-2.5 text-xs font-bold text-primary outline-none" placeholder="e.g. Dream Wedding" />
+                                    </div>
+                                    <div class="grid grid-cols-2 gap-3">
+                                        <div class="space-y-1">
+                                            <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{ format!("Amount ({})", currency.symbol) }</label>
+                                            <input type="text" inputmode="decimal" pattern={amount_pattern('.', currency.entry_places())} value={(*new_goal_amount).clone()} oninput={amount_oninput(&new_goal_amount, '.', currency.entry_places())} class="w-full bg-muted border-none rounded-xl p-2.5 text-xs font-bold text-primary outline-none" placeholder={currency.entry_placeholder("0.00".to_string())} />
+                                            { entry_preview(&new_goal_amount, &currency) }
+                                        </div>
+                                        <div class="space-y-1">
+                                            <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Target Date"}</label>
+                                            <input type="date" value={(*new_goal_date).clone()} oninput={{
+                                                let new_goal_date = new_goal_date.clone();
+                                                Callback::from(move |e: InputEvent| {
+                                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                                    new_goal_date.set(input.value());
+                                                })
+                                            }} class="w-full bg-muted border-none rounded-xl p-2.5 text-xs font-bold text-primary outline-none" />
+                                        </div>
+                                    </div>
+                                    <div class="space-y-1">
+                                        <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Contribution Schedule"}</label>
+                                        <select onchange={{
+                                            let new_goal_cadence = new_goal_cadence.clone();
+                                            Callback::from(move |e: Event| {
+                                                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                                new_goal_cadence.set(SavingCadence::from_key(&input.value()));
+                                            })
+                                        }} class="w-full bg-muted border-none rounded-xl p-2.5 text-xs font-bold text-primary outline-none">
+                                            { for SavingCadence::ALL.iter().map(|cadence| html! {
+                                                <option value={cadence.key()} selected={*cadence == *new_goal_cadence}>{ cadence.label() }</option>
+                                            }) }
+                                        </select>
+                                    </div>
+                                </div>
+                                <button onclick={create_goal} class="w-full bg-primary text-primary-foreground py-2.5 rounded-xl text-[10px] font-black uppercase flex items-center justify-center gap-2 shadow-md">{"Start New Goal"}</button>
+                            </div>
+                        }
+                    }}
+                </div>
+
+                <div class="lg:col-span-7 bg-card p-6 rounded-2xl shadow-md border border-border flex flex-col h-full">
+                    <h4 class="text-primary font-bold text-[13px] mb-6 uppercase tracking-widest border-b border-slate-50 pb-2">{"Add Contribution"}</h4>
+                    <div class="flex-grow space-y-5">
+                        <div class="grid grid-cols-2 gap-4">
+                            <div class="space-y-1.5">
+                                <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Date"}</label>
+                                <input type="date" max={today.clone()} value={(*contrib_date).clone()} oninput={{
+                                    let contrib_date = contrib_date.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        contrib_date.set(input.value());
+                                    })
+                                }} class="w-full bg-muted border-none rounded-xl p-3 text-xs font-bold text-primary transition-all" />
+                            </div>
+                            <div class="space-y-1.5">
+                                <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{ format!("Amount ({})", currency.symbol) }</label>
+                                <input type="text" inputmode="decimal" pattern={amount_pattern('.', currency.entry_places())} placeholder={currency.entry_placeholder(format_currency(0, &currency))} value={(*contrib_amount).clone()} oninput={amount_oninput(&contrib_amount, '.', currency.entry_places())} class="w-full bg-muted border-none rounded-xl p-3 text-xs font-bold text-primary transition-all" />
+                                { entry_preview(&contrib_amount, &currency) }
+                            </div>
+                        </div>
+                        <div class="space-y-1.5">
+                            <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Description"}</label>
+                            <input type="text" placeholder="e.g. Monthly Savings" value={(*contrib_desc).clone()} oninput={{
+                                let contrib_desc = contrib_desc.clone();
+                                Callback::from(move |e: InputEvent| {
+                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                    contrib_desc.set(input.value());
+                                })
+                            }} class="w-full bg-muted border-none rounded-xl p-3 text-xs font-bold text-primary transition-all" />
+                        </div>
+                    </div>
+                    <div class="flex gap-3 mt-8">
+                        <button onclick={add_contribution} class="flex-2 grow-[2] bg-primary text-primary-foreground py-3 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2 shadow-md hover:translate-y-[-1px] transition-all">{"Add Contribution"}</button>
+                        <button onclick={clear_contribution} class="flex-1 bg-muted text-primary py-3 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2">{"Clear"}</button>
+                    </div>
+                </div>
+            </div>
+                    if *post_failed {
+                        <div class="fixed bottom-6 left-1/2 -translate-x-1/2 z-50 bg-red-600 text-white text-sm px-4 py-3 rounded-[10px] shadow-lg" role="alert">
+                            {"Contribution saved, but its transaction couldn't be recorded. It will be retried automatically."}
+                        </div>
+                    }
+                    if let Some(check) = savings_check.as_ref().filter(|check| !check.is_clean()) {
+                        <div class="bg-amber-50 border border-amber-300 rounded-2xl p-5 space-y-4" role="status">
+                            <h3 class="font-bold text-amber-900">{"Savings check"}</h3>
+                            if !check.missing.is_empty() {
+                                <div class="space-y-2">
+                                    <p class="text-sm text-amber-900">
+                                        { format!(
+                                            "{} {} no matching Savings transaction, so your totals leave {} out:",
+                                            check.missing.len(),
+                                            if check.missing.len() == 1 { "contribution has" } else { "contributions have" },
+                                            if check.missing.len() == 1 { "it" } else { "them" },
+                                        ) }
+                                    </p>
+                                    <ul class="text-xs text-amber-900 list-disc pl-5">
+                                        { for check.missing.iter().map(|entry| html! {
+                                            <li>{ format!("{} · {} · {}", entry.date, entry.description, format_currency(entry.amount, &currency)) }</li>
+                                        }) }
+                                    </ul>
+                                    <div class="flex flex-wrap gap-2">
+                                        <button type="button" onclick={on_repost_missing} disabled={*fixing} class="px-3 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground disabled:opacity-50">
+                                            { if *fixing { "Posting..." } else { "Re-post transactions" } }
+                                        </button>
+                                        <button type="button" onclick={on_remove_missing} disabled={*fixing} class="px-3 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary disabled:opacity-50">{"Remove these contributions"}</button>
+                                    </div>
+                                </div>
+                            }
+                            if !check.untracked.is_empty() {
+                                <div class="space-y-2">
+                                    <p class="text-sm text-amber-900">
+                                        { format!(
+                                            "{} Savings {} no contribution on this goal:",
+                                            check.untracked.len(),
+                                            if check.untracked.len() == 1 { "transaction has" } else { "transactions have" },
+                                        ) }
+                                    </p>
+                                    <ul class="text-xs text-amber-900 list-disc pl-5">
+                                        { for check.untracked.iter().map(|tx| html! {
+                                            <li>{ format!("{} · {} · {}", tx.date.get(0..10).unwrap_or(&tx.date), tx.description, format_currency(-tx.amount, &currency)) }</li>
+                                        }) }
+                                    </ul>
+                                    <button type="button" onclick={on_track_untracked} disabled={*fixing} class="px-3 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground disabled:opacity-50">{"Add to goal"}</button>
+                                </div>
+                            }
+                        </div>
+                    }
+                    if !goal_is_empty {
+                        { goal_projection_chart(&goal, &currency, rounding) }
+                    }
+                    if !goal_totals.is_empty() {
+                        <div class="bg-card rounded-2xl shadow-md border border-border p-5">
+                            <h3 class="font-bold text-foreground text-lg mb-3">{"Saved per Goal"}</h3>
+                            <ul class="space-y-2">
+                                { for goal_totals.iter().map(|(title, total)| html! {
+                                    <li class="flex items-center justify-between text-sm">
+                                        { match title {
+                                            Some(title) => html! { <span class="text-foreground">{ title.clone() }</span> },
+                                            None => html! { <span class="text-muted-foreground">{"Earlier savings (no goal recorded)"}</span> },
+                                        } }
+                                        <span class="font-semibold text-foreground"><CurrencyAmount amount={*total} currency={currency.clone()} /></span>
+                                    </li>
+                                }) }
+                            </ul>
+                        </div>
+                    }
+                    <div class="bg-card rounded-2xl shadow-md border border-border overflow-hidden">
+                        <div class="p-5 border-b border-border">
+                            <h3 class="font-bold text-foreground text-lg">{"Contribution History"}</h3>
+                        </div>
+                        <div class="overflow-auto max-h-[70vh]">
+                            <table class="w-full min-w-[480px] text-left border-collapse">
+                                <caption class="sr-only">{"Contribution history"}</caption>
+                                <thead class="sticky top-0 z-10">
+                                    <tr class="bg-muted text-muted-foreground text-[10px] uppercase tracking-widest">
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Date"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold">{"Description"}</th>
+                                        <th scope="col" class="px-8 py-4 font-bold text-right">{"Amount"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody class="divide-y divide-border">
+                                    { for goal.contributions.iter().enumerate().map(|(idx, item)| html! {
+                                        <tr key={idx} class="text-sm hover:bg-muted/40 transition-colors">
+                                            <td class="px-8 py-4 text-muted-foreground">{ item.date.clone() }</td>
+                                            <td class="px-8 py-4 text-foreground">{ item.description.clone() }</td>
+                                            <td class="px-8 py-4 text-right font-semibold text-foreground"><CurrencyAmount amount={item.amount} currency={currency.clone()} /></td>
+                                        </tr>
+                                    }) }
+                                </tbody>
+                            </table>
+                        </div>
+                    </div>
+                </>
+            }
+        ) }
+    }
+}
+
+/// How long the "copied" toast stays up.
+const COPY_NOTICE_MS: u32 = 3_000;
+
+/// Plain-text digest of one month for pasting into a chat: totals and the
+/// three biggest expense categories.
+fn month_summary_text(
+    transactions: &[Transaction],
+    key: &str,
+    bounds: PeriodBounds,
+    currency: &CurrencyFormat,
+) -> String {
+    let (income, expenses) = month_totals(transactions, key, bounds);
+    let categories = month_expense_categories(transactions, key, bounds);
+    let mut text = format!(
+        "PondoBro summary for {}\nIncome: {}\nExpenses: {}\nBalance: {}",
+        key,
+        format_currency(income, currency),
+        format_currency(expenses, currency),
+        format_currency(income - expenses, currency)
+    );
+    if !categories.is_empty() {
+        let top = categories
+            .iter()
+            .take(3)
+            .map(|(category, amount)| {
+                format!("{} {}", category, format_currency(*amount, currency))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        text.push_str(&format!("\nTop categories: {}", top));
+    }
+    text
+}
+
+/// Expense totals per category for one `YYYY-MM` period, biggest first.
+fn month_expense_categories(
+    transactions: &[Transaction],
+    key: &str,
+    bounds: PeriodBounds,
+) -> Vec<(String, i64)> {
+    let mut categories = sum_by_category(
+        transactions
+            .iter()
+            .filter(|tx| {
+                tx.amount < 0
+                    && !is_transfer(tx)
+                    && month_key(&tx.date, bounds).as_deref() == Some(key)
+            })
+            .map(|tx| (tx.category.as_str(), tx.amount.abs())),
+    );
+    categories.sort_by_key(|b| std::cmp::Reverse(b.1));
+    categories
+}
+
+/// `navigator.clipboard.writeText`, looked up through `Reflect` since the
+/// web-sys `Clipboard` binding needs the unstable-APIs cfg. False when the
+/// API is missing or the write is refused.
+async fn write_clipboard(text: &str) -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let Ok(clipboard) = js_sys::Reflect::get(&window.navigator(), &JsValue::from_str("clipboard"))
+    else {
+        return false;
+    };
+    let Some(write_text) = js_sys::Reflect::get(&clipboard, &JsValue::from_str("writeText"))
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+    else {
+        return false;
+    };
+    match write_text.call1(&clipboard, &JsValue::from_str(text)) {
+        Ok(promise) => JsFuture::from(js_sys::Promise::from(promise)).await.is_ok(),
+        Err(_) => false,
+    }
+}
+
+#[function_component(SummaryPage)]
+fn summary_page() -> Html {
+    let currency = use_currency_format();
+    let bounds = use_period_bounds();
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    let savings_as_spending = settings.as_ref().is_none_or(|s| s.savings_as_spending);
+    let rounding = settings
+        .as_ref()
+        .map(|s| s.rounding_mode)
+        .unwrap_or_default();
+
+    let summary = use_summary();
+    let refresh_summary = summary.refresh.clone();
+    let transactions = use_state(Vec::<Transaction>::new);
+    let loading = use_state(|| true);
+    let scope = use_request_scope();
+    let load_error = use_state(|| None::<ApiError>);
+    let load_attempt = use_state(|| 0u32);
+    let refreshed_at = use_state(|| None::<f64>);
+
+    {
+        let transactions = transactions.clone();
+        let loading = loading.clone();
+        let load_error = load_error.clone();
+        let refreshed_at = refreshed_at.clone();
+
+        use_effect_with_deps(
+            move |_| {
+                load_error.set(None);
+                loading.set(true);
+                spawn_local(async move {
+                    match fetch_transactions(Some(&scope)).await {
+                        Ok(list) => {
+                            transactions.set(list);
+                            refreshed_at.set(Some(js_sys::Date::now()));
+                        }
+                        Err(ApiError::Cancelled) => return,
+                        Err(err) => load_error.set(Some(err)),
+                    }
+
+                    loading.set(false);
+                });
+                || ()
+            },
+            *load_attempt,
+        );
+    }
+
+    let selected_tx = use_state(|| None::<Transaction>);
+    let selected_day = use_state(|| None::<String>);
+    // label of the merchant picked in Top Merchants
+    let selected_merchant = use_state(|| None::<String>);
+    let min_amount = use_state(|| "".to_string());
+    let max_amount = use_state(|| "".to_string());
+    let copy_notice = use_state(|| false);
+    let copy_notice_timer = use_mut_ref(|| None::<Timeout>);
+    // text the clipboard refused, shown for copying by hand
+    let clipboard_fallback = use_state(|| None::<String>);
+    let show_export = use_state(|| false);
+    // the last choices stick while the page is open
+    let export_options = use_state(CsvOptions::default);
+    // `None` is "All years"
+    let selected_year = use_state(|| Some(current_year()));
+    let on_year_change = {
+        let selected_year = selected_year.clone();
+        let selected_day = selected_day.clone();
+        let selected_merchant = selected_merchant.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            selected_year.set(input.value().parse::<i32>().ok());
+            selected_day.set(None);
+            selected_merchant.set(None);
+        })
+    };
+    let TransactionModalCallbacks {
+        on_select: on_select_tx,
+        on_close: on_close_tx,
+        on_updated: on_tx_updated,
+        on_deleted: on_tx_deleted,
+        on_split: on_tx_split,
+    } = transaction_modal_callbacks(&transactions, &selected_tx, &refresh_summary);
+
+    let on_select_day = {
+        let selected_day = selected_day.clone();
+        Callback::from(move |day: String| {
+            if selected_day.as_deref() == Some(day.as_str()) {
+                selected_day.set(None);
+            } else {
+                selected_day.set(Some(day));
+            }
+        })
+    };
+    let on_select_merchant = {
+        let selected_merchant = selected_merchant.clone();
+        Callback::from(move |label: String| {
+            if selected_merchant.as_deref().map(merchant_key) == Some(merchant_key(&label)) {
+                selected_merchant.set(None);
+            } else {
+                selected_merchant.set(Some(label));
+            }
+        })
+    };
+    let on_clear_day = {
+        let selected_day = selected_day.clone();
+        let selected_merchant = selected_merchant.clone();
+        Callback::from(move |_| {
+            selected_day.set(None);
+            selected_merchant.set(None);
+        })
+    };
+
+    let year = *selected_year;
+    let years = transaction_years(&transactions);
+    let year_transactions: Vec<Transaction> = transactions
+        .iter()
+        .filter(|tx| year.is_none() || year_of(&tx.date) == year)
+        .cloned()
+        .collect();
+    let tax_transactions: Vec<Transaction> = year_transactions
+        .iter()
+        .filter(|tx| tx.tax && !is_transfer(tx))
+        .cloned()
+        .collect();
+    let (tax_income, tax_expenses) = income_expense_totals(&tax_transactions);
+    let on_export_tax = {
+        let tax_transactions = tax_transactions.clone();
+        let show_export = show_export.clone();
+        let export_options = export_options.clone();
+        let app_code = currency.code.clone();
+        Callback::from(move |options: CsvOptions| {
+            let filename = match year {
+                Some(y) => format!("pondobro-tax-{}.csv", y),
+                None => "pondobro-tax-all.csv".to_string(),
+            };
+            download_csv(
+                &filename,
+                &transactions_csv(&tax_transactions, options, &app_code),
+            );
+            export_options.set(options);
+            show_export.set(false);
+        })
+    };
+
+    let (income, expenses, balance) = match year {
+        Some(_) => {
+            let (income, expenses) = income_expense_totals(&year_transactions);
+            (income, expenses, income - expenses)
+        }
+        None => (
+            summary.totals.income,
+            summary.totals.expenses,
+            summary.totals.balance,
+        ),
+    };
+
+    // filters combine with AND; with none active the list shows the latest 10
+    let amount_range = (parse_bound(&min_amount), parse_bound(&max_amount));
+    let is_filtered =
+        selected_day.is_some() || selected_merchant.is_some() || amount_range != (None, None);
+    let merchant_filter = selected_merchant.as_deref().map(merchant_key);
+    let matching = year_transactions.iter().filter(|tx| {
+        selected_day
+            .as_deref()
+            .map(|day| tx.date.get(0..10) == Some(day))
+            .unwrap_or(true)
+            && merchant_filter
+                .as_ref()
+                .is_none_or(|key| merchant_key(&tx.description) == *key)
+            && within_amount_range(tx, amount_range)
+    });
+    let merchants = top_merchants(&year_transactions, savings_as_spending);
+    let by_weekday = weekday_spending(&year_transactions, savings_as_spending, rounding);
+    let recent: Vec<Transaction> = if is_filtered {
+        matching.cloned().collect()
+    } else {
+        matching.take(10).cloned().collect()
+    };
+
+    // month-over-month comparison, bucketed by the YYYY-MM prefix of each date;
+    // a past year compares its December with November
+    let this_month = match year {
+        Some(y) if y != current_year() => format!("{:04}-12", y),
+        _ => current_month_key(bounds),
+    };
+    let last_month = previous_month_key(&this_month);
+    let (this_income, this_expenses) = month_totals(&transactions, &this_month, bounds);
+
+    // budgets are monthly, so they are reconciled against the same month
+    let month_transactions: Vec<Transaction> = transactions
+        .iter()
+        .filter(|tx| month_key(&tx.date, bounds).as_deref() == Some(this_month.as_str()))
+        .cloned()
+        .collect();
+    let budget_rows = budget_vs_actual(&load_budgets(), &month_transactions, savings_as_spending);
+    let budget_limit_total: i64 = budget_rows.iter().map(|(_, limit, _)| limit).sum();
+    let budget_actual_total: i64 = budget_rows.iter().map(|(_, _, actual)| actual).sum();
+    let on_export_budget = {
+        let csv = budget_vs_actual_csv(&budget_rows);
+        let filename = format!("pondobro-budget-{}.csv", this_month);
+        Callback::from(move |_| download_csv(&filename, &csv))
+    };
+    let last_totals = last_month
+        .as_deref()
+        .filter(|key| {
+            transactions
+                .iter()
+                .any(|tx| month_key(&tx.date, bounds).as_deref() == Some(*key))
+        })
+        .map(|key| month_totals(&transactions, key, bounds));
+
+    let on_copy_summary = {
+        let text = month_summary_text(&transactions, &this_month, bounds, &currency);
+        let copy_notice = copy_notice.clone();
+        let copy_notice_timer = copy_notice_timer.clone();
+        let clipboard_fallback = clipboard_fallback.clone();
+        Callback::from(move |_| {
+            let text = text.clone();
+            let copy_notice = copy_notice.clone();
+            let copy_notice_timer = copy_notice_timer.clone();
+            let clipboard_fallback = clipboard_fallback.clone();
+            spawn_local(async move {
+                if write_clipboard(&text).await {
+                    copy_notice.set(true);
+                    let copy_notice = copy_notice.clone();
+                    *copy_notice_timer.borrow_mut() =
+                        Some(Timeout::new(COPY_NOTICE_MS, move || copy_notice.set(false)));
+                } else {
+                    // permission refused or no clipboard: let the user copy by hand
+                    clipboard_fallback.set(Some(text));
+                }
+            });
+        })
+    };
+
+    let on_refresh = {
+        let load_attempt = load_attempt.clone();
+        let refresh_summary = refresh_summary.clone();
+        Callback::from(move |_| {
+            load_attempt.set(*load_attempt + 1);
+            refresh_summary.emit(());
+        })
+    };
+
+    let header_actions = html! {
+        <div class="flex items-center gap-2">
+            <RefreshStatus refreshed_at={*refreshed_at} refreshing={*loading} {on_refresh} />
+            <button type="button" onclick={on_copy_summary} class="p-2 border rounded text-sm">{"Copy summary"}</button>
+            <select onchange={on_year_change} aria-label="Report year" class="p-2 border rounded text-sm">
+                <option value="" selected={year.is_none()}>{"All years"}</option>
+                { for years.iter().map(|y| html! {
+                    <option value={y.to_string()} selected={year == Some(*y)}>{ y.to_string() }</option>
+                }) }
+            </select>
+        </div>
+    };
+
+    html! {
+        { page_shell(
+            "Summary Report",
+            header_actions,
+            html! {
+                <>
+                    { load_error_banner(*load_error, &load_attempt) }
+                    if *show_export {
+                        <CsvExportDialog
+                            options={*export_options}
+                            on_export={on_export_tax}
+                            on_close={{
+                                let show_export = show_export.clone();
+                                Callback::from(move |_| show_export.set(false))
+                            }}
+                        />
+                    }
+                    if *copy_notice {
+                        <div class="fixed bottom-6 left-1/2 -translate-x-1/2 z-50 bg-primary text-primary-foreground text-sm px-4 py-3 rounded-[10px] shadow-lg" role="status">
+                            {"Summary copied to clipboard."}
+                        </div>
+                    }
+                    if let Some(text) = &*clipboard_fallback {
+                        <div class="bg-card rounded-lg p-4 border border-border space-y-2">
+                            <div class="flex items-center justify-between">
+                                <p class="text-sm text-muted-foreground">{"Couldn't reach the clipboard. Select the text below and copy it."}</p>
+                                <button type="button" onclick={{
+                                    let clipboard_fallback = clipboard_fallback.clone();
+                                    Callback::from(move |_| clipboard_fallback.set(None))
+                                }} class="text-muted-foreground hover:text-foreground" aria-label="Dismiss">{"✕"}</button>
+                            </div>
+                            <textarea readonly=true rows="6" aria-label="Summary text" value={text.clone()} onfocus={Callback::from(|e: FocusEvent| {
+                                let area: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                                area.select();
+                            })} class="w-full p-2 border rounded text-sm font-mono" />
+                        </div>
+                    }
+                    if !*loading && year_transactions.is_empty() {
+                        if let Some(y) = year {
+                            <div class="bg-card rounded-lg p-6 border border-border text-center text-muted-foreground">
+                                { format!("No data for {}.", y) }
+                            </div>
+                        }
+                    }
+                    <div class="grid grid-cols-1 md:grid-cols-3 gap-6">
+                        <div class="bg-card rounded-lg p-6 border border-border">
+                            <p class="text-sm text-muted-foreground mb-2">{"Total Income"}</p>
+                            <h3 class="text-3xl font-bold text-foreground"><CurrencyAmount amount={income} currency={currency.clone()} /></h3>
+                        </div>
+                        <div class="bg-card rounded-lg p-6 border border-border">
+                            <p class="text-sm text-muted-foreground mb-2">{"Total Expenses"}</p>
+                            <h3 class="text-3xl font-bold text-foreground"><CurrencyAmount amount={expenses} currency={currency.clone()} /></h3>
+                        </div>
+                        <div class="bg-card rounded-lg p-6 border border-border">
+                            <p class="text-sm text-muted-foreground mb-2">{"Net Balance"}</p>
+                            <h3 class="text-3xl font-bold text-foreground"><CurrencyAmount amount={balance} currency={currency.clone()} /></h3>
+                        </div>
+                    </div>
+
+                    <div class="bg-card rounded-lg p-6 border border-border">
+                        <div class="flex items-center justify-between mb-4">
+                            <h3 class="text-lg font-bold text-foreground">{"Month over Month"}</h3>
+                            <span class="text-xs text-muted-foreground">{ format!("{} vs {}", this_month, last_month.clone().unwrap_or_default()) }</span>
+                        </div>
+                        <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
+                            { month_delta_card("Income", this_income, last_totals.map(|(income, _)| income), true, &currency) }
+                            { month_delta_card("Expenses", this_expenses, last_totals.map(|(_, expenses)| expenses), false, &currency) }
+                            { month_delta_card("Balance", this_income - this_expenses, last_totals.map(|(income, expenses)| income - expenses), true, &currency) }
+                        </div>
+                    </div>
+
+                    <RangeComparison transactions={(*transactions).clone()} currency={currency.clone()} />
+
+                    <div class="bg-card rounded-lg p-6 border border-border">
+                        <div class="flex items-center justify-between mb-4">
+                            <h3 class="text-lg font-bold text-foreground">{"Tax"}</h3>
+                            <button type="button" onclick={{
+                                let show_export = show_export.clone();
+                                Callback::from(move |_| show_export.set(true))
+                            }} disabled={tax_transactions.is_empty()} class="px-3 py-1 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground disabled:opacity-50">{"Export CSV"}</button>
+                        </div>
+                        <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
+                            <div class="p-4 rounded-lg border border-border">
+                                <p class="text-sm text-muted-foreground mb-1">{"Taxable income"}</p>
+                                <p class="text-xl font-bold text-foreground"><CurrencyAmount amount={tax_income} currency={currency.clone()} /></p>
+                            </div>
+                            <div class="p-4 rounded-lg border border-border">
+                                <p class="text-sm text-muted-foreground mb-1">{"Deductible expenses"}</p>
+                                <p class="text-xl font-bold text-foreground"><CurrencyAmount amount={tax_expenses} currency={currency.clone()} /></p>
+                            </div>
+                            <div class="p-4 rounded-lg border border-border">
+                                <p class="text-sm text-muted-foreground mb-1">{"Flagged transactions"}</p>
+                                <p class="text-xl font-bold text-foreground">{ tax_transactions.len() }</p>
+                            </div>
+                        </div>
+                    </div>
+
+                    <div class="bg-card rounded-lg p-6 border border-border">
+                        <div class="flex items-center justify-between mb-4">
+                            <div>
+                                <h3 class="text-lg font-bold text-foreground">{"Budget vs Actual"}</h3>
+                                <span class="text-xs text-muted-foreground">{ this_month.clone() }</span>
+                            </div>
+                            <button type="button" onclick={on_export_budget} disabled={budget_rows.is_empty()} class="px-3 py-1 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground disabled:opacity-50">{"Export CSV"}</button>
+                        </div>
+                        { if budget_rows.is_empty() {
+                            html! { <p class="text-sm text-muted-foreground">{"No budgets set. Add limits on the Budget page to compare them here."}</p> }
+                        } else {
+                            html! {
+                                <div class="overflow-x-auto">
+                                    <table class="w-full text-sm text-left border-collapse">
+                                        <caption class="sr-only">{ format!("Budget vs actual for {}", this_month) }</caption>
+                                        <thead>
+                                            <tr class="text-muted-foreground text-[10px] uppercase tracking-widest border-b border-border">
+                                                <th scope="col" class="py-2 font-bold">{"Category"}</th>
+                                                <th scope="col" class="py-2 font-bold text-right">{"Limit"}</th>
+                                                <th scope="col" class="py-2 font-bold text-right">{"Actual"}</th>
+                                                <th scope="col" class="py-2 font-bold text-right">{"Variance"}</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody class="divide-y divide-border">
+                                            { for budget_rows.iter().map(|(category, limit, actual)| {
+                                                let variance = limit - actual;
+                                                html! {
+                                                    <tr>
+                                                        <td class="py-2 text-foreground">{ category.clone() }</td>
+                                                        <td class="py-2 text-right"><CurrencyAmount amount={*limit} currency={currency.clone()} /></td>
+                                                        <td class="py-2 text-right"><CurrencyAmount amount={*actual} currency={currency.clone()} /></td>
+                                                        <td class="py-2 text-right font-semibold"><CurrencyAmount amount={variance} currency={currency.clone()} /></td>
+                                                    </tr>
+                                                }
+                                            }) }
+                                        </tbody>
+                                        <tfoot>
+                                            <tr class="border-t-2 border-border font-bold">
+                                                <th scope="row" class="py-2 text-left text-foreground">{"Total"}</th>
+                                                <td class="py-2 text-right"><CurrencyAmount amount={budget_limit_total} currency={currency.clone()} /></td>
+                                                <td class="py-2 text-right"><CurrencyAmount amount={budget_actual_total} currency={currency.clone()} /></td>
+                                                <td class="py-2 text-right"><CurrencyAmount amount={budget_limit_total - budget_actual_total} currency={currency.clone()} /></td>
+                                            </tr>
+                                        </tfoot>
+                                    </table>
+                                </div>
+                            }
+                        }}
+                    </div>
+
+                    <div class="bg-card rounded-lg p-6 border border-border">
+                        <div class="flex items-center justify-between mb-4">
+                            <h3 class="text-lg font-bold text-foreground">{"Spending Calendar"}</h3>
+                            <span class="text-xs text-muted-foreground">{"Click a day to see its transactions"}</span>
+                        </div>
+                        { spending_heatmap(&year_transactions, year, selected_day.as_deref(), &on_select_day, bounds, &currency) }
+                    </div>
+
+                    <div class="bg-card rounded-lg p-6 border border-border">
+                        <div class="flex items-center justify-between mb-4">
+                            <h3 class="text-lg font-bold text-foreground">{"Top Merchants"}</h3>
+                            <span class="text-xs text-muted-foreground">{"Click one to see its transactions"}</span>
+                        </div>
+                        if merchants.is_empty() {
+                            <p class="text-sm text-muted-foreground">{"No spending yet."}</p>
+                        } else {
+                            <table class="w-full text-sm">
+                                <thead>
+                                    <tr class="text-left text-muted-foreground border-b border-border">
+                                        <th class="py-2 font-semibold">{"Description"}</th>
+                                        <th class="py-2 font-semibold text-right">{"Count"}</th>
+                                        <th class="py-2 font-semibold text-right">{"Total"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for merchants.iter().map(|(label, total, count)| {
+                                        let active = merchant_filter.as_deref() == Some(merchant_key(label).as_str());
+                                        let onclick = {
+                                            let on_select_merchant = on_select_merchant.clone();
+                                            let label = label.clone();
+                                            Callback::from(move |_| on_select_merchant.emit(label.clone()))
+                                        };
+                                        html! {
+                                            <tr key={merchant_key(label)} class={classes!("border-b", "border-border", "last:border-0", active.then_some("bg-muted"))}>
+                                                <td class="py-2">
+                                                    <button type="button" {onclick} aria-pressed={active.to_string()} class="text-left font-semibold text-primary hover:underline">{ label.clone() }</button>
+                                                </td>
+                                                <td class="py-2 text-right text-muted-foreground">{ *count }</td>
+                                                <td class="py-2 text-right text-foreground"><CurrencyAmount amount={*total} currency={currency.clone()} /></td>
+                                            </tr>
+                                        }
+                                    }) }
+                                </tbody>
+                            </table>
+                        }
+                    </div>
+
+                    <div class="bg-card rounded-lg p-6 border border-border">
+                        <div class="flex items-center justify-between mb-4">
+                            <h3 class="text-lg font-bold text-foreground">{"Spending by Weekday"}</h3>
+                            <span class="text-xs text-muted-foreground">{"Hover a bar for its total and average"}</span>
+                        </div>
+                        { weekday_chart(&by_weekday, &currency) }
+                    </div>
+
+                    <div class="bg-card rounded-lg border border-border overflow-hidden">
+                        <div class="px-6 py-4 border-b border-border flex items-center justify-between">
+                            <h3 class="text-lg font-bold text-foreground">
+                                { match (selected_day.as_deref(), selected_merchant.as_deref()) {
+                                    (Some(day), Some(merchant)) => format!("{} on {}", merchant, day),
+                                    (Some(day), None) => format!("Transactions on {}", day),
+                                    (None, Some(merchant)) => format!("Transactions at {}", merchant),
+                                    (None, None) => "Recent Transactions".to_string(),
+                                } }
+                            </h3>
+                            <div class="flex flex-wrap items-center gap-2">
+                                if is_filtered {
+                                    <span class="text-xs text-muted-foreground">{ format!("{} match(es)", recent.len()) }</span>
+                                }
+                                { amount_range_inputs(&min_amount, &max_amount) }
+                                if selected_day.is_some() || selected_merchant.is_some() {
+                                    <button type="button" onclick={on_clear_day} class="text-xs font-bold text-primary hover:underline">{"Show recent"}</button>
+                                }
+                            </div>
+                        </div>
+                        <div class="overflow-x-auto">
+                            <table class="w-full text-left border-collapse">
+                                <caption class="sr-only">{"Transactions"}</caption>
+                                <thead class="bg-secondary border-b border-border">
+                                    <tr>
+                                        <th scope="col" class="px-6 py-3 text-left text-sm font-semibold text-secondary-foreground">{"Date"}</th>
+                                        <th scope="col" class="px-6 py-3 text-left text-sm font-semibold text-secondary-foreground">{"Description"}</th>
+                                        <th scope="col" class="px-6 py-3 text-left text-sm font-semibold text-secondary-foreground">{"Category"}</th>
+                                        <th scope="col" class="px-6 py-3 text-right text-sm font-semibold text-secondary-foreground">{"Amount"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody class="divide-y divide-border">
+                                    { if *loading {
+                                        html! { <tr><td colspan="4" class="px-6 py-6 text-center text-muted-foreground">{"Loading..."}</td></tr> }
+                                    } else if recent.is_empty() {
+                                        html! { <tr><td colspan="4" class="px-6 py-6 text-center text-muted-foreground">{ if is_filtered { "No transactions match these filters." } else { "No transactions yet." } }</td></tr> }
+                                    } else {
+                                        html! {
+                                            <>
+                                                { for recent.iter().enumerate().map(|(idx, tx)| html! {
+                                                    <tr key={transaction_key(tx, idx)} class="text-sm hover:bg-muted/30 transition-colors cursor-pointer" tabindex="0" aria-haspopup="dialog" aria-label={format!("{}, {} on {}", tx.description, amount_description(tx, &currency), tx.date)} onclick={row_select_callback(&on_select_tx, tx)} onkeydown={row_keyboard_callback(&on_select_tx, tx)}>
+                                                        <td class="px-6 py-3 text-muted-foreground">{ tx.date.clone() }</td>
+                                                        <td class="px-6 py-3 text-foreground">{ tx.description.clone() }{ note_details(tx) }</td>
+                                                        <td class={classes!("px-6", "py-3", if is_transfer(tx) { "text-primary italic" } else { "text-foreground" })}>{ category_label(tx) }{ split_badge(tx) }</td>
+                                                        <td class="px-6 py-3 text-right font-semibold text-foreground">{ amount_cell(tx, &currency, false) }</td>
+                                                    </tr>
+                                                }) }
+                                            </>
+                                        }
+                                    }}
+                                </tbody>
+                            </table>
+                        </div>
+                    </div>
+
+                    if let Some(tx) = &*selected_tx {
+                        <TransactionModal
+                            transaction={tx.clone()}
+                            currency={currency.clone()}
+                            on_close={on_close_tx}
+                            on_updated={on_tx_updated}
+                            on_deleted={on_tx_deleted}
+                            on_split={on_tx_split}
+                        />
+                    }
+                </>
+            }
+        ) }
+    }
+}
+
+/// Quotes a CSV field when it contains a comma, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One budgeted category's `(category, limit, actual)` for a period;
+/// variance is `limit - actual`, negative when over budget.
+type BudgetActualRow = (String, i64, i64);
+
+/// Joins each budget with what was spent in its category over
+/// `transactions`, in budget order.
+fn budget_vs_actual(
+    budgets: &[BudgetItem],
+    transactions: &[Transaction],
+    savings_as_spending: bool,
+) -> Vec<BudgetActualRow> {
+    let spent = spent_by_category(transactions, savings_as_spending);
+    budgets
+        .iter()
+        .map(|b| {
+            let actual = spent.get(&category_key(&b.category)).cloned().unwrap_or(0);
+            (b.category.clone(), b.limit, actual)
+        })
+        .collect()
+}
+
+/// The budget-vs-actual table as CSV, ending with a Total row. Amounts are
+/// raw whole units, like `transactions_csv`.
+fn budget_vs_actual_csv(rows: &[BudgetActualRow]) -> String {
+    let mut csv = String::from("Category,Limit,Actual,Variance\n");
+    let (mut limit_total, mut actual_total) = (0i64, 0i64);
+    for (category, limit, actual) in rows {
+        limit_total += limit;
+        actual_total += actual;
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_field(category),
+            limit,
+            actual,
+            limit - actual
+        ));
+    }
+    csv.push_str(&format!(
+        "Total,{},{},{}\n",
+        limit_total,
+        actual_total,
+        limit_total - actual_total
+    ));
+    csv
+}
+
+/// Column choices offered before a CSV export.
+#[derive(Clone, Copy, PartialEq, Default)]
+struct CsvOptions {
+    /// Separate Debit (money out) and Credit (money in) columns instead of
+    /// one signed Amount column.
+    split_amounts: bool,
+    /// Adds a Balance column; rows are then written oldest first so the
+    /// balance reads top to bottom. Each currency keeps its own balance.
+    running_balance: bool,
+}
+
+/// One row per transaction; amounts are raw whole units. The signed layout
+/// matches storage (negative for expenses); the split layout puts magnitudes
+/// under Debit or Credit and leaves the other cell blank. Currency is the
+/// row's own code, or `app_code` for rows kept in the app currency.
+fn transactions_csv(transactions: &[Transaction], options: CsvOptions, app_code: &str) -> String {
+    let mut rows: Vec<&Transaction> = transactions.iter().collect();
+    if options.running_balance {
+        // stable, so same-day rows keep their order
+        rows.sort_by(|a, b| a.date.get(0..10).cmp(&b.date.get(0..10)));
+    }
+
+    let mut csv = String::from("Date,Description,Category,Account,Currency,");
+    csv.push_str(if options.split_amounts {
+        "Debit,Credit,"
+    } else {
+        "Amount,"
+    });
+    if options.running_balance {
+        csv.push_str("Balance,");
+    }
+    csv.push_str("Note\n");
+
+    // there are no exchange rates, so amounts in different currencies are
+    // never added together
+    let mut balances: HashMap<String, i64> = HashMap::new();
+    for tx in rows {
+        let code = tx
+            .currency
+            .as_deref()
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            .unwrap_or(app_code)
+            .to_uppercase();
+        let mut row = vec![
+            csv_field(tx.date.get(0..10).unwrap_or(&tx.date)),
+            csv_field(&tx.description),
+            csv_field(&tx.category),
+            csv_field(account_name(tx)),
+            csv_field(&code),
+        ];
+        if options.split_amounts {
+            let magnitude = tx.amount.unsigned_abs().to_string();
+            if tx.amount < 0 {
+                row.extend([magnitude, String::new()]);
+            } else {
+                row.extend([String::new(), magnitude]);
+            }
+        } else {
+            row.push(tx.amount.to_string());
+        }
+        if options.running_balance {
+            let balance = balances.entry(code).or_insert(0);
+            *balance += tx.amount;
+            row.push(balance.to_string());
+        }
+        row.push(csv_field(tx.note.as_deref().unwrap_or("")));
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+#[derive(Properties, PartialEq)]
+struct CsvExportDialogProps {
+    /// Choices the dialog opens with.
+    options: CsvOptions,
+    on_export: Callback<CsvOptions>,
+    on_close: Callback<()>,
+}
+
+/// Asks how amounts should be laid out before a CSV download starts.
+#[function_component(CsvExportDialog)]
+fn csv_export_dialog(props: &CsvExportDialogProps) -> Html {
+    let options = use_state(|| props.options);
+    let on_close = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+    let on_export = {
+        let on_export = props.on_export.clone();
+        let options = options.clone();
+        Callback::from(move |_| on_export.emit(*options))
+    };
+    let layout_choice = |split: bool, label: &'static str, hint: &'static str| {
+        let onchange = {
+            let options = options.clone();
+            Callback::from(move |_| {
+                options.set(CsvOptions {
+                    split_amounts: split,
+                    ..*options
+                })
+            })
+        };
+        html! {
+            <label class="flex items-start gap-2 text-sm">
+                <input type="radio" name="csv-amount-layout" checked={options.split_amounts == split} {onchange} class="mt-1" />
+                <span>
+                    <span class="text-foreground">{ label }</span>
+                    <span class="block text-xs text-muted-foreground">{ hint }</span>
+                </span>
+            </label>
+        }
+    };
+    let on_balance = {
+        let options = options.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            options.set(CsvOptions {
+                running_balance: input.checked(),
+                ..*options
+            });
+        })
+    };
+
+    html! {
+        <div class="fixed inset-0 z-50 flex items-center justify-center bg-black/30" onclick={on_close.clone()}>
+            <div class="w-full max-w-sm bg-card border border-border rounded-xl shadow-lg p-6" role="dialog" aria-modal="true" aria-label="Export options" onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}>
+                <h3 class="font-bold text-foreground text-lg mb-4">{"Export CSV"}</h3>
+                <fieldset class="space-y-3 mb-4">
+                    <legend class="text-xs font-bold text-muted-foreground uppercase mb-2">{"Amounts"}</legend>
+                    { layout_choice(false, "Signed amount", "One column, negative for expenses. Best for spreadsheet sums.") }
+                    { layout_choice(true, "Debit / Credit", "Money out under Debit, money in under Credit. Suits double-entry tools.") }
+                </fieldset>
+                <label class="flex items-center gap-2 text-sm text-foreground mb-6">
+                    <input type="checkbox" checked={options.running_balance} onchange={on_balance} />
+                    {"Include running balance"}
+                </label>
+                <div class="flex justify-end gap-2">
+                    <button type="button" onclick={on_close} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{"Cancel"}</button>
+                    <button type="button" onclick={on_export} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{"Download"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Saves `contents` through a temporary object URL and a synthetic link click.
+fn download_csv(filename: &str, contents: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("text/csv;charset=utf-8");
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else {
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+    if let Ok(link) = document.create_element("a") {
+        if let Ok(link) = link.dyn_into::<web_sys::HtmlAnchorElement>() {
+            link.set_href(&url);
+            link.set_download(filename);
+            link.click();
+        }
+    }
+    let _ = web_sys::Url::revoke_object_url(&url);
+}
+
+/// Renders one metric of the month-over-month card. `previous` is `None` when
+/// there is no data for the prior month, in which case the delta shows "—".
+fn month_delta_card(
+    label: &'static str,
+    current: i64,
+    previous: Option<i64>,
+    increase_is_good: bool,
+    currency: &CurrencyFormat,
+) -> Html {
+    let delta = previous.map(|prev| current - prev);
+    let delta_class = match delta {
+        Some(d) if d != 0 => currency.sign_color((d > 0) == increase_is_good),
+        _ => Some("text-muted-foreground"),
+    };
+
+    html! {
+        <div class="p-4 rounded-lg border border-border">
+            <p class="text-sm text-muted-foreground mb-1">{ label }</p>
+            <p class="text-xl font-bold text-foreground"><CurrencyAmount amount={current} currency={currency.clone()} /></p>
+            <div class={classes!("flex", "items-center", "gap-1", "mt-2", "text-xs", "font-semibold", delta_class)}>
+                {
+                    match delta {
+                        Some(d) if d > 0 => html! { <span class="shrink-0">{ icon_arrow_up_right() }</span> },
+                        Some(d) if d < 0 => html! { <span class="shrink-0 rotate-90">{ icon_arrow_up_right() }</span> },
+                        _ => html! {},
+                    }
+                }
+                <span>
+                    {
+                        match (delta, previous) {
+                            (Some(d), Some(prev)) => format!("{} vs {} last month", format_currency(d.abs(), currency), format_currency(prev, currency)),
+                            _ => "—".to_string(),
+                        }
+                    }
+                </span>
+            </div>
+        </div>
+    }
+}
+
+/// Length in days of each range the comparison opens with.
+const COMPARE_RANGE_DAYS: i64 = 30;
+
+/// Day number of a `YYYY-MM-DD` date, for range arithmetic.
+fn day_number(date: &str) -> Option<i64> {
+    parse_ymd(date).map(|(year, month, day)| days_from_civil(year, month, day))
+}
+
+fn date_key_from_days(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// What one side of the range comparison adds up to.
+struct RangeTotals {
+    income: i64,
+    expenses: i64,
+    /// Spending per category, transfers excluded.
+    by_category: Vec<(String, i64)>,
+    /// Inclusive length of the range.
+    days: i64,
+}
+
+/// Totals the transactions dated `start..=end`. `None` when either date is
+/// missing or the range runs backwards.
+fn range_totals(transactions: &[Transaction], start: &str, end: &str) -> Option<RangeTotals> {
+    let (from, to) = (day_number(start)?, day_number(end)?);
+    if from > to {
+        return None;
+    }
+    let in_range: Vec<&Transaction> = transactions
+        .iter()
+        .filter(|tx| {
+            tx.date
+                .get(0..10)
+                .is_some_and(|date| (start..=end).contains(&date))
+        })
+        .collect();
+    let (income, expenses) = income_expense_totals(in_range.iter().copied());
+    let by_category = sum_by_category(
+        in_range
+            .iter()
+            .filter(|tx| tx.amount < 0 && !is_transfer(tx))
+            .map(|tx| (tx.category.as_str(), tx.amount.abs())),
+    );
+    Some(RangeTotals {
+        income,
+        expenses,
+        by_category,
+        days: to - from + 1,
+    })
+}
+
+/// Pairs up the categories of both ranges as `(label, a, b)`, largest
+/// change first.
+fn category_deltas(a: &[(String, i64)], b: &[(String, i64)]) -> Vec<(String, i64, i64)> {
+    let mut rows: Vec<(String, i64, i64)> = a
+        .iter()
+        .map(|(label, amount)| (label.clone(), *amount, 0))
+        .collect();
+    for (label, amount) in b {
+        match rows
+            .iter_mut()
+            .find(|(existing, _, _)| category_key(existing) == category_key(label))
+        {
+            Some(row) => row.2 = *amount,
+            None => rows.push((label.clone(), 0, *amount)),
+        }
+    }
+    rows.sort_by(|x, y| {
+        (y.1 - y.2)
+            .abs()
+            .cmp(&(x.1 - x.2).abs())
+            .then_with(|| x.0.cmp(&y.0))
+    });
+    rows
+}
+
+/// Arrow and colour for a change; spending going up is the bad direction.
+fn delta_indicator(delta: i64, increase_is_good: bool, currency: &CurrencyFormat) -> Html {
+    let class = match delta.signum() {
+        0 => Some("text-muted-foreground"),
+        sign => currency.sign_color((sign > 0) == increase_is_good),
+    };
+    html! {
+        <span class={classes!("inline-flex", "items-center", "gap-1", "font-semibold", class)}>
+            {
+                match delta.signum() {
+                    1 => html! { <span class="shrink-0" aria-label="Up">{ icon_arrow_up_right() }</span> },
+                    -1 => html! { <span class="shrink-0 rotate-90" aria-label="Down">{ icon_arrow_up_right() }</span> },
+                    _ => html! {},
+                }
+            }
+        </span>
+    }
+}
+
+fn range_date_input(label: &'static str, value: &UseStateHandle<String>) -> Html {
+    let oninput = {
+        let value = value.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            value.set(input.value());
+        })
+    };
+    html! {
+        <input type="date" aria-label={label} value={(**value).clone()} {oninput} class="p-2 border rounded text-sm" />
+    }
+}
+
+/// One metric drawn as an A bar over a B bar, scaled to `scale`.
+fn paired_bars(
+    label: &'static str,
+    a: i64,
+    b: i64,
+    scale: i64,
+    increase_is_good: bool,
+    currency: &CurrencyFormat,
+) -> Html {
+    let bar = |amount: i64, color: &'static str| {
+        let width = format!("width: {}%", percent_of(amount.abs(), scale));
+        html! {
+            <div class="h-3 rounded bg-muted overflow-hidden">
+                <div class={classes!("h-full", "rounded", color)} style={width}></div>
+            </div>
+        }
+    };
+    html! {
+        <div class="space-y-1">
+            <div class="flex items-center justify-between text-sm">
+                <span class="font-semibold text-foreground">{ label }</span>
+                <span class="flex items-center gap-2 text-xs">
+                    { delta_indicator(a - b, increase_is_good, currency) }
+                    <span class="text-muted-foreground">{ format!("{} change", format_currency((a - b).abs(), currency)) }</span>
+                </span>
+            </div>
+            <div class="grid grid-cols-[1.5rem_1fr_auto] items-center gap-2 text-xs">
+                <span class="font-bold text-muted-foreground">{"A"}</span>
+                { bar(a, "bg-primary") }
+                <span class="text-foreground"><CurrencyAmount amount={a} currency={currency.clone()} /></span>
+                <span class="font-bold text-muted-foreground">{"B"}</span>
+                { bar(b, "bg-secondary") }
+                <span class="text-foreground"><CurrencyAmount amount={b} currency={currency.clone()} /></span>
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct RangeComparisonProps {
+    transactions: Vec<Transaction>,
+    currency: CurrencyFormat,
+}
+
+/// Compares two date ranges the user picks, side by side and per category.
+#[function_component(RangeComparison)]
+fn range_comparison(props: &RangeComparisonProps) -> Html {
+    // opens on the last 30 days against the 30 before them
+    let today = day_number(&today_key()).unwrap_or_default();
+    let a_start = use_state(|| date_key_from_days(today - COMPARE_RANGE_DAYS + 1));
+    let a_end = use_state(|| date_key_from_days(today));
+    let b_start = use_state(|| date_key_from_days(today - 2 * COMPARE_RANGE_DAYS + 1));
+    let b_end = use_state(|| date_key_from_days(today - COMPARE_RANGE_DAYS));
+    let currency = &props.currency;
+
+    let a = range_totals(&props.transactions, &a_start, &a_end);
+    let b = range_totals(&props.transactions, &b_start, &b_end);
+
+    html! {
+        <div class="bg-card rounded-lg p-6 border border-border">
+            <div class="flex items-center justify-between mb-4">
+                <h3 class="text-lg font-bold text-foreground">{"Compare Ranges"}</h3>
+                <span class="text-xs text-muted-foreground">{"Change = A minus B"}</span>
+            </div>
+            <div class="grid grid-cols-1 md:grid-cols-2 gap-4 mb-4">
+                <div class="flex flex-wrap items-center gap-2">
+                    <span class="w-4 h-4 rounded bg-primary"></span>
+                    <span class="text-sm font-bold text-foreground">{"Range A"}</span>
+                    { range_date_input("Range A start", &a_start) }
+                    <span class="text-muted-foreground text-xs">{"–"}</span>
+                    { range_date_input("Range A end", &a_end) }
+                </div>
+                <div class="flex flex-wrap items-center gap-2">
+                    <span class="w-4 h-4 rounded bg-secondary"></span>
+                    <span class="text-sm font-bold text-foreground">{"Range B"}</span>
+                    { range_date_input("Range B start", &b_start) }
+                    <span class="text-muted-foreground text-xs">{"–"}</span>
+                    { range_date_input("Range B end", &b_end) }
+                </div>
+            </div>
+            {
+                match (a, b) {
+                    (Some(a), Some(b)) => {
+                        let net_a = a.income - a.expenses;
+                        let net_b = b.income - b.expenses;
+                        let scale = [a.income, b.income, a.expenses, b.expenses, net_a.abs(), net_b.abs()]
+                            .into_iter()
+                            .max()
+                            .unwrap_or_default();
+                        let rows = category_deltas(&a.by_category, &b.by_category);
+                        html! {
+                            <>
+                                if a.days != b.days {
+                                    <p class="text-xs text-muted-foreground mb-4">
+                                        { format!("Range A covers {} days and Range B covers {}; totals are not adjusted for length.", a.days, b.days) }
+                                    </p>
+                                }
+                                <div class="space-y-4 mb-6">
+                                    { paired_bars("Income", a.income, b.income, scale, true, currency) }
+                                    { paired_bars("Expenses", a.expenses, b.expenses, scale, false, currency) }
+                                    { paired_bars("Net", net_a, net_b, scale, true, currency) }
+                                </div>
+                                if rows.is_empty() {
+                                    <p class="text-sm text-muted-foreground">{"No spending in either range."}</p>
+                                } else {
+                                    <table class="w-full text-sm">
+                                        <thead>
+                                            <tr class="text-left text-muted-foreground border-b border-border">
+                                                <th class="py-2 font-semibold">{"Category"}</th>
+                                                <th class="py-2 font-semibold text-right">{"A"}</th>
+                                                <th class="py-2 font-semibold text-right">{"B"}</th>
+                                                <th class="py-2 font-semibold text-right">{"Change"}</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            { for rows.iter().map(|(label, amount_a, amount_b)| html! {
+                                                <tr class="border-b border-border last:border-0">
+                                                    <td class="py-2 text-foreground">{ label.clone() }</td>
+                                                    <td class="py-2 text-right"><CurrencyAmount amount={*amount_a} currency={currency.clone()} /></td>
+                                                    <td class="py-2 text-right"><CurrencyAmount amount={*amount_b} currency={currency.clone()} /></td>
+                                                    <td class="py-2 text-right">
+                                                        <span class="inline-flex items-center gap-1">
+                                                            { delta_indicator(amount_a - amount_b, false, currency) }
+                                                            { format_currency((amount_a - amount_b).abs(), currency) }
+                                                        </span>
+                                                    </td>
+                                                </tr>
+                                            }) }
+                                        </tbody>
+                                    </table>
+                                }
+                            </>
+                        }
+                    }
+                    _ => html! {
+                        <p class="text-sm text-muted-foreground">{"Pick a start date on or before the end date for both ranges."}</p>
+                    },
+                }
+            }
+        </div>
+    }
+}
+
+/// Number of weeks shown in the spending calendar (about a year).
+const HEATMAP_WEEKS: i64 = 53;
+
+const HEATMAP_SHADES: [&str; 5] = [
+    "bg-[#eef4f9]",
+    "bg-[#B2CBDE]",
+    "bg-[#6f9bbd]",
+    "bg-[#1D617A]",
+    "bg-[#173E63]",
+];
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Total spent per `YYYY-MM-DD`, ignoring income and transfers.
+fn daily_expenses(transactions: &[Transaction]) -> HashMap<String, i64> {
+    let mut totals: HashMap<String, i64> = HashMap::new();
+    for tx in transactions {
+        if tx.amount < 0 && !is_transfer(tx) {
+            if let Some(day) = tx.date.get(0..10) {
+                *totals.entry(day.to_string()).or_insert(0) += tx.amount.abs();
+            }
+        }
+    }
+    totals
+}
+
+/// GitHub-style calendar of daily spending, one column per week ending today.
+/// Days without expenses use the lightest shade.
+fn spending_heatmap(
+    transactions: &[Transaction],
+    year: Option<i32>,
+    selected_day: Option<&str>,
+    on_select_day: &Callback<String>,
+    bounds: PeriodBounds,
+    currency: &CurrencyFormat,
+) -> Html {
+    let totals = daily_expenses(transactions);
+    let max = totals.values().copied().max().unwrap_or(0);
+
+    // a past year ends on its Dec 31; otherwise the calendar ends today
+    let now = js_sys::Date::new_0();
+    let today = match year {
+        Some(y) if y != now.get_full_year() as i32 => days_from_civil(y as i64, 12, 31),
+        _ => days_from_civil(
+            now.get_full_year() as i64,
+            now.get_month() + 1,
+            now.get_date(),
+        ),
+    };
+    // columns start on the configured first day of the week
+    let start = week_start_of(today, bounds.week_start) - (HEATMAP_WEEKS - 1) * 7;
+
+    let month_names = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    html! {
+        <div class="overflow-x-auto">
+            <div class="inline-flex flex-col gap-1">
+                <div class="flex gap-[3px] text-[10px] text-muted-foreground h-4">
+                    { for (0..HEATMAP_WEEKS).map(|week| {
+                        let (_, month, day) = civil_from_days(start + week * 7);
+                        let label = if day <= 7 { month_names[(month - 1) as usize] } else { "" };
+                        html! { <span class="w-[11px] shrink-0 overflow-visible whitespace-nowrap">{ label }</span> }
+                    }) }
+                </div>
+                <div class="grid grid-rows-[repeat(7,11px)] grid-flow-col gap-[3px]">
+                    { for (0..HEATMAP_WEEKS * 7).map(|offset| {
+                        let days = start + offset;
+                        if days > today {
+                            return html! { <span class="w-[11px] h-[11px]"></span> };
+                        }
+                        let (year, month, day) = civil_from_days(days);
+                        let key = format!("{:04}-{:02}-{:02}", year, month, day);
+                        let spent = totals.get(&key).copied().unwrap_or(0);
+                        let level = if spent == 0 || max == 0 {
+                            0
+                        } else {
+                            ((spent as f64 / max as f64) * 4.0).ceil().clamp(1.0, 4.0) as usize
+                        };
+                        let is_selected = selected_day == Some(key.as_str());
+                        let title = format!("{}: {}", key, format_currency(spent, currency));
+                        let onclick = {
+                            let on_select_day = on_select_day.clone();
+                            let key = key.clone();
+                            Callback::from(move |_| on_select_day.emit(key.clone()))
+                        };
+                        html! {
+                            <button type="button" {onclick} title={title.clone()} aria-label={title}
+                                class={classes!("w-[11px]", "h-[11px]", "rounded-[2px]", HEATMAP_SHADES[level], is_selected.then_some("ring-2 ring-ring ring-offset-1"))}></button>
+                        }
+                    }) }
+                </div>
+                <div class="flex items-center justify-end gap-1 text-[10px] text-muted-foreground mt-1">
+                    <span class="mr-1">{"Less"}</span>
+                    { for HEATMAP_SHADES.iter().map(|shade| html! { <span class={classes!("w-[11px]", "h-[11px]", "rounded-[2px]", *shade)}></span> }) }
+                    <span class="ml-1">{"More"}</span>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(SettingsPage)]
+fn settings_page() -> Html {
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    // settings apply as they change, so this is the live app-wide format
+    let preview_currency = use_currency_format();
+    let notifications_blocked = use_state(|| {
+        notifications_supported()
+            && web_sys::Notification::permission() == web_sys::NotificationPermission::Denied
+    });
+    let accounts = use_state(load_accounts);
+    let account_currencies = use_state(load_account_currencies);
+    let new_account = use_state(|| "".to_string());
+    let account_error = use_state(|| None::<String>);
+    let currency_query = use_state(|| "".to_string());
+    let currency_open = use_state(|| false);
+    let custom_symbol = use_state(|| {
+        settings
+            .as_ref()
+            .filter(|s| s.currency_code == CUSTOM_CURRENCY)
+            .map(|s| s.currency_symbol.clone())
+            .unwrap_or_default()
+    });
+    let custom_error = use_state(|| None::<String>);
+    let summary = use_context::<SummaryContext>();
+    let reset_phrase = use_state(|| "".to_string());
+    let reset_confirm_open = use_state(|| false);
+    // (deleted, total) while the server-side deletions run
+    let reset_progress = use_state(|| None::<(usize, usize)>);
+    let reset_result = use_state(|| None::<Result<String, String>>);
+
+    let on_reset_confirm = {
+        let settings = settings.clone();
+        let accounts = accounts.clone();
+        let account_currencies = account_currencies.clone();
+        let custom_symbol = custom_symbol.clone();
+        let reset_phrase = reset_phrase.clone();
+        let reset_confirm_open = reset_confirm_open.clone();
+        let reset_progress = reset_progress.clone();
+        let reset_result = reset_result.clone();
+        Callback::from(move |_| {
+            reset_confirm_open.set(false);
+            reset_result.set(None);
+            reset_progress.set(Some((0, 0)));
+
+            let settings = settings.clone();
+            let accounts = accounts.clone();
+            let account_currencies = account_currencies.clone();
+            let custom_symbol = custom_symbol.clone();
+            let reset_phrase = reset_phrase.clone();
+            let reset_progress = reset_progress.clone();
+            let reset_result = reset_result.clone();
+            let summary = summary.clone();
+            spawn_local(async move {
+                let Some(list) = fetch_transactions(None).await.ok() else {
+                    reset_progress.set(None);
+                    reset_result.set(Some(Err(
+                        "Could not load your transactions. Nothing was deleted.".to_string(),
+                    )));
+                    return;
+                };
+
+                let ids: Vec<i32> = list.iter().filter_map(|tx| tx.id).collect();
+                let total = ids.len();
+                let mut deleted = 0;
+                reset_progress.set(Some((0, total)));
+                for id in ids {
+                    if delete_transaction(id).await {
+                        deleted += 1;
+                        reset_progress.set(Some((deleted, total)));
+                    }
+                }
+                reset_progress.set(None);
+                if let Some(summary) = &summary {
+                    summary.refresh();
+                }
+
+                // local data is only wiped once the server side is empty, so a
+                // failed run can simply be repeated
+                if deleted < total {
+                    reset_result.set(Some(Err(format!(
+                        "Could not delete {} of {} transactions. Local data was kept; try again.",
+                        total - deleted,
+                        total
+                    ))));
+                    return;
+                }
+
+                clear_local_user_data();
+                if let Some(settings) = settings.as_ref() {
+                    settings.set(default_settings());
+                }
+                accounts.set(load_accounts());
+                account_currencies.set(load_account_currencies());
+                custom_symbol.set("".to_string());
+                reset_phrase.set("".to_string());
+                reset_result.set(Some(Ok(format!(
+                    "Deleted {} transactions and reset budgets, goal and settings.",
+                    total
+                ))));
+            });
+        })
+    };
+
+    let current_currency = settings
+        .as_ref()
+        .map(|s| s.currency_code.clone())
+        .unwrap_or_else(|| "PHP".to_string());
+
+    let on_currency_pick = {
+        let settings = settings.clone();
+        let currency_query = currency_query.clone();
+        let currency_open = currency_open.clone();
+        Callback::from(move |code: String| {
+            if let Some(settings) = settings.as_ref() {
+                // Switching to Custom keeps the current symbol until a new one is applied.
+                let symbol = if code == CUSTOM_CURRENCY {
+                    settings.currency_symbol.clone()
+                } else {
+                    currency_symbol_for(&code)
+                };
+                let next = AppSettings {
+                    currency_code: code,
+                    symbol_spacing: default_symbol_spacing(&symbol),
+                    currency_symbol: symbol,
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+            currency_query.set("".to_string());
+            currency_open.set(false);
+        })
+    };
+
+    let on_currency_toggle = {
+        let currency_open = currency_open.clone();
+        Callback::from(move |_| currency_open.set(!*currency_open))
+    };
+
+    let on_currency_query = {
+        let currency_query = currency_query.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            currency_query.set(input.value());
+        })
+    };
+
+    let on_currency_query_key = {
+        let currency_query = currency_query.clone();
+        let currency_open = currency_open.clone();
+        let on_currency_pick = on_currency_pick.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "Enter" => {
+                e.prevent_default();
+                if let Some((code, _, _)) = currency_matches(&currency_query).first() {
+                    on_currency_pick.emit(code.to_string());
+                }
+            }
+            "Escape" => {
+                currency_query.set("".to_string());
+                currency_open.set(false);
+            }
+            _ => {}
+        })
+    };
+
+    let is_custom_currency = current_currency == CUSTOM_CURRENCY;
+    let currency_label = match currency_entry(&current_currency) {
+        Some((code, symbol, name)) => format!("{} — {} ({})", code, name, symbol),
+        None if is_custom_currency => format!(
+            "Custom ({})",
+            settings
+                .as_ref()
+                .map(|s| s.currency_symbol.clone())
+                .unwrap_or_default()
+        ),
+        None => current_currency.clone(),
+    };
+
+    let on_apply_custom_symbol = {
+        let settings = settings.clone();
+        let custom_symbol = custom_symbol.clone();
+        let custom_error = custom_error.clone();
+        Callback::from(move |_| match validate_custom_symbol(&custom_symbol) {
+            Ok(symbol) => {
+                if let Some(settings) = settings.as_ref() {
+                    let next = AppSettings {
+                        currency_code: CUSTOM_CURRENCY.to_string(),
+                        currency_symbol: symbol.clone(),
+                        symbol_spacing: default_symbol_spacing(&symbol),
+                        ..(**settings).clone()
+                    };
+                    save_settings(&next);
+                    settings.set(next);
+                }
+                custom_symbol.set(symbol);
+                custom_error.set(None);
+            }
+            Err(msg) => custom_error.set(Some(msg)),
+        })
+    };
+    let currency_results = currency_matches(&currency_query);
+
+    let current_decimals = settings.as_ref().map(|s| s.decimal_places).unwrap_or(2);
+    let decimals_locked = is_zero_decimal_currency(&current_currency);
+
+    let on_decimals_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    decimal_places: if input.value() == "0" { 0 } else { 2 },
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let current_grouping = settings.as_ref().map(|s| s.grouping).unwrap_or_default();
+    let current_bounds =
+        PeriodBounds::from_settings(&settings.as_ref().map(|s| (**s).clone()).unwrap_or_default());
+
+    let current_default_page = settings
+        .as_ref()
+        .map(|s| s.default_page)
+        .unwrap_or_default();
+    let on_default_page_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let page = input
+                    .value()
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|idx| SHORTCUT_PAGES.get(idx))
+                    .map(|(_, _, page)| *page)
+                    .unwrap_or_default();
+                let next = AppSettings {
+                    default_page: page,
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_week_start_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    week_start: input.value().parse().unwrap_or(0),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_month_start_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    month_start_day: input.value().parse().unwrap_or(1),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let current_timeout = settings
+        .as_ref()
+        .map(|s| s.request_timeout_secs)
+        .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS);
+    let on_timeout_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    request_timeout_secs: input
+                        .value()
+                        .parse()
+                        .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_grouping_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    grouping: GroupingStyle::from_key(&input.value()),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let current_negative_style = settings
+        .as_ref()
+        .map(|s| s.negative_style)
+        .unwrap_or_default();
+    let current_rounding = settings
+        .as_ref()
+        .map(|s| s.rounding_mode)
+        .unwrap_or_default();
+
+    let current_amount_entry = settings
+        .as_ref()
+        .map(|s| s.amount_entry)
+        .unwrap_or_default();
+
+    let on_amount_entry_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    amount_entry: AmountEntry::from_key(&input.value()),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_rounding_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    rounding_mode: RoundingMode::from_key(&input.value()),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_negative_style_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    negative_style: NegativeStyle::from_key(&input.value()),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let current_spacing = settings.as_ref().is_some_and(|s| s.symbol_spacing);
+
+    let current_sign_cue = settings.as_ref().map(|s| s.sign_cue).unwrap_or_default();
+    let on_sign_cue_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    sign_cue: SignCue::from_key(&input.value()),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let current_sign_palette = settings
+        .as_ref()
+        .map(|s| s.sign_palette)
+        .unwrap_or_default();
+    let on_sign_palette_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    sign_palette: SignPalette::from_key(&input.value()),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_spacing_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let next = AppSettings {
+                    symbol_spacing: input.checked(),
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let current_budget_alerts = settings.as_ref().is_none_or(|s| s.budget_alerts);
+
+    let current_savings_as_spending = settings.as_ref().is_none_or(|s| s.savings_as_spending);
+
+    let on_savings_as_spending_toggle = {
+        let settings = settings.clone();
+        Callback::from(move |_| {
+            let Some(settings) = settings.as_ref() else {
+                return;
+            };
+            let next = AppSettings {
+                savings_as_spending: !settings.savings_as_spending,
+                ..(**settings).clone()
+            };
+            save_settings(&next);
+            settings.set(next);
+        })
+    };
+
+    let current_hide_zero_spend = settings.as_ref().is_some_and(|s| s.hide_zero_spend);
+
+    let current_monthly_report = settings.as_ref().is_none_or(|s| s.monthly_report);
+
+    let current_confirm_on_leave = settings.as_ref().is_none_or(|s| s.confirm_on_leave);
+
+    let on_confirm_on_leave_toggle = {
+        let settings = settings.clone();
+        Callback::from(move |_| {
+            let Some(settings) = settings.as_ref() else {
+                return;
+            };
+            let next = AppSettings {
+                confirm_on_leave: !settings.confirm_on_leave,
+                ..(**settings).clone()
+            };
+            save_settings(&next);
+            settings.set(next);
+        })
+    };
+
+    let on_monthly_report_toggle = {
+        let settings = settings.clone();
+        Callback::from(move |_| {
+            let Some(settings) = settings.as_ref() else {
+                return;
+            };
+            let next = AppSettings {
+                monthly_report: !settings.monthly_report,
+                ..(**settings).clone()
+            };
+            save_settings(&next);
+            settings.set(next);
+        })
+    };
+
+    let on_hide_zero_spend_toggle = {
+        let settings = settings.clone();
+        Callback::from(move |_| {
+            let Some(settings) = settings.as_ref() else {
+                return;
+            };
+            let next = AppSettings {
+                hide_zero_spend: !settings.hide_zero_spend,
+                ..(**settings).clone()
+            };
+            save_settings(&next);
+            settings.set(next);
+        })
+    };
+
+    let on_budget_alerts_toggle = {
+        let settings = settings.clone();
+        let notifications_blocked = notifications_blocked.clone();
+        Callback::from(move |_| {
+            let Some(settings) = settings.as_ref() else {
+                return;
+            };
+            let enabled = !settings.budget_alerts;
+            let next = AppSettings {
+                budget_alerts: enabled,
+                ..(**settings).clone()
+            };
+            save_settings(&next);
+            settings.set(next);
+            if enabled {
+                request_notification_permission(&notifications_blocked);
+            }
+        })
+    };
+
+    let on_saving_alerts_toggle = {
+        let settings = settings.clone();
+        let notifications_blocked = notifications_blocked.clone();
+        Callback::from(move |_| {
+            let Some(settings) = settings.as_ref() else {
+                return;
+            };
+            let enabled = !settings.saving_alerts;
+            let next = AppSettings {
+                saving_alerts: enabled,
+                ..(**settings).clone()
+            };
+            save_settings(&next);
+            settings.set(next);
+            if enabled {
+                request_notification_permission(&notifications_blocked);
+            }
+        })
+    };
+    let current_saving_alerts = settings.as_ref().map(|s| s.saving_alerts).unwrap_or(true);
+
+    let current_auto_save = settings.as_ref().map(|s| s.auto_save_percent).unwrap_or(0);
+
+    let current_round_up = settings.as_ref().is_some_and(|s| s.round_up_enabled);
+    let current_round_up_increment = settings
+        .as_ref()
+        .map(|s| s.round_up_increment)
+        .unwrap_or(10);
+    let round_up_saved: i64 = load_saving_goal()
+        .contributions
+        .iter()
+        .filter(|c| c.round_up)
+        .map(|c| c.amount)
+        .sum();
+
+    let on_round_up_toggle = {
+        let settings = settings.clone();
+        Callback::from(move |_| {
+            let Some(settings) = settings.as_ref() else {
+                return;
+            };
+            let next = AppSettings {
+                round_up_enabled: !settings.round_up_enabled,
+                ..(**settings).clone()
+            };
+            save_settings(&next);
+            settings.set(next);
+        })
+    };
+
+    let on_round_up_increment_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let increment = input.value().trim().parse::<i64>().unwrap_or(0);
+                let next = AppSettings {
+                    round_up_increment: if increment > 0 { increment } else { 10 },
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_auto_save_change = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            if let Some(settings) = settings.as_ref() {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let percent = input.value().trim().parse::<u32>().unwrap_or(0).min(100);
+                let next = AppSettings {
+                    auto_save_percent: percent,
+                    ..(**settings).clone()
+                };
+                save_settings(&next);
+                settings.set(next);
+            }
+        })
+    };
+
+    let on_add_account = {
+        let accounts = accounts.clone();
+        let new_account = new_account.clone();
+        let account_error = account_error.clone();
+        Callback::from(move |_| {
+            let name = new_account.trim().to_string();
+            if name.is_empty() {
+                account_error.set(Some("Enter an account name.".to_string()));
+                return;
+            }
+            if accounts.iter().any(|a| a.eq_ignore_ascii_case(&name)) {
+                account_error.set(Some("That account already exists.".to_string()));
+                return;
+            }
+            let mut next = (*accounts).clone();
+            next.push(name);
+            save_accounts(&next);
+            accounts.set(next);
+            new_account.set("".to_string());
+            account_error.set(None);
+        })
+    };
+
+    html! {
+        { page_shell(
+            "Settings",
+            html! {},
+            html! {
+                <>
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
+                        <div class="bg-card rounded-lg p-6 border border-border">
+                            <h2 class="text-xl font-bold text-foreground mb-6">{"Preferences"}</h2>
+                            <div class="space-y-4">
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Currency"}</label>
+                                    <div class="relative">
+                                        <button type="button" onclick={on_currency_toggle} aria-haspopup="listbox" aria-expanded={(*currency_open).to_string()}
+                                            class="w-full flex items-center justify-between px-4 py-2 bg-input border border-input rounded-lg text-foreground text-left focus:outline-none focus:ring-2 focus:ring-primary">
+                                            <span>{ currency_label }</span>
+                                            <span class="text-muted-foreground">{ if *currency_open { "▴" } else { "▾" } }</span>
+                                        </button>
+                                        { if *currency_open {
+                                            html! {
+                                                <div class="absolute z-20 mt-1 w-full bg-card border border-border rounded-lg shadow-lg">
+                                                    <input type="text" placeholder="Search by code or name" autofocus=true
+                                                        value={(*currency_query).clone()} oninput={on_currency_query} onkeydown={on_currency_query_key}
+                                                        class="w-full px-4 py-2 border-b border-border bg-input text-foreground rounded-t-lg focus:outline-none" />
+                                                    <ul role="listbox" class="max-h-60 overflow-y-auto py-1">
+                                                        { if currency_results.is_empty() {
+                                                            html! { <li class="px-4 py-2 text-sm text-muted-foreground">{"No matching currencies"}</li> }
+                                                        } else {
+                                                            html! { for currency_results.iter().map(|(code, symbol, name)| {
+                                                                let selected = *code == current_currency;
+                                                                let onclick = {
+                                                                    let on_currency_pick = on_currency_pick.clone();
+                                                                    let code = code.to_string();
+                                                                    Callback::from(move |_| on_currency_pick.emit(code.clone()))
+                                                                };
+                                                                html! {
+                                                                    <li role="option" aria-selected={selected.to_string()}>
+                                                                        <button type="button" {onclick}
+                                                                            class={classes!("w-full", "flex", "justify-between", "px-4", "py-2", "text-sm", "text-left", "hover:bg-muted", selected.then_some("bg-muted font-semibold"))}>
+                                                                            <span>{ format!("{} — {}", code, name) }</span>
+                                                                            <span class="text-muted-foreground">{ *symbol }</span>
+                                                                        </button>
+                                                                    </li>
+                                                                }
+                                                            }) }
+                                                        } }
+                                                        <li role="option" aria-selected={is_custom_currency.to_string()} class="border-t border-border">
+                                                            <button type="button" onclick={{
+                                                                let on_currency_pick = on_currency_pick.clone();
+                                                                Callback::from(move |_| on_currency_pick.emit(CUSTOM_CURRENCY.to_string()))
+                                                            }}
+                                                                class={classes!("w-full", "px-4", "py-2", "text-sm", "text-left", "hover:bg-muted", is_custom_currency.then_some("bg-muted font-semibold"))}>
+                                                                {"Custom symbol…"}
+                                                            </button>
+                                                        </li>
+                                                    </ul>
+                                                </div>
+                                            }
+                                        } else {
+                                            html! {}
+                                        } }
                                     </div>
-                                    <div class="space-y-3">
-                                        <div>
-                                            <p class="text-[12px] font-bold text-slate-400 mb-0.5 tracking-widest">{"Amount Saved"}</p>
-                                            <p class="text-lg font-black text-[#1D617A] leading-none">{ format_currency(saved_so_far, &currency_symbol) }</p>
-                                        </div>
-                                        <div>
-                                            <p class="text-[10px] font-bold text-slate-400 mb-0.5 tracking-widest">{"Goal Target"}</p>
-                                            <p class="text-sm font-black text-[#173E63]/70 leading-none">{ format_currency(goal.target_amount, &currency_symbol) }</p>
+                                    if is_custom_currency {
+                                        <div class="flex gap-2 mt-2">
+                                            <input type="text" placeholder="e.g. Rp, zł, BTC" maxlength="8" value={(*custom_symbol).clone()} oninput={{
+                                                let custom_symbol = custom_symbol.clone();
+                                                Callback::from(move |e: InputEvent| {
+                                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                                    custom_symbol.set(input.value());
+                                                })
+                                            }} class="flex-1 px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary" />
+                                            <button type="button" onclick={on_apply_custom_symbol} class="px-4 py-2 rounded-lg bg-primary text-primary-foreground text-sm font-bold">{"Apply"}</button>
                                         </div>
-                                    </div>
-                                </div>
-                                {
-                                    if goal.target_amount > 0 && saved_so_far >= goal.target_amount {
-                                        html! {
-                                            <div class="mt-4 p-3 rounded-lg bg-green-50 border border-green-200 text-green-700 text-xs font-bold">
-                                                {"Goal reached! You can set a new goal or keep saving."}
-                                            </div>
+                                        if let Some(msg) = &*custom_error {
+                                            <p class="text-sm text-red-500 mt-2">{ msg.clone() }</p>
                                         }
-                                    } else {
-                                        html! {}
                                     }
-                                }
+                                    <p class="text-xs text-muted-foreground mt-2">{"Currency updates are applied across the dashboard and reports."}</p>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Decimal places"}</label>
+                                    <select onchange={on_decimals_change} disabled={decimals_locked} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        <option value="2" selected={!decimals_locked && current_decimals == 2}>{"2 (1,234.00)"}</option>
+                                        <option value="0" selected={decimals_locked || current_decimals == 0}>{"0 (1,234)"}</option>
+                                    </select>
+                                    if decimals_locked {
+                                        <p class="text-xs text-muted-foreground mt-2">{"This currency has no minor unit, so amounts are shown without decimals."}</p>
+                                    }
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Number format"}</label>
+                                    <select onchange={on_grouping_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for GroupingStyle::ALL.into_iter().map(|style| html! {
+                                            <option value={style.key()} selected={style == current_grouping}>{ style.label() }</option>
+                                        }) }
+                                    </select>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Negative amounts"}</label>
+                                    <select onchange={on_negative_style_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for NegativeStyle::ALL.into_iter().map(|style| html! {
+                                            <option value={style.key()} selected={style == current_negative_style}>{ style.label() }</option>
+                                        }) }
+                                    </select>
+                                    <label class="flex items-center gap-2 mt-3 text-sm text-foreground">
+                                        <input type="checkbox" checked={current_spacing} onchange={on_spacing_change} class="w-4 h-4" />
+                                        {"Space between symbol and amount"}
+                                    </label>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Show positive and negative with"}</label>
+                                    <select onchange={on_sign_cue_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for SignCue::ALL.into_iter().map(|cue| html! {
+                                            <option value={cue.key()} selected={cue == current_sign_cue}>{ cue.label() }</option>
+                                        }) }
+                                    </select>
+                                    <label class="block text-sm font-medium text-foreground mt-3 mb-2">{"Colours"}</label>
+                                    <select onchange={on_sign_palette_change} disabled={!current_sign_cue.shows_color()} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary disabled:opacity-50">
+                                        { for SignPalette::ALL.into_iter().map(|palette| html! {
+                                            <option value={palette.key()} selected={palette == current_sign_palette}>{ palette.label() }</option>
+                                        }) }
+                                    </select>
+                                    <p class="text-xs text-muted-foreground mt-2">{"Symbols work without relying on colour. The blue and orange palette stays distinct for red-green colour blindness."}</p>
+                                </div>
+                                <div class="rounded-lg border border-border bg-muted/40 px-4 py-3" aria-live="polite">
+                                    <p class="text-xs font-bold text-muted-foreground tracking-widest mb-1">{"Preview"}</p>
+                                    <p class="flex flex-wrap gap-x-6 gap-y-1 text-sm font-semibold">
+                                        <CurrencyAmount amount={-1_234_567} currency={preview_currency.clone()} />
+                                        <CurrencyAmount amount={1_234} currency={preview_currency.clone()} show_plus=true />
+                                    </p>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Rounding"}</label>
+                                    <select onchange={on_rounding_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for RoundingMode::ALL.into_iter().map(|mode| html! {
+                                            <option value={mode.key()} selected={mode == current_rounding}>{ mode.label() }</option>
+                                        }) }
+                                    </select>
+                                    <p class="text-xs text-muted-foreground mt-2">{"Applies to computed amounts such as auto-save shares, suggested budgets and goal pace."}</p>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Amount entry"}</label>
+                                    <select onchange={on_amount_entry_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for AmountEntry::ALL.into_iter().map(|entry| html! {
+                                            <option value={entry.key()} selected={entry == current_amount_entry}>{ entry.label() }</option>
+                                        }) }
+                                    </select>
+                                    <p class="text-xs text-muted-foreground mt-2">{"With cents, type whole minor units and the decimal point is placed for you. Amounts are saved in whole units, so cents must make a whole unit. Currencies without decimals read the same either way."}</p>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Landing page"}</label>
+                                    <select onchange={on_default_page_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for SHORTCUT_PAGES.iter().enumerate().map(|(idx, (_, label, page))| html! {
+                                            <option value={idx.to_string()} selected={*page == current_default_page}>{ *label }</option>
+                                        }) }
+                                    </select>
+                                    <p class="text-xs text-muted-foreground mt-2">{"The page PondoBro opens on when you start the app."}</p>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Week starts on"}</label>
+                                    <select onchange={on_week_start_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for WEEKDAY_NAMES.iter().enumerate().map(|(idx, name)| html! {
+                                            <option value={idx.to_string()} selected={idx as u32 == current_bounds.week_start}>{ *name }</option>
+                                        }) }
+                                    </select>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Month starts on day"}</label>
+                                    <select onchange={on_month_start_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for (1..=31u32).map(|day| html! {
+                                            <option value={day.to_string()} selected={day == current_bounds.month_start_day}>
+                                                { if day == 1 { "1 (calendar month)".to_string() } else { day.to_string() } }
+                                            </option>
+                                        }) }
+                                    </select>
+                                    <p class="text-xs text-muted-foreground mt-2">{"Set this to your payday to budget by pay cycle. In shorter months a later day falls on the last day of the month."}</p>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Auto-save to goal (% of income)"}</label>
+                                    <input type="number" min="0" max="100" value={current_auto_save.to_string()} onchange={on_auto_save_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary" />
+                                    <p class="text-xs text-muted-foreground mt-2">{"When set, adding income offers to move this share into your saving goal. Use 0 to turn it off."}</p>
+                                </div>
+                                <div>
+                                    <label class="flex items-center gap-2 text-sm font-medium text-foreground">
+                                        <input type="checkbox" checked={current_round_up} onclick={on_round_up_toggle} />
+                                        {"Round up expenses to savings"}
+                                    </label>
+                                    <div class="flex items-center gap-2 mt-2">
+                                        <span class="text-sm text-muted-foreground">{ format!("Round up to the nearest {}", preview_currency.symbol) }</span>
+                                        <input type="number" min="1" step="1" value={current_round_up_increment.to_string()} disabled={!current_round_up} onchange={on_round_up_increment_change} aria-label="Round-up increment" class="w-24 px-3 py-1 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary disabled:opacity-50" />
+                                    </div>
+                                    <p class="text-xs text-muted-foreground mt-2">{ format!("Each new expense is rounded up to this step and the difference goes to your saving goal as a contribution, e.g. {} with a step of 10 saves {}. It posts a matching Savings expense like any other contribution. Nothing is saved without a goal.", format_currency(123, &preview_currency), format_currency(7, &preview_currency)) }</p>
+                                    if round_up_saved > 0 {
+                                        <p class="text-xs text-primary font-semibold mt-1">{ format!("Saved from round-ups so far: {}", format_currency(round_up_saved, &preview_currency)) }</p>
+                                    }
+                                </div>
+                                <div>
+                                    <label class="flex items-center gap-2 text-sm font-medium text-foreground">
+                                        <input type="checkbox" checked={current_savings_as_spending} onclick={on_savings_as_spending_toggle} />
+                                        {"Count savings as spending"}
+                                    </label>
+                                    <p class="text-xs text-muted-foreground mt-2">{"Goal contributions post as Savings expenses. Turn this off to leave them out of expense totals, the breakdown and budgets."}</p>
+                                </div>
+                                <div>
+                                    <label class="flex items-center gap-2 text-sm font-medium text-foreground">
+                                        <input type="checkbox" checked={current_hide_zero_spend} onclick={on_hide_zero_spend_toggle} />
+                                        {"Hide budgets with no spend"}
+                                    </label>
+                                    <p class="text-xs text-muted-foreground mt-2">{"Leaves empty 0% bars out of the Dashboard budget card and the Budget page. They stay listed under Category Budgets so their limits can still be edited."}</p>
+                                </div>
+                                <div>
+                                    <label class="flex items-center gap-2 text-sm font-medium text-foreground">
+                                        <input type="checkbox" checked={current_confirm_on_leave} onclick={on_confirm_on_leave_toggle} />
+                                        {"Confirm before leaving unsaved forms"}
+                                    </label>
+                                    <p class="text-xs text-muted-foreground mt-2">{"Asks before switching pages while the add-transaction, budget, new goal or contribution form has input that hasn't been saved."}</p>
+                                </div>
+                                <div>
+                                    <label class="block text-sm font-medium text-foreground mb-2">{"Server timeout"}</label>
+                                    <select onchange={on_timeout_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
+                                        { for REQUEST_TIMEOUT_CHOICES.into_iter().map(|secs| html! {
+                                            <option value={secs.to_string()} selected={secs == current_timeout}>{ format!("{} seconds", secs) }</option>
+                                        }) }
+                                    </select>
+                                    <p class="text-xs text-muted-foreground mt-2">{"How long to wait for the server before showing an error. Raise it on slow connections."}</p>
+                                </div>
                             </div>
-                        }
-                    } else {
-                        html! {
-                            <div class="flex flex-col h-full space-y-3">
-                                <h4 class="text-[#173E63] font-black text-[13px] mb-2 uppercase tracking-wide border-b border-slate-50 pb-2 text-center">{"Setup New Goal"}</h4>
-                                <div class="flex-grow space-y-3">
-                                    <div class="space-y-1">
-                                        <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Goal Name"}</label>
-                                        <input type="text" value={(*new_goal_title).clone()} oninput={{
-                                            let new_goal_title = new_goal_title.clone();
-                                            Callback::from(move |e: InputEvent| {
-                                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                                new_goal_title.set(input.value());
-                                            })
-                                        }} class="w-full bg-[#f1f4f9] border-none rounded-xl p-2.5 text-xs font-bold text-[#173E63] outline-none" placeholder="e.g. Dream Wedding" />
+                        </div>
+
+                        <div class="bg-card rounded-lg p-6 border border-border">
+                            <h2 class="text-xl font-bold text-foreground mb-6">{"Notifications"}</h2>
+                            <div class="space-y-4">
+                                <div class="flex items-start gap-3 pb-4 border-b border-border">
+                                    <div class="flex-1 pt-1">
+                                        <p class="font-medium text-foreground">{"Budget Alerts"}</p>
+                                        <p class="text-sm text-muted-foreground">{"Get notified when expenses exceed your plan"}</p>
                                     </div>
-                                    <div class="grid grid-cols-2 gap-3">
-                                        <div class="space-y-1">
-                                            <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{ format!("Amount ({})", currency_symbol) }</label>
-                                            <input type="number" value={(*new_goal_amount).clone()} oninput={{
-                                                let new_goal_amount = new_goal_amount.clone();
-                                                Callback::from(move |e: InputEvent| {
-                                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                                    new_goal_amount.set(input.value());
-                                                })
-                                            }} class="w-full bg-[#f1f4f9] border-none rounded-xl p-2.5 text-xs font-bold text-[#173E63] outline-none" placeholder="0.00" />
-                                        </div>
-                                        <div class="space-y-1">
-                                            <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Target Date"}</label>
-                                            <input type="date" value={(*new_goal_date).clone()} oninput={{
-                                                let new_goal_date = new_goal_date.clone();
-                                                Callback::from(move |e: InputEvent| {
-                                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                                    new_goal_date.set(input.value());
-                                                })
-                                            }} class="w-full bg-[#f1f4f9] border-none rounded-xl p-2.5 text-xs font-bold text-[#173E63] outline-none" />
-                                        </div>
+                                    <input type="checkbox" checked={current_budget_alerts} onclick={on_budget_alerts_toggle} />
+                                </div>
+                                if current_budget_alerts && *notifications_blocked {
+                                    <p class="text-xs text-red-500 -mt-2">{"Notifications are blocked for this site. Allow them in your browser settings to receive budget alerts."}</p>
+                                }
+                                <div class="flex items-start gap-3 pb-4 border-b border-border">
+                                    <div class="flex-1 pt-1">
+                                        <p class="font-medium text-foreground">{"Monthly Report"}</p>
+                                        <p class="text-sm text-muted-foreground">{"Show last month's summary on the Dashboard when a new month starts"}</p>
+                                    </div>
+                                    <input type="checkbox" checked={current_monthly_report} onclick={on_monthly_report_toggle} />
+                                </div>
+                                <div class="flex items-start gap-3">
+                                    <div class="flex-1 pt-1">
+                                        <p class="font-medium text-foreground">{"Saving Alert"}</p>
+                                        <p class="text-sm text-muted-foreground">{"Get reminded when a goal contribution is due"}</p>
                                     </div>
+                                    <input type="checkbox" checked={current_saving_alerts} onclick={on_saving_alerts_toggle} />
                                 </div>
-                                <button onclick={create_goal} class="w-full bg-[#1D617A] text-white py-2.5 rounded-xl text-[10px] font-black uppercase flex items-center justify-center gap-2 shadow-md">{"Start New Goal"}</button>
+                                if current_saving_alerts && !current_budget_alerts && *notifications_blocked {
+                                    <p class="text-xs text-red-500 -mt-2">{"Notifications are blocked for this site. Allow them in your browser settings to receive saving reminders."}</p>
+                                }
                             </div>
-                        }
-                    }}
-                </div>
+                        </div>
 
-                <div class="lg:col-span-7 bg-white p-6 rounded-2xl shadow-md border border-border flex flex-col h-full">
-                    <h4 class="text-[#1D617A] font-bold text-[13px] mb-6 uppercase tracking-widest border-b border-slate-50 pb-2">{"Add Contribution"}</h4>
-                    <div class="flex-grow space-y-5">
-                        <div class="grid grid-cols-2 gap-4">
-                            <div class="space-y-1.5">
-                                <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Date"}</label>
-                                <input type="date" value={(*contrib_date).clone()} oninput={{
-                                    let contrib_date = contrib_date.clone();
+                        <div class="bg-card rounded-lg p-6 border border-border">
+                            <h2 class="text-xl font-bold text-foreground mb-6">{"Accounts"}</h2>
+                            <ul class="space-y-2 mb-4">
+                                { for accounts.iter().map(|account| {
+                                    let on_remove = {
+                                        let accounts = accounts.clone();
+                                        let account_currencies = account_currencies.clone();
+                                        let account = account.clone();
+                                        Callback::from(move |_| {
+                                            let next = accounts
+                                                .iter()
+                                                .filter(|a| **a != account)
+                                                .cloned()
+                                                .collect::<Vec<_>>();
+                                            save_accounts(&next);
+                                            accounts.set(next);
+                                            let mut currencies = (*account_currencies).clone();
+                                            if currencies.remove(&account).is_some() {
+                                                save_account_currencies(&currencies);
+                                                account_currencies.set(currencies);
+                                            }
+                                        })
+                                    };
+                                    let on_currency = {
+                                        let account_currencies = account_currencies.clone();
+                                        let account = account.clone();
+                                        let app_code = preview_currency.code.clone();
+                                        Callback::from(move |e: Event| {
+                                            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                            let mut currencies = (*account_currencies).clone();
+                                            // the app currency is the default, so it isn't stored
+                                            match stored_currency(&input.value(), &app_code) {
+                                                Some(code) => currencies.insert(account.clone(), code),
+                                                None => currencies.remove(&account),
+                                            };
+                                            save_account_currencies(&currencies);
+                                            account_currencies.set(currencies);
+                                        })
+                                    };
+                                    let code = account_currency(&account_currencies, account, &preview_currency.code);
+                                    html! {
+                                        <li key={account.clone()} class="flex items-center justify-between gap-3 text-sm">
+                                            <span class="flex-1 text-foreground">{ account.clone() }</span>
+                                            <select onchange={on_currency} aria-label={format!("Currency for {}", account)} class="p-1 border rounded text-xs">
+                                                { currency_code_options(&code) }
+                                            </select>
+                                            if account != DEFAULT_ACCOUNT {
+                                                <button type="button" onclick={on_remove} class="text-xs text-red-600 hover:underline">{"Remove"}</button>
+                                            }
+                                        </li>
+                                    }
+                                }) }
+                            </ul>
+                            <div class="flex gap-2">
+                                <input type="text" placeholder="e.g. Cash, Bank, GCash" value={(*new_account).clone()} oninput={{
+                                    let new_account = new_account.clone();
                                     Callback::from(move |e: InputEvent| {
                                         let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                        contrib_date.set(input.value());
+                                        new_account.set(input.value());
                                     })
-                                }} class="w-full bg-[#f1f4f9] border-none rounded-xl p-3 text-xs font-bold text-[#173E63] transition-all" />
+                                }} class="flex-1 px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary" />
+                                <button type="button" onclick={on_add_account} class="px-4 py-2 rounded-lg bg-primary text-primary-foreground text-sm font-bold">{"Add"}</button>
                             </div>
-                            <div class="space-y-1.5">
-                                <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{ format!("Amount ({})", currency_symbol) }</label>
-                                <input type="number" placeholder={format!("{} 0.00", currency_symbol)} value={(*contrib_amount).clone()} oninput={{
-                                    let contrib_amount = contrib_amount.clone();
+                            if let Some(msg) = &*account_error {
+                                <p class="text-sm text-red-500 mt-2">{ msg.clone() }</p>
+                            }
+                            <p class="text-xs text-muted-foreground mt-2">{"Transactions without an account are shown under \"Default\"."}</p>
+                        </div>
+
+                        <CategoryManager />
+
+                        <TransactionImport />
+
+                        <div class="bg-card rounded-lg p-6 border border-red-300">
+                            <h2 class="text-xl font-bold text-red-700 mb-2">{"Danger zone"}</h2>
+                            <p class="text-sm text-muted-foreground mb-4">{ format!("Permanently deletes all of your transactions and resets budgets, the saving goal and these settings. Type {} to continue.", RESET_CONFIRM_PHRASE) }</p>
+                            <div class="flex gap-2">
+                                <input type="text" aria-label={format!("Type {} to confirm", RESET_CONFIRM_PHRASE)} placeholder={RESET_CONFIRM_PHRASE} value={(*reset_phrase).clone()} oninput={{
+                                    let reset_phrase = reset_phrase.clone();
                                     Callback::from(move |e: InputEvent| {
                                         let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                        contrib_amount.set(input.value());
+                                        reset_phrase.set(input.value());
                                     })
-                                }} class="w-full bg-[#f1f4f9] border-none rounded-xl p-3 text-xs font-bold text-[#173E63] transition-all" />
+                                }} class="flex-1 px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-red-500" />
+                                <button type="button" onclick={{
+                                    let reset_confirm_open = reset_confirm_open.clone();
+                                    Callback::from(move |_| reset_confirm_open.set(true))
+                                }} disabled={reset_phrase.trim() != RESET_CONFIRM_PHRASE || reset_progress.is_some()} class="px-4 py-2 rounded-lg bg-red-600 text-white text-sm font-bold disabled:opacity-50">{"Delete all my data"}</button>
                             </div>
-                        </div>
-                        <div class="space-y-1.5">
-                            <label class="text-[10px] font-bold text-slate-400 uppercase tracking-widest">{"Description"}</label>
-                            <input type="text" placeholder="e.g. Monthly Savings" value={(*contrib_desc).clone()} oninput={{
-                                let contrib_desc = contrib_desc.clone();
-                                Callback::from(move |e: InputEvent| {
-                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                    contrib_desc.set(input.value());
-                                })
-                            }} class="w-full bg-[#f1f4f9] border-none rounded-xl p-3 text-xs font-bold text-[#173E63] transition-all" />
-                        </div>
-                    </div>
-                    <div class="flex gap-3 mt-8">
-                        <button onclick={add_contribution} class="flex-2 grow-[2] bg-[#173E63] text-white py-3 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2 shadow-md hover:translate-y-[-1px] transition-all">{"Add Contribution"}</button>
-                        <button onclick={clear_contribution} class="flex-1 bg-[#D8E1E8] text-[#173E63] py-3 rounded-[10px] text-[10px] font-bold flex items-center justify-center gap-2">{"Clear"}</button>
-                    </div>
-                </div>
-            </div>
-                    <div class="bg-white rounded-2xl shadow-md border border-border overflow-hidden">
-                        <div class="p-5 border-b border-border">
-                            <h3 class="font-bold text-foreground text-lg">{"Contribution History"}</h3>
-                        </div>
-                        <div class="overflow-x-auto">
-                            <table class="w-full text-left border-collapse">
-                                <thead>
-                                    <tr class="bg-muted text-muted-foreground text-[10px] uppercase tracking-widest">
-                                        <th class="px-8 py-4 font-bold">{"Date"}</th>
-                                        <th class="px-8 py-4 font-bold">{"Description"}</th>
-                                        <th class="px-8 py-4 font-bold text-right">{"Amount"}</th>
-                                    </tr>
-                                </thead>
-                                <tbody class="divide-y divide-border">
-                                    { for goal.contributions.iter().enumerate().map(|(idx, item)| html! {
-                                        <tr key={idx} class="text-sm hover:bg-muted/40 transition-colors">
-                                            <td class="px-8 py-4 text-muted-foreground">{ item.date.clone() }</td>
-                                            <td class="px-8 py-4 text-foreground">{ item.description.clone() }</td>
-                                            <td class="px-8 py-4 text-right font-semibold text-foreground">{ format_currency(item.amount, &currency_symbol) }</td>
-                                        </tr>
-                                    }) }
-                                </tbody>
-                            </table>
+                            if let Some((deleted, total)) = *reset_progress {
+                                { progress_status(
+                                    if total == 0 { "Loading transactions…".to_string() } else { format!("Deleting {} of {}…", deleted, total) },
+                                    deleted,
+                                    total,
+                                ) }
+                            }
+                            {
+                                match &*reset_result {
+                                    Some(Ok(msg)) => html! { <p class="text-sm text-green-600 mt-3" role="status">{ msg.clone() }</p> },
+                                    Some(Err(msg)) => html! { <p class="text-sm text-red-500 mt-3" role="alert">{ msg.clone() }</p> },
+                                    None => html! {},
+                                }
+                            }
                         </div>
                     </div>
+                    if *reset_confirm_open {
+                        <ConfirmModal
+                            title="Delete all my data"
+                            message="This deletes every transaction on the server and resets your local budgets, goal and settings. It cannot be undone."
+                            confirm_label="Delete everything"
+                            on_confirm={on_reset_confirm}
+                            on_cancel={{
+                                let reset_confirm_open = reset_confirm_open.clone();
+                                Callback::from(move |_| reset_confirm_open.set(false))
+                            }}
+                            destructive=true
+                        />
+                    }
                 </>
             }
         ) }
     }
 }
 
-#[function_component(SummaryPage)]
-fn summary_page() -> Html {
-    let settings = use_context::<UseStateHandle<AppSettings>>();
-    let currency_symbol = settings
-        .as_ref()
-        .map(|s| s.currency_symbol.clone())
-        .unwrap_or_else(|| "₱".to_string());
+/// Days from today to the end of the current period, today included.
+fn days_left_in_month(bounds: PeriodBounds) -> i64 {
+    let Some((year, month, day)) = parse_ymd(&today_key()) else {
+        return 1;
+    };
+    let (period_year, period_month) = period_of(year, month, day, bounds.month_start_day);
+    let (next_year, next) = next_month(period_year, period_month);
+    period_start(next_year, next, bounds.month_start_day) - days_from_civil(year, month, day)
+}
+
+/// Budget left this month across all budgets, from this month's spend in
+/// the budgeted categories. `None` without budgets.
+fn budget_left_this_month(
+    budgets: &[BudgetItem],
+    transactions: &[Transaction],
+    bounds: PeriodBounds,
+    savings_as_spending: bool,
+) -> Option<i64> {
+    if budgets.is_empty() {
+        return None;
+    }
+    let month = current_month_key(bounds);
+    let this_month: Vec<Transaction> = transactions
+        .iter()
+        .filter(|tx| month_key(&tx.date, bounds).as_ref() == Some(&month))
+        .cloned()
+        .collect();
+    let spent = spent_by_category(&this_month, savings_as_spending);
+    let total_budget: i64 = budgets.iter().map(|b| b.limit).sum();
+    let budget_spent: i64 = budgets
+        .iter()
+        .map(|b| spent.get(&category_key(&b.category)).copied().unwrap_or(0))
+        .sum();
+    Some(total_budget - budget_spent)
+}
+
+fn safe_to_spend_card(left: i64, bounds: PeriodBounds, currency: &CurrencyFormat) -> Html {
+    let days = days_left_in_month(bounds);
+    html! {
+        <div class="bg-card rounded-[10px] p-6 border border-border flex flex-wrap items-center justify-between gap-4" aria-label="Safe to spend">
+            <div>
+                <p class="text-[10px] font-bold tracking-widest text-muted-foreground">{"SAFE TO SPEND THIS MONTH"}</p>
+                if left >= 0 {
+                    <p class="text-2xl font-bold text-primary"><CurrencyAmount amount={left} currency={currency.clone()} /></p>
+                } else {
+                    <p class="text-2xl font-bold text-red-600">{"Over by "}<CurrencyAmount amount={-left} currency={currency.clone()} /></p>
+                }
+            </div>
+            if left >= 0 {
+                <p class="text-sm text-muted-foreground">
+                    <span class="font-semibold text-foreground"><CurrencyAmount amount={left / days.max(1)} currency={currency.clone()} /></span>
+                    { format!(" a day for the next {} day(s)", days) }
+                </p>
+            } else {
+                <p class="text-sm text-red-600">{"Your budgets are used up for this month."}</p>
+            }
+        </div>
+    }
+}
+
+/// Compact "This month so far" line above the Dashboard stat cards; `totals`
+/// is `(income, expenses)`, or `None` when nothing is dated this month.
+fn month_so_far_strip(totals: Option<(i64, i64)>, currency: &CurrencyFormat) -> Html {
+    let item = |label: &'static str, amount: Option<i64>| {
+        html! {
+            <span class="flex items-center gap-1">
+                <span class="text-muted-foreground">{ label }</span>
+                <span class="font-semibold text-foreground">
+                    { match amount {
+                        Some(amount) => html! { <CurrencyAmount {amount} currency={currency.clone()} /> },
+                        None => html! { "—" },
+                    } }
+                </span>
+            </span>
+        }
+    };
+    html! {
+        <div class="flex flex-wrap items-center gap-x-6 gap-y-1 bg-muted rounded-[10px] px-4 py-2 text-sm" aria-label="This month so far">
+            <span class="text-[10px] font-bold tracking-widest text-primary">{"THIS MONTH SO FAR"}</span>
+            { item("Income", totals.map(|(income, _)| income)) }
+            { item("Expenses", totals.map(|(_, expenses)| expenses)) }
+            { item("Net", totals.map(|(income, expenses)| income - expenses)) }
+        </div>
+    }
+}
+
+/// `stroke-dasharray` and `stroke-dashoffset` for a progress ring of
+/// `radius` filled to `progress` (clamped to 0..=1).
+fn ring_dash(radius: f64, progress: f64) -> (f64, f64) {
+    let circumference = 2.0 * std::f64::consts::PI * radius;
+    (
+        circumference,
+        circumference - progress.clamp(0.0, 1.0) * circumference,
+    )
+}
+
+/// Expenses as a share of income: green under half, amber up to 90%, red
+/// beyond. `None` when there is no income to divide by.
+fn spend_ratio_zone(income: i64, expenses: i64) -> (Option<f64>, &'static str, &'static str) {
+    if income <= 0 {
+        return if expenses > 0 {
+            (None, "#dc2626", "No income recorded yet")
+        } else {
+            (None, "#e2e8f0", "Nothing recorded yet")
+        };
+    }
+    let ratio = expenses as f64 / income as f64;
+    if ratio < 0.5 {
+        (Some(ratio), "#16a34a", "Comfortably within income")
+    } else if ratio <= 0.9 {
+        (Some(ratio), "#d97706", "Most of your income is spent")
+    } else {
+        (
+            Some(ratio),
+            "#dc2626",
+            "Spending is close to or above income",
+        )
+    }
+}
+
+/// Dashboard gauge of this period's expenses against its income, drawn with
+/// the same ring as the saving goal.
+fn spend_ratio_gauge(totals: Option<(i64, i64)>) -> Html {
+    let (income, expenses) = totals.unwrap_or((0, 0));
+    let (ratio, color, caption) = spend_ratio_zone(income, expenses);
+    let radius = 38.0;
+    let filled = match ratio {
+        Some(ratio) => ratio,
+        None if expenses > 0 => 1.0,
+        None => 0.0,
+    };
+    let (circumference, offset) = ring_dash(radius, filled);
+    let label = match ratio {
+        Some(ratio) => format!("{}%", (ratio * 100.0).round() as i64),
+        None => "—".to_string(),
+    };
+    html! {
+        <div class="bg-card rounded-[10px] p-4 border border-border flex items-center gap-4" role="img" aria-label={format!("Expenses are {} of income this month. {}", label, caption)}>
+            <div class="relative flex items-center justify-center shrink-0">
+                <svg class="w-24 h-24 transform -rotate-90">
+                    <circle cx="48" cy="48" r={radius.to_string()} stroke="#e2e8f0" stroke-width="8" fill="transparent" />
+                    <circle cx="48" cy="48" r={radius.to_string()} stroke={color} stroke-width="8" fill="transparent" stroke-dasharray={circumference.to_string()} stroke-dashoffset={offset.to_string()} stroke-linecap="round" />
+                </svg>
+                <div class="absolute inset-0 flex flex-col items-center justify-center">
+                    <span class="text-lg font-black text-primary">{ label }</span>
+                    <span class="text-[7px] text-slate-400 font-bold uppercase tracking-tighter">{"Of income"}</span>
+                </div>
+            </div>
+            <div>
+                <p class="text-[10px] font-bold tracking-widest text-muted-foreground">{"EXPENSES VS INCOME · THIS MONTH"}</p>
+                <p class="text-sm font-semibold text-foreground mt-1">{ caption }</p>
+                <p class="text-xs text-muted-foreground mt-1">{"Under 50% is green, up to 90% amber, above that red."}</p>
+            </div>
+        </div>
+    }
+}
+
+/// Categories listed in the monthly report.
+const MONTHLY_REPORT_TOP: usize = 5;
+
+/// Last month whose report was shown, as a `YYYY-MM` period key.
+fn load_reported_month() -> Option<String> {
+    let window = web_sys::window()?;
+    let storage = window.local_storage().ok()??;
+    storage.get_item("monthly_report_shown").ok()?
+}
+
+fn save_reported_month(key: &str) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            let _ = storage.set_item("monthly_report_shown", key);
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct MonthlyReport {
+    key: String,
+    income: i64,
+    expenses: i64,
+    top_categories: Vec<(String, i64)>,
+}
+
+/// Totals and top expense categories for one period; `None` when nothing
+/// was recorded in it.
+fn monthly_report(
+    transactions: &[Transaction],
+    key: &str,
+    bounds: PeriodBounds,
+) -> Option<MonthlyReport> {
+    transactions
+        .iter()
+        .any(|tx| month_key(&tx.date, bounds).as_deref() == Some(key))
+        .then(|| {
+            let (income, expenses) = month_totals(transactions, key, bounds);
+            let mut top_categories = month_expense_categories(transactions, key, bounds);
+            top_categories.truncate(MONTHLY_REPORT_TOP);
+            MonthlyReport {
+                key: key.to_string(),
+                income,
+                expenses,
+                top_categories,
+            }
+        })
+}
+
+/// The monthly report as CSV: the totals, then the top categories. Amounts
+/// are raw whole units, like `transactions_csv`.
+fn monthly_report_csv(report: &MonthlyReport) -> String {
+    let mut csv = format!(
+        "Month,{}\nIncome,{}\nExpenses,{}\nNet,{}\n\nCategory,Spent\n",
+        report.key,
+        report.income,
+        report.expenses,
+        report.income - report.expenses
+    );
+    for (category, amount) in &report.top_categories {
+        csv.push_str(&format!("{},{}\n", csv_field(category), amount));
+    }
+    csv
+}
+
+#[derive(Properties, PartialEq)]
+struct MonthlyReportCardProps {
+    bounds: PeriodBounds,
+    currency: CurrencyFormat,
+}
+
+/// Last month's report, shown once on the first Dashboard visit of a new
+/// month. The month is marked reported as soon as the report is built, so
+/// reloading or dismissing never brings it back.
+#[function_component(MonthlyReportCard)]
+fn monthly_report_card(props: &MonthlyReportCardProps) -> Html {
+    let report = use_state(|| None::<MonthlyReport>);
+    let scope = use_request_scope();
+    {
+        let report = report.clone();
+        use_effect_with_deps(
+            move |&bounds| {
+                let due = previous_month_key(&current_month_key(bounds))
+                    .filter(|key| load_reported_month().as_ref() != Some(key));
+                if let Some(key) = due {
+                    spawn_local(async move {
+                        let Ok(list) = fetch_transactions(Some(&scope)).await else {
+                            return;
+                        };
+                        save_reported_month(&key);
+                        report.set(monthly_report(&list, &key, bounds));
+                    });
+                }
+                || ()
+            },
+            props.bounds,
+        );
+    }
+
+    let Some(current) = &*report else {
+        return html! {};
+    };
+    let on_dismiss = {
+        let report = report.clone();
+        Callback::from(move |_| report.set(None))
+    };
+    let on_download = {
+        let filename = format!("pondobro-report-{}.csv", current.key);
+        let csv = monthly_report_csv(current);
+        Callback::from(move |_| download_csv(&filename, &csv))
+    };
+    let currency = &props.currency;
+    html! {
+        <div class="bg-card rounded-[10px] p-6 border border-border" role="region" aria-label="Monthly report">
+            <div class="flex items-start justify-between gap-3 mb-4">
+                <div>
+                    <h3 class="font-bold text-foreground text-lg">{ format!("Your report for {}", current.key) }</h3>
+                    <p class="text-xs text-muted-foreground">{"A look back at last month. Turn this off under Settings → Notifications."}</p>
+                </div>
+                <button type="button" onclick={on_dismiss} class="text-xs text-muted-foreground hover:text-foreground">{"Dismiss"}</button>
+            </div>
+            <div class="grid grid-cols-3 gap-3 mb-4">
+                <div>
+                    <p class="text-xs text-muted-foreground">{"Income"}</p>
+                    <p class="font-bold text-foreground"><CurrencyAmount amount={current.income} currency={currency.clone()} /></p>
+                </div>
+                <div>
+                    <p class="text-xs text-muted-foreground">{"Expenses"}</p>
+                    <p class="font-bold text-foreground"><CurrencyAmount amount={current.expenses} currency={currency.clone()} /></p>
+                </div>
+                <div>
+                    <p class="text-xs text-muted-foreground">{"Net"}</p>
+                    <p class="font-bold text-foreground"><CurrencyAmount amount={current.income - current.expenses} currency={currency.clone()} show_plus=true /></p>
+                </div>
+            </div>
+            if !current.top_categories.is_empty() {
+                <p class="text-[10px] font-bold tracking-widest text-muted-foreground mb-2">{"TOP CATEGORIES"}</p>
+                <ul class="space-y-1 text-sm mb-4">
+                    { for current.top_categories.iter().map(|(category, amount)| html! {
+                        <li class="flex justify-between">
+                            <span class="text-foreground">{ category.clone() }</span>
+                            <span class="font-semibold text-foreground"><CurrencyAmount amount={*amount} currency={currency.clone()} /></span>
+                        </li>
+                    }) }
+                </ul>
+            }
+            <button type="button" onclick={on_download} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{"Download this report"}</button>
+        </div>
+    }
+}
+
+/// Labelled progress bar for batch operations that run one request per row.
+fn progress_status(label: String, done: usize, total: usize) -> Html {
+    html! {
+        <div class="mt-4" role="status">
+            <p class="text-sm text-muted-foreground mb-1">{ label }</p>
+            <div class="h-2 w-full bg-secondary rounded-full overflow-hidden">
+                <div class="h-full bg-primary" style={format!("width: {}%", (done * 100).checked_div(total).unwrap_or(0))}></div>
+            </div>
+        </div>
+    }
+}
+
+/// Settings card for tidying up free-text categories across transactions
+/// and budgets.
+#[function_component(CategoryManager)]
+fn category_manager() -> Html {
+    // `None` until the first fetch finishes
+    let transactions = use_state(|| None::<Vec<Transaction>>);
+    let merge_source = use_state(|| "".to_string());
+    let merge_target = use_state(|| "".to_string());
+    let confirm_merge = use_state(|| false);
+    let rename_source = use_state(|| "".to_string());
+    let rename_to = use_state(|| "".to_string());
+    let confirm_rename = use_state(|| false);
+    // (processed, total) while the PUTs run
+    let progress = use_state(|| None::<(usize, usize)>);
+    let result = use_state(|| None::<Result<String, String>>);
+    // rows the last merge or rename could not update
+    let failed_rows = use_state(Vec::<Transaction>::new);
+
+    {
+        let transactions = transactions.clone();
+        let result = result.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    match fetch_transactions(None).await.ok() {
+                        Some(list) => transactions.set(Some(list)),
+                        None => {
+                            transactions.set(Some(vec![]));
+                            result.set(Some(Err("Could not load your categories.".to_string())));
+                        }
+                    }
+                });
+                || ()
+            },
+            (),
+        );
+    }
+
+    let categories = transactions
+        .as_deref()
+        .map(category_counts)
+        .unwrap_or_default();
+    let source_key = category_key(&merge_source);
+    let affected: Vec<Transaction> = transactions
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter(|tx| !is_transfer(tx) && category_key(&tx.category) == source_key)
+        .cloned()
+        .collect();
+    let can_merge = !merge_source.is_empty()
+        && !merge_target.is_empty()
+        && source_key != category_key(&merge_target)
+        && progress.is_none();
+    let rename_count = transactions
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter(|tx| !is_transfer(tx) && category_key(&tx.category) == category_key(&rename_source))
+        .count();
+    let new_name = normalize_category(&rename_to);
+    let can_rename = !rename_source.is_empty()
+        && !new_name.is_empty()
+        && new_name != *rename_source
+        && progress.is_none();
+
+    // moves every non-transfer row in `source` to `target`, one PUT per row;
+    // shared by merge and rename
+    let reassign = {
+        let transactions = transactions.clone();
+        let progress = progress.clone();
+        let result = result.clone();
+        let failed_rows = failed_rows.clone();
+        Callback::from(move |(source, target): (String, String)| {
+            let affected = recategorized(
+                transactions.as_deref().unwrap_or_default(),
+                &source,
+                &target,
+            );
+            result.set(None);
+            failed_rows.set(vec![]);
+            let transactions = transactions.clone();
+            let progress = progress.clone();
+            let result = result.clone();
+            let failed_rows = failed_rows.clone();
+            spawn_local(async move {
+                let total = affected.len();
+                let mut updated = Vec::new();
+                let mut failed = Vec::new();
+                progress.set(Some((0, total)));
+                for tx in affected {
+                    match put_transaction(&tx).await {
+                        Some(saved) => updated.push(saved),
+                        None => failed.push(tx),
+                    }
+                    progress.set(Some((updated.len() + failed.len(), total)));
+                }
+                progress.set(None);
+                reassign_budgets(&source, &target);
+
+                let next = transactions
+                    .as_deref()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|tx| {
+                        updated
+                            .iter()
+                            .find(|saved| saved.id == tx.id)
+                            .cloned()
+                            .unwrap_or_else(|| tx.clone())
+                    })
+                    .collect();
+                transactions.set(Some(next));
+                if failed.is_empty() {
+                    result.set(Some(Ok(format!(
+                        "Moved {} transactions from \"{}\" to \"{}\".",
+                        total, source, target
+                    ))));
+                } else {
+                    result.set(Some(Err(format!(
+                        "{} of {} transactions could not be updated; run it again to retry:",
+                        failed.len(),
+                        total
+                    ))));
+                    failed_rows.set(failed);
+                }
+            });
+        })
+    };
+
+    let on_merge = {
+        let merge_source = merge_source.clone();
+        let merge_target = merge_target.clone();
+        let confirm_merge = confirm_merge.clone();
+        let reassign = reassign.clone();
+        Callback::from(move |_| {
+            confirm_merge.set(false);
+            reassign.emit(((*merge_source).clone(), (*merge_target).clone()));
+            merge_source.set("".to_string());
+        })
+    };
+
+    let on_rename = {
+        let rename_source = rename_source.clone();
+        let rename_to = rename_to.clone();
+        let confirm_rename = confirm_rename.clone();
+        Callback::from(move |_| {
+            confirm_rename.set(false);
+            reassign.emit(((*rename_source).clone(), normalize_category(&rename_to)));
+            rename_source.set("".to_string());
+            rename_to.set("".to_string());
+        })
+    };
+
+    let category_select = |label: &'static str, state: &UseStateHandle<String>| {
+        let onchange = {
+            let state = state.clone();
+            Callback::from(move |e: Event| {
+                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                state.set(input.value());
+            })
+        };
+        html! {
+            <select {onchange} aria-label={label} class="flex-1 p-2 border rounded text-sm">
+                <option value="" selected={state.is_empty()}>{ label }</option>
+                { for categories.iter().map(|(category, count)| html! {
+                    <option value={category.clone()} selected={category_key(state.as_str()) == category_key(category)}>{ format!("{} ({})", category, count) }</option>
+                }) }
+            </select>
+        }
+    };
+
+    html! {
+        <div class="bg-card rounded-lg p-6 border border-border">
+            <h2 class="text-xl font-bold text-foreground mb-2">{"Categories"}</h2>
+            if transactions.is_none() {
+                <p class="text-sm text-muted-foreground">{"Loading categories..."}</p>
+            } else if categories.is_empty() {
+                <p class="text-sm text-muted-foreground">{"No categories yet."}</p>
+            } else {
+                <h3 class="font-medium text-foreground mb-1">{"Merge categories"}</h3>
+                <p class="text-sm text-muted-foreground mb-3">{"Move every transaction (and budget) from one category into another."}</p>
+                <div class="flex flex-col md:flex-row gap-2">
+                    { category_select("Merge from…", &merge_source) }
+                    { category_select("Into…", &merge_target) }
+                    <button type="button" disabled={!can_merge} onclick={{
+                        let confirm_merge = confirm_merge.clone();
+                        Callback::from(move |_| confirm_merge.set(true))
+                    }} class="px-4 py-2 rounded-lg bg-primary text-primary-foreground text-sm font-bold disabled:opacity-50">{"Merge"}</button>
+                </div>
+                if can_merge {
+                    <p class="text-xs text-muted-foreground mt-2">{ format!("{} transaction(s) will move to \"{}\".", affected.len(), *merge_target) }</p>
+                }
+
+                <h3 class="font-medium text-foreground mt-6 mb-1">{"Rename a category"}</h3>
+                <p class="text-sm text-muted-foreground mb-3">{"Updates every transaction and budget that uses it."}</p>
+                <div class="flex flex-col md:flex-row gap-2">
+                    { category_select("Rename…", &rename_source) }
+                    <input type="text" placeholder="New name" aria-label="New category name" value={(*rename_to).clone()} oninput={{
+                        let rename_to = rename_to.clone();
+                        Callback::from(move |e: InputEvent| {
+                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                            rename_to.set(input.value());
+                        })
+                    }} class="flex-1 p-2 border rounded text-sm" />
+                    <button type="button" disabled={!can_rename} onclick={{
+                        let confirm_rename = confirm_rename.clone();
+                        Callback::from(move |_| confirm_rename.set(true))
+                    }} class="px-4 py-2 rounded-lg bg-primary text-primary-foreground text-sm font-bold disabled:opacity-50">{"Rename"}</button>
+                </div>
+                if can_rename {
+                    <p class="text-xs text-muted-foreground mt-2">{ format!("{} transaction(s) will be renamed to \"{}\".", rename_count, new_name) }</p>
+                }
+            }
+            if let Some((done, total)) = *progress {
+                { progress_status(format!("Updating {} of {}…", done, total), done, total) }
+            }
+            {
+                match &*result {
+                    Some(Ok(msg)) => html! { <p class="text-sm text-green-600 mt-3" role="status">{ msg.clone() }</p> },
+                    Some(Err(msg)) => html! { <p class="text-sm text-red-500 mt-3" role="alert">{ msg.clone() }</p> },
+                    None => html! {},
+                }
+            }
+            if !failed_rows.is_empty() {
+                <ul class="mt-2 text-xs text-red-500 list-disc pl-5">
+                    { for failed_rows.iter().map(|tx| html! {
+                        <li>{ format!("{} — {}", tx.date.get(0..10).unwrap_or(&tx.date), tx.description) }</li>
+                    }) }
+                </ul>
+            }
+            if *confirm_rename {
+                <ConfirmModal
+                    title="Rename category"
+                    message={format!("Rename \"{}\" to \"{}\" on {} transaction(s) and any budget?", *rename_source, new_name, rename_count)}
+                    confirm_label="Rename"
+                    on_confirm={on_rename}
+                    on_cancel={{
+                        let confirm_rename = confirm_rename.clone();
+                        Callback::from(move |_| confirm_rename.set(false))
+                    }}
+                />
+            }
+            if *confirm_merge {
+                <ConfirmModal
+                    title="Merge categories"
+                    message={format!("Move {} transaction(s) from \"{}\" to \"{}\"? Budgets for \"{}\" move too.", affected.len(), *merge_source, *merge_target, *merge_source)}
+                    confirm_label="Merge"
+                    on_confirm={on_merge}
+                    on_cancel={{
+                        let confirm_merge = confirm_merge.clone();
+                        Callback::from(move |_| confirm_merge.set(false))
+                    }}
+                />
+            }
+        </div>
+    }
+}
+
+/// Data rows shown in the import preview.
+const IMPORT_PREVIEW_ROWS: usize = 5;
+
+/// Used when neither a category column nor a default is given.
+const IMPORT_FALLBACK_CATEGORY: &str = "Uncategorized";
+
+/// Field a CSV column feeds when importing. Exports with separate debit and
+/// credit columns map them to `MoneyOut`/`MoneyIn` instead of `Amount`.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum ImportField {
+    Date,
+    Description,
+    Category,
+    Amount,
+    MoneyIn,
+    MoneyOut,
+}
+
+impl ImportField {
+    const ALL: [ImportField; 6] = [
+        ImportField::Date,
+        ImportField::Description,
+        ImportField::Category,
+        ImportField::Amount,
+        ImportField::MoneyIn,
+        ImportField::MoneyOut,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            ImportField::Date => "date",
+            ImportField::Description => "description",
+            ImportField::Category => "category",
+            ImportField::Amount => "amount",
+            ImportField::MoneyIn => "money_in",
+            ImportField::MoneyOut => "money_out",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ImportField::Date => "Date",
+            ImportField::Description => "Description",
+            ImportField::Category => "Category",
+            ImportField::Amount => "Amount (signed)",
+            ImportField::MoneyIn => "Money in",
+            ImportField::MoneyOut => "Money out",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<ImportField> {
+        ImportField::ALL
+            .into_iter()
+            .find(|field| field.key() == key)
+    }
+
+    /// Best guess from a bank's column header.
+    fn guess(header: &str) -> Option<ImportField> {
+        let header = header.trim().to_lowercase();
+        let has = |words: &[&str]| words.iter().any(|word| header.contains(word));
+        if has(&["date"]) {
+            Some(ImportField::Date)
+        } else if has(&[
+            "description",
+            "details",
+            "memo",
+            "narration",
+            "particulars",
+            "payee",
+        ]) {
+            Some(ImportField::Description)
+        } else if has(&["category"]) {
+            Some(ImportField::Category)
+        } else if has(&["debit", "withdrawal", "money out", "paid out"]) {
+            Some(ImportField::MoneyOut)
+        } else if has(&["credit", "deposit", "money in", "paid in"]) {
+            Some(ImportField::MoneyIn)
+        } else if has(&["amount"]) {
+            Some(ImportField::Amount)
+        } else {
+            None
+        }
+    }
+}
+
+/// Order of the day, month and year in the file's date column.
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum ImportDateFormat {
+    /// 2024-01-31
+    #[default]
+    Ymd,
+    /// 01/31/2024
+    Mdy,
+    /// 31/01/2024
+    Dmy,
+}
 
-    let total_income = use_state(|| 0i64);
-    let total_expenses = use_state(|| 0i64);
-    let balance = use_state(|| 0i64);
-    let recent = use_state(|| Vec::<Transaction>::new());
-    let loading = use_state(|| true);
+impl ImportDateFormat {
+    const ALL: [ImportDateFormat; 3] = [
+        ImportDateFormat::Ymd,
+        ImportDateFormat::Mdy,
+        ImportDateFormat::Dmy,
+    ];
 
-    {
-        let total_income = total_income.clone();
-        let total_expenses = total_expenses.clone();
-        let balance = balance.clone();
-        let recent = recent.clone();
-        let loading = loading.clone();
+    fn key(self) -> &'static str {
+        match self {
+            ImportDateFormat::Ymd => "ymd",
+            ImportDateFormat::Mdy => "mdy",
+            ImportDateFormat::Dmy => "dmy",
+        }
+    }
 
-        use_effect_with_deps(
-            move |_| {
-                spawn_local(async move {
-                    let summary_url = format!("{}/api/dashboard/summary", API_BASE_URL);
-                    let mut req =
-                        Request::get(&summary_url).credentials(RequestCredentials::Include);
-                    if let Some(window) = web_sys::window() {
-                        if let Ok(Some(storage)) = window.local_storage() {
-                            if let Ok(token_opt) = storage.get_item("access_token") {
-                                if let Some(token) = token_opt {
-                                    req = req.header("Authorization", &format!("Bearer {}", token));
-                                }
-                            }
-                        }
-                    }
+    fn label(self) -> &'static str {
+        match self {
+            ImportDateFormat::Ymd => "Year first (2024-01-31)",
+            ImportDateFormat::Mdy => "Month first (01/31/2024)",
+            ImportDateFormat::Dmy => "Day first (31/01/2024)",
+        }
+    }
+}
 
-                    if let Ok(resp) = req.send().await {
-                        if resp.ok() {
-                            if let Ok(json) = resp.json::<serde_json::Value>().await {
-                                if let Some(v) = json.get("total_income").and_then(|x| x.as_i64()) {
-                                    total_income.set(v);
-                                }
-                                if let Some(v) = json.get("total_expenses").and_then(|x| x.as_i64())
-                                {
-                                    total_expenses.set(v);
-                                }
-                                if let Some(v) = json.get("balance").and_then(|x| x.as_i64()) {
-                                    balance.set(v);
-                                }
-                            }
-                        }
-                    }
+/// How the columns of one CSV layout map onto transaction fields;
+/// remembered per header signature.
+#[derive(Clone, PartialEq, Default, Serialize, Deserialize)]
+struct ImportMapping {
+    /// One entry per column; `None` skips it.
+    fields: Vec<Option<ImportField>>,
+    date_format: ImportDateFormat,
+    /// For rows without a category column or with the cell left blank.
+    default_category: String,
+}
 
-                    let tx_url = format!("{}/api/transactions", API_BASE_URL);
-                    let mut req2 = Request::get(&tx_url).credentials(RequestCredentials::Include);
-                    if let Some(window) = web_sys::window() {
-                        if let Ok(Some(storage)) = window.local_storage() {
-                            if let Ok(token_opt) = storage.get_item("access_token") {
-                                if let Some(token) = token_opt {
-                                    req2 =
-                                        req2.header("Authorization", &format!("Bearer {}", token));
-                                }
-                            }
-                        }
-                    }
-                    if let Ok(resp2) = req2.send().await {
-                        if resp2.ok() {
-                            if let Ok(list) = resp2.json::<Vec<Transaction>>().await {
-                                recent.set(list.into_iter().take(10).collect());
-                            }
-                        }
-                    }
+impl ImportMapping {
+    fn guess(headers: &[String]) -> ImportMapping {
+        let mut mapping = ImportMapping {
+            fields: vec![None; headers.len()],
+            ..ImportMapping::default()
+        };
+        for (column, header) in headers.iter().enumerate() {
+            if let Some(field) = ImportField::guess(header) {
+                if mapping.column(field).is_none() {
+                    mapping.fields[column] = Some(field);
+                }
+            }
+        }
+        mapping
+    }
 
-                    loading.set(false);
-                });
-                || ()
-            },
-            (),
-        );
+    fn column(&self, field: ImportField) -> Option<usize> {
+        self.fields.iter().position(|f| *f == Some(field))
     }
 
-    html! {
-        { page_shell(
-            "Summary Report",
-            html! {},
-            html! {
-                <>
-                    <div class="grid grid-cols-1 md:grid-cols-3 gap-6">
-                        <div class="bg-card rounded-lg p-6 border border-border">
-                            <p class="text-sm text-muted-foreground mb-2">{"Total Income"}</p>
-                            <h3 class="text-3xl font-bold text-foreground">{ format_currency(*total_income, &currency_symbol) }</h3>
-                        </div>
-                        <div class="bg-card rounded-lg p-6 border border-border">
-                            <p class="text-sm text-muted-foreground mb-2">{"Total Expenses"}</p>
-                            <h3 class="text-3xl font-bold text-foreground">{ format_currency(*total_expenses, &currency_symbol) }</h3>
-                        </div>
-                        <div class="bg-card rounded-lg p-6 border border-border">
-                            <p class="text-sm text-muted-foreground mb-2">{"Net Balance"}</p>
-                            <h3 class="text-3xl font-bold text-foreground">{ format_currency(*balance, &currency_symbol) }</h3>
-                        </div>
-                    </div>
+    /// Gives `column` to `field`, taking it from whichever column had it.
+    fn assign(&mut self, column: usize, field: Option<ImportField>) {
+        if let Some(field) = field {
+            for f in self.fields.iter_mut().filter(|f| **f == Some(field)) {
+                *f = None;
+            }
+        }
+        if let Some(slot) = self.fields.get_mut(column) {
+            *slot = field;
+        }
+    }
 
-                    <div class="bg-card rounded-lg border border-border overflow-hidden">
-                        <div class="px-6 py-4 border-b border-border">
-                            <h3 class="text-lg font-bold text-foreground">{"Recent Transactions"}</h3>
-                        </div>
-                        <div class="overflow-x-auto">
-                            <table class="w-full text-left border-collapse">
-                                <thead class="bg-secondary border-b border-border">
-                                    <tr>
-                                        <th class="px-6 py-3 text-left text-sm font-semibold text-secondary-foreground">{"Date"}</th>
-                                        <th class="px-6 py-3 text-left text-sm font-semibold text-secondary-foreground">{"Description"}</th>
-                                        <th class="px-6 py-3 text-left text-sm font-semibold text-secondary-foreground">{"Category"}</th>
-                                        <th class="px-6 py-3 text-right text-sm font-semibold text-secondary-foreground">{"Amount"}</th>
-                                    </tr>
-                                </thead>
-                                <tbody class="divide-y divide-border">
-                                    { if *loading {
-                                        html! { <tr><td colspan="4" class="px-6 py-6 text-center text-muted-foreground">{"Loading..."}</td></tr> }
-                                    } else if recent.is_empty() {
-                                        html! { <tr><td colspan="4" class="px-6 py-6 text-center text-muted-foreground">{"No transactions yet."}</td></tr> }
-                                    } else {
-                                        html! {
-                                            <>
-                                                { for recent.iter().map(|tx| html! {
-                                                    <tr class="text-sm hover:bg-muted/30 transition-colors">
-                                                        <td class="px-6 py-3 text-muted-foreground">{ tx.date.clone() }</td>
-                                                        <td class="px-6 py-3 text-foreground">{ tx.description.clone() }</td>
-                                                        <td class="px-6 py-3 text-foreground">{ tx.category.clone() }</td>
-                                                        <td class="px-6 py-3 text-right font-semibold text-foreground">{ format_currency(tx.amount, &currency_symbol) }</td>
-                                                    </tr>
-                                                }) }
-                                            </>
-                                        }
-                                    }}
-                                </tbody>
-                            </table>
-                        </div>
-                    </div>
-                </>
+    /// What still has to be assigned before rows can be parsed.
+    fn missing(&self) -> Option<&'static str> {
+        let has = |field| self.column(field).is_some();
+        if !has(ImportField::Date) {
+            Some("Pick the Date column.")
+        } else if !has(ImportField::Description) {
+            Some("Pick the Description column.")
+        } else if !(has(ImportField::Amount)
+            || has(ImportField::MoneyIn)
+            || has(ImportField::MoneyOut))
+        {
+            Some("Pick an Amount column, or Money in / Money out.")
+        } else {
+            None
+        }
+    }
+}
+
+/// Identifies a bank's CSV layout by its header row.
+fn header_signature(headers: &[String]) -> String {
+    headers
+        .iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+fn load_import_mappings() -> HashMap<String, ImportMapping> {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(Some(raw)) = storage.get_item("import_mappings") {
+                if let Ok(mappings) = serde_json::from_str(&raw) {
+                    return mappings;
+                }
             }
-        ) }
+        }
     }
+    HashMap::new()
 }
 
-#[function_component(SettingsPage)]
-fn settings_page() -> Html {
-    let settings = use_context::<UseStateHandle<AppSettings>>();
-    let budget_alerts = use_state(|| true);
-    let monthly_report = use_state(|| true);
-    let saving_alert = use_state(|| true);
+fn save_import_mapping(signature: &str, mapping: &ImportMapping) {
+    let mut mappings = load_import_mappings();
+    mappings.insert(signature.to_string(), mapping.clone());
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            if let Ok(raw) = serde_json::to_string(&mappings) {
+                let _ = storage.set_item("import_mappings", &raw);
+            }
+        }
+    }
+}
 
-    let current_currency = settings
-        .as_ref()
-        .map(|s| s.currency_code.clone())
-        .unwrap_or_else(|| "PHP".to_string());
+/// Splits CSV text into rows, honouring quoted fields. The delimiter is
+/// whichever of `,` and `;` the first line uses more; blank lines are dropped.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let text = text.trim_start_matches('\u{feff}');
+    let first_line = text.lines().next().unwrap_or_default();
+    let delimiter = if first_line.matches(';').count() > first_line.matches(',').count() {
+        ';'
+    } else {
+        ','
+    };
 
-    let on_currency_change = {
-        let settings = settings.clone();
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.is_empty() => in_quotes = true,
+            c if c == delimiter && !in_quotes => row.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|row| row.iter().any(|cell| !cell.trim().is_empty()));
+    rows
+}
+
+/// Reads a date cell in `format` as `YYYY-MM-DD`. A time after the date is
+/// ignored and two-digit years are taken as 20xx.
+fn parse_import_date(raw: &str, format: ImportDateFormat) -> Option<String> {
+    let token = raw.split_whitespace().next()?.split('T').next()?;
+    let parts = token
+        .split(['-', '/', '.'])
+        .map(|part| part.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let [a, b, c] = parts[..] else {
+        return None;
+    };
+    let (year, month, day) = match format {
+        ImportDateFormat::Ymd => (a, b, c),
+        ImportDateFormat::Mdy => (c, a, b),
+        ImportDateFormat::Dmy => (c, b, a),
+    };
+    let year = if year < 100 { year + 2000 } else { year } as i64;
+    if !(1..=12).contains(&month)
+        || day == 0
+        || civil_from_days(days_from_civil(year, month, day)) != (year, month, day)
+    {
+        return None;
+    }
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+/// Reads an amount cell as exported by a bank: currency symbols and digit
+/// grouping are dropped and `(250.00)` counts as negative. `Ok(None)` is a
+/// blank cell.
+fn parse_import_amount(raw: &str, grouping: GroupingStyle) -> Result<Option<i64>, String> {
+    let value = raw.trim();
+    let (negative, value) = match value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+        Some(inner) => (true, inner),
+        None => (false, value),
+    };
+    let (_, decimal) = grouping.separators();
+    let cleaned: String = value
+        .chars()
+        .filter(|c| c.is_ascii_digit() || matches!(c, '-' | '+') || *c == decimal)
+        .map(|c| if c == decimal { '.' } else { c })
+        .collect();
+    if cleaned.is_empty() {
+        return if value.is_empty() {
+            Ok(None)
+        } else {
+            Err(format!("\"{}\" is not an amount.", raw.trim()))
+        };
+    }
+    let amount = parse_rounded_amount(&cleaned)?;
+    Ok(Some(if negative { -amount.abs() } else { amount }))
+}
+
+/// A CSV row read through an `ImportMapping`, ready to POST.
+#[derive(Clone, PartialEq)]
+struct ImportRow {
+    date: String,
+    description: String,
+    category: String,
+    amount: i64,
+}
+
+fn parse_import_row(
+    row: &[String],
+    mapping: &ImportMapping,
+    grouping: GroupingStyle,
+) -> Result<ImportRow, String> {
+    let cell = |field| {
+        mapping
+            .column(field)
+            .and_then(|column| row.get(column))
+            .map(|cell| cell.trim())
+            .unwrap_or_default()
+    };
+
+    let date =
+        parse_import_date(cell(ImportField::Date), mapping.date_format).ok_or_else(|| {
+            format!(
+                "\"{}\" is not a date in the chosen format.",
+                cell(ImportField::Date)
+            )
+        })?;
+    let description = cell(ImportField::Description).to_string();
+    if description.is_empty() {
+        return Err("No description.".to_string());
+    }
+    let category = match normalize_category(cell(ImportField::Category)) {
+        category if !category.is_empty() => category,
+        _ => match normalize_category(&mapping.default_category) {
+            default if !default.is_empty() => default,
+            _ => IMPORT_FALLBACK_CATEGORY.to_string(),
+        },
+    };
+
+    let amount = if mapping.column(ImportField::Amount).is_some() {
+        parse_import_amount(cell(ImportField::Amount), grouping)?
+    } else {
+        let money_in = parse_import_amount(cell(ImportField::MoneyIn), grouping)?;
+        let money_out = parse_import_amount(cell(ImportField::MoneyOut), grouping)?;
+        match (money_in, money_out) {
+            (None, None) => None,
+            (money_in, money_out) => {
+                Some(money_in.unwrap_or(0).abs() - money_out.unwrap_or(0).abs())
+            }
+        }
+    };
+    match amount {
+        Some(amount) if amount != 0 => Ok(ImportRow {
+            date,
+            description,
+            category,
+            amount,
+        }),
+        Some(_) => Err("Amount is zero.".to_string()),
+        None => Err("No amount.".to_string()),
+    }
+}
+
+/// A CSV the user picked, waiting on the mapping step.
+#[derive(Clone, PartialEq)]
+struct ImportFile {
+    name: String,
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+/// Imports transactions from a bank's CSV export: pick a file, map its
+/// columns, check the preview, then POST the rows one by one.
+#[function_component(TransactionImport)]
+fn transaction_import() -> Html {
+    let settings = use_context::<UseStateHandle<AppSettings>>();
+    let grouping = settings.as_ref().map(|s| s.grouping).unwrap_or_default();
+    let currency = use_currency_format();
+    let summary = use_context::<SummaryContext>();
+    let file = use_state(|| None::<ImportFile>);
+    let mapping = use_state(ImportMapping::default);
+    // (processed, total) while the POSTs run
+    let progress = use_state(|| None::<(usize, usize)>);
+    let result = use_state(|| None::<Result<String, String>>);
+
+    let on_file = {
+        let file = file.clone();
+        let mapping = mapping.clone();
+        let result = result.clone();
         Callback::from(move |e: Event| {
-            if let Some(settings) = settings.as_ref() {
-                let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
-                let code = input.value();
-                let symbol = currency_symbol_for(&code).to_string();
-                let next = AppSettings {
-                    currency_code: code,
-                    currency_symbol: symbol,
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let Some(picked) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            // so picking the same file again still fires `change`
+            input.set_value("");
+            result.set(None);
+            let file = file.clone();
+            let mapping = mapping.clone();
+            let result = result.clone();
+            spawn_local(async move {
+                let text = JsFuture::from(picked.text())
+                    .await
+                    .ok()
+                    .and_then(|text| text.as_string());
+                let Some(text) = text else {
+                    result.set(Some(Err("Could not read that file.".to_string())));
+                    return;
                 };
-                save_settings(&next);
-                settings.set(next);
-            }
+                let mut rows = parse_csv(&text);
+                if rows.len() < 2 {
+                    result.set(Some(Err(
+                        "That file has no rows below its header.".to_string()
+                    )));
+                    return;
+                }
+                let headers = rows.remove(0);
+                let saved = load_import_mappings()
+                    .remove(&header_signature(&headers))
+                    .filter(|saved| saved.fields.len() == headers.len());
+                mapping.set(saved.unwrap_or_else(|| ImportMapping::guess(&headers)));
+                file.set(Some(ImportFile {
+                    name: picked.name(),
+                    headers,
+                    rows,
+                }));
+            });
+        })
+    };
+
+    let on_cancel = {
+        let file = file.clone();
+        Callback::from(move |_| file.set(None))
+    };
+
+    let parsed: Vec<Result<ImportRow, String>> = match (&*file, mapping.missing()) {
+        (Some(file), None) => file
+            .rows
+            .iter()
+            .map(|row| parse_import_row(row, &mapping, grouping))
+            .collect(),
+        _ => vec![],
+    };
+    let ready: Vec<ImportRow> = parsed.iter().filter_map(|row| row.clone().ok()).collect();
+    let skipped = parsed.len() - ready.len();
+
+    let on_import = {
+        let file = file.clone();
+        let mapping = mapping.clone();
+        let progress = progress.clone();
+        let result = result.clone();
+        let ready = ready.clone();
+        Callback::from(move |_| {
+            let Some(picked) = &*file else {
+                return;
+            };
+            save_import_mapping(&header_signature(&picked.headers), &mapping);
+            result.set(None);
+            let file = file.clone();
+            let progress = progress.clone();
+            let result = result.clone();
+            let summary = summary.clone();
+            let rows = ready.clone();
+            spawn_local(async move {
+                let total = rows.len();
+                let mut failed = 0;
+                progress.set(Some((0, total)));
+                for (done, row) in rows.iter().enumerate() {
+                    let payload = serde_json::json!({
+                        "date": row.date.as_str(),
+                        "description": row.description.as_str(),
+                        "category": row.category.as_str(),
+                        "amount": row.amount,
+                    });
+                    if post_transaction(&payload).await.is_none() {
+                        failed += 1;
+                    }
+                    progress.set(Some((done + 1, total)));
+                }
+                progress.set(None);
+                if let Some(summary) = &summary {
+                    summary.refresh();
+                }
+                if failed == 0 {
+                    file.set(None);
+                    result.set(Some(Ok(format!("Imported {} transactions.", total))));
+                } else {
+                    result.set(Some(Err(format!(
+                        "Imported {} of {} transactions; {} could not be saved.",
+                        total - failed,
+                        total,
+                        failed
+                    ))));
+                }
+            });
         })
     };
 
     html! {
-        { page_shell(
-            "Settings",
-            html! {},
-            html! {
-                <>
-                    <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
-                        <div class="bg-card rounded-lg p-6 border border-border">
-                            <h2 class="text-xl font-bold text-foreground mb-6">{"Preferences"}</h2>
-                            <div class="space-y-4">
-                                <div>
-                                    <label class="block text-sm font-medium text-foreground mb-2">{"Currency"}</label>
-                                    <select value={current_currency} onchange={on_currency_change} class="w-full px-4 py-2 bg-input border border-input rounded-lg text-foreground focus:outline-none focus:ring-2 focus:ring-primary">
-                                        <option value="PHP">{"PHP (₱)"}</option>
-                                        <option value="USD">{"USD ($)"}</option>
-                                        <option value="EUR">{"EUR (€)"}</option>
-                                        <option value="GBP">{"GBP (£)"}</option>
-                                        <option value="JPY">{"JPY (¥)"}</option>
+        <div class="bg-card rounded-lg p-6 border border-border">
+            <h2 class="text-xl font-bold text-foreground mb-2">{"Import transactions"}</h2>
+            <p class="text-sm text-muted-foreground mb-4">{"Load a CSV export from your bank, match its columns to transaction fields and check the preview before importing."}</p>
+            <input type="file" accept=".csv,text/csv" aria-label="CSV file" onchange={on_file} disabled={progress.is_some()} class="text-sm" />
+            if let Some(picked) = &*file {
+                <div class="mt-6">
+                    <h3 class="font-medium text-foreground mb-1">{ format!("Columns in {}", picked.name) }</h3>
+                    <p class="text-sm text-muted-foreground mb-3">{ format!("{} row(s) below the header.", picked.rows.len()) }</p>
+                    <div class="space-y-2">
+                        { for picked.headers.iter().enumerate().map(|(column, header)| {
+                            let onchange = {
+                                let mapping = mapping.clone();
+                                Callback::from(move |e: Event| {
+                                    let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                    let mut next = (*mapping).clone();
+                                    next.assign(column, ImportField::from_key(&input.value()));
+                                    mapping.set(next);
+                                })
+                            };
+                            let current = mapping.fields.get(column).copied().flatten();
+                            let sample = picked.rows[0].get(column).cloned().unwrap_or_default();
+                            html! {
+                                <div class="grid grid-cols-1 md:grid-cols-3 gap-2 items-center text-sm">
+                                    <span class="font-semibold text-foreground truncate">{ if header.trim().is_empty() { format!("Column {}", column + 1) } else { header.clone() } }</span>
+                                    <span class="text-muted-foreground truncate">{ sample }</span>
+                                    <select {onchange} aria-label={format!("Field for {}", header)} class="p-2 border rounded text-sm">
+                                        <option value="" selected={current.is_none()}>{"Skip"}</option>
+                                        { for ImportField::ALL.iter().map(|field| html! {
+                                            <option value={field.key()} selected={current == Some(*field)}>{ field.label() }</option>
+                                        }) }
                                     </select>
-                                    <p class="text-xs text-muted-foreground mt-2">{"Currency updates are applied across the dashboard and reports."}</p>
                                 </div>
-                            </div>
+                            }
+                        }) }
+                    </div>
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-4 mt-4">
+                        <div>
+                            <label class="block text-sm font-medium text-foreground mb-2">{"Date format"}</label>
+                            <select onchange={{
+                                let mapping = mapping.clone();
+                                Callback::from(move |e: Event| {
+                                    let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                    if let Some(format) = ImportDateFormat::ALL.into_iter().find(|f| f.key() == input.value()) {
+                                        mapping.set(ImportMapping { date_format: format, ..(*mapping).clone() });
+                                    }
+                                })
+                            }} class="w-full p-2 border rounded text-sm">
+                                { for ImportDateFormat::ALL.iter().map(|format| html! {
+                                    <option value={format.key()} selected={mapping.date_format == *format}>{ format.label() }</option>
+                                }) }
+                            </select>
+                        </div>
+                        <div>
+                            <label class="block text-sm font-medium text-foreground mb-2">{"Default category"}</label>
+                            <input type="text" placeholder={IMPORT_FALLBACK_CATEGORY} value={mapping.default_category.clone()} oninput={{
+                                let mapping = mapping.clone();
+                                Callback::from(move |e: InputEvent| {
+                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                    mapping.set(ImportMapping { default_category: input.value(), ..(*mapping).clone() });
+                                })
+                            }} class="w-full p-2 border rounded text-sm" />
                         </div>
+                    </div>
 
-                        <div class="bg-card rounded-lg p-6 border border-border">
-                            <h2 class="text-xl font-bold text-foreground mb-6">{"Notifications"}</h2>
-                            <div class="space-y-4">
-                                <div class="flex items-start gap-3 pb-4 border-b border-border">
-                                    <div class="flex-1 pt-1">
-                                        <p class="font-medium text-foreground">{"Budget Alerts"}</p>
-                                        <p class="text-sm text-muted-foreground">{"Get notified when expenses exceed your plan"}</p>
-                                    </div>
-                                    <input type="checkbox" checked={*budget_alerts} onclick={{
-                                        let budget_alerts = budget_alerts.clone();
-                                        Callback::from(move |_| budget_alerts.set(!*budget_alerts))
-                                    }} />
-                                </div>
-                                <div class="flex items-start gap-3 pb-4 border-b border-border">
-                                    <div class="flex-1 pt-1">
-                                        <p class="font-medium text-foreground">{"Monthly Report"}</p>
-                                        <p class="text-sm text-muted-foreground">{"Receive a summary of your spending monthly"}</p>
-                                    </div>
-                                    <input type="checkbox" checked={*monthly_report} onclick={{
-                                        let monthly_report = monthly_report.clone();
-                                        Callback::from(move |_| monthly_report.set(!*monthly_report))
-                                    }} />
-                                </div>
-                                <div class="flex items-start gap-3">
-                                    <div class="flex-1 pt-1">
-                                        <p class="font-medium text-foreground">{"Saving Alert"}</p>
-                                        <p class="text-sm text-muted-foreground">{"Get notified when you reach a saving milestone"}</p>
-                                    </div>
-                                    <input type="checkbox" checked={*saving_alert} onclick={{
-                                        let saving_alert = saving_alert.clone();
-                                        Callback::from(move |_| saving_alert.set(!*saving_alert))
-                                    }} />
-                                </div>
-                            </div>
+                    <h3 class="font-medium text-foreground mt-6 mb-2">{"Preview"}</h3>
+                    if let Some(missing) = mapping.missing() {
+                        <p class="text-sm text-muted-foreground">{ missing }</p>
+                    } else {
+                        <div class="overflow-x-auto">
+                            <table class="w-full text-sm">
+                                <thead>
+                                    <tr class="text-left text-muted-foreground border-b border-border">
+                                        <th class="py-2 font-semibold">{"Date"}</th>
+                                        <th class="py-2 font-semibold">{"Description"}</th>
+                                        <th class="py-2 font-semibold">{"Category"}</th>
+                                        <th class="py-2 font-semibold text-right">{"Amount"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for parsed.iter().take(IMPORT_PREVIEW_ROWS).map(|row| match row {
+                                        Ok(row) => html! {
+                                            <tr class="border-b border-border last:border-0">
+                                                <td class="py-2 text-foreground">{ row.date.clone() }</td>
+                                                <td class="py-2 text-foreground">{ row.description.clone() }</td>
+                                                <td class="py-2 text-foreground">{ row.category.clone() }</td>
+                                                <td class="py-2 text-right text-foreground"><CurrencyAmount amount={row.amount} currency={currency.clone()} /></td>
+                                            </tr>
+                                        },
+                                        Err(msg) => html! {
+                                            <tr class="border-b border-border last:border-0">
+                                                <td colspan="4" class="py-2 text-red-500">{ format!("Skipped: {}", msg) }</td>
+                                            </tr>
+                                        },
+                                    }) }
+                                </tbody>
+                            </table>
                         </div>
+                        <p class="text-xs text-muted-foreground mt-2">
+                            { format!("{} of {} row(s) ready to import.", ready.len(), parsed.len()) }
+                            if skipped > 0 {
+                                { format!(" {} will be skipped.", skipped) }
+                            }
+                        </p>
+                    }
+                    <div class="flex gap-2 mt-4">
+                        <button type="button" onclick={on_import} disabled={ready.is_empty() || progress.is_some()} class="px-4 py-2 rounded-lg bg-primary text-primary-foreground text-sm font-bold disabled:opacity-50">{ format!("Import {} row(s)", ready.len()) }</button>
+                        <button type="button" onclick={on_cancel} disabled={progress.is_some()} class="px-4 py-2 rounded-lg border border-border text-sm font-bold disabled:opacity-50">{"Cancel"}</button>
                     </div>
-                </>
+                </div>
             }
-        ) }
+            if let Some((done, total)) = *progress {
+                { progress_status(format!("Importing {} of {}…", done, total), done, total) }
+            }
+            {
+                match &*result {
+                    Some(Ok(msg)) => html! { <p class="text-sm text-green-600 mt-3" role="status">{ msg.clone() }</p> },
+                    Some(Err(msg)) => html! { <p class="text-sm text-red-500 mt-3" role="alert">{ msg.clone() }</p> },
+                    None => html! {},
+                }
+            }
+        </div>
     }
 }
 
@@ -2172,18 +11453,18 @@ struct StatCardProps {
     title: &'static str,
     amount: i64,
     icon: StatIcon,
-    currency_symbol: String,
+    currency: CurrencyFormat,
 }
 
 #[function_component(StatCard)]
 fn stat_card(props: &StatCardProps) -> Html {
     html! {
-        <div class="bg-card p-6 rounded-[10px] shadow-sm border border-border flex justify-between items-start">
-            <div>
+        <div class="bg-card p-4 md:p-6 rounded-[10px] shadow-sm border border-border flex justify-between items-center md:items-start gap-3">
+            <div class="min-w-0">
                 <p class="text-muted-foreground text-[10px] font-bold mb-1 tracking-widest">{ props.title }</p>
-                <h3 class="text-2xl font-bold text-[#1D617A] tracking-tight">{ format_currency(props.amount, &props.currency_symbol) }</h3>
+                <h3 class="text-xl md:text-2xl font-bold text-primary tracking-tight truncate"><CurrencyAmount amount={props.amount} currency={props.currency.clone()} /></h3>
             </div>
-            <div class="p-3 bg-[#eef4f9] rounded-[10px]">
+            <div class="shrink-0 p-2 md:p-3 bg-muted rounded-[10px]">
                 {
                     match props.icon {
                         StatIcon::UpRight => icon_arrow_up_right(),
@@ -2196,85 +11477,918 @@ fn stat_card(props: &StatCardProps) -> Html {
     }
 }
 
-fn format_with_commas(value: i64) -> String {
-    let is_negative = value < 0;
-    let s = value.abs().to_string().chars().rev().collect::<Vec<char>>();
-    let mut out = Vec::new();
-    for (i, ch) in s.iter().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            out.push(',');
+#[derive(Properties, PartialEq)]
+struct EmptyStateProps {
+    icon: Html,
+    message: &'static str,
+    action_label: &'static str,
+    on_action: Callback<MouseEvent>,
+}
+
+/// Placeholder for an empty list that points at the action that fills it.
+#[function_component(EmptyState)]
+fn empty_state(props: &EmptyStateProps) -> Html {
+    html! {
+        <div class="flex flex-col items-center justify-center gap-3 py-8 text-center">
+            <div class="p-3 bg-muted rounded-[10px] text-primary">{ props.icon.clone() }</div>
+            <p class="text-sm text-muted-foreground">{ props.message }</p>
+            <button type="button" onclick={props.on_action.clone()} class="bg-primary text-primary-foreground px-4 py-2 rounded-[10px] text-xs font-bold uppercase shadow-md hover:opacity-90 transition-all">
+                { props.action_label }
+            </button>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ConfirmModalProps {
+    title: AttrValue,
+    message: AttrValue,
+    confirm_label: AttrValue,
+    on_confirm: Callback<()>,
+    on_cancel: Callback<()>,
+    /// Styles the confirm button as a destructive action.
+    #[prop_or_default]
+    destructive: bool,
+}
+
+/// In-app replacement for `window.confirm`. Focus starts on Cancel, Tab
+/// cycles between the two buttons, Escape cancels, and focus returns to the
+/// element that was focused when the modal opened.
+#[function_component(ConfirmModal)]
+fn confirm_modal(props: &ConfirmModalProps) -> Html {
+    let cancel_ref = use_node_ref();
+    let confirm_ref = use_node_ref();
+
+    {
+        let on_cancel = props.on_cancel.clone();
+        let cancel_ref = cancel_ref.clone();
+        let confirm_ref = confirm_ref.clone();
+        use_effect_with_deps(
+            move |_| {
+                let window = web_sys::window();
+                let previous = window
+                    .as_ref()
+                    .and_then(|w| w.document())
+                    .and_then(|d| d.active_element());
+                if let Some(button) = cancel_ref.cast::<web_sys::HtmlElement>() {
+                    let _ = button.focus();
+                }
+                let listener = window.map(|window| {
+                    // Capture phase, so page-level key handlers (shortcuts,
+                    // the transaction modal's Escape) never see these keys.
+                    let options = EventListenerOptions {
+                        phase: EventListenerPhase::Capture,
+                        passive: false,
+                    };
+                    EventListener::new_with_options(&window, "keydown", options, move |event| {
+                        let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() else {
+                            return;
+                        };
+                        event.stop_propagation();
+                        match event.key().as_str() {
+                            "Escape" => {
+                                event.prevent_default();
+                                on_cancel.emit(());
+                            }
+                            "Tab" => {
+                                event.prevent_default();
+                                let on_cancel_button = web_sys::window()
+                                    .and_then(|w| w.document())
+                                    .and_then(|d| d.active_element())
+                                    .zip(cancel_ref.get())
+                                    .is_some_and(|(active, cancel)| {
+                                        active.is_same_node(Some(&cancel))
+                                    });
+                                let next = if on_cancel_button {
+                                    &confirm_ref
+                                } else {
+                                    &cancel_ref
+                                };
+                                if let Some(button) = next.cast::<web_sys::HtmlElement>() {
+                                    let _ = button.focus();
+                                }
+                            }
+                            _ => {}
+                        }
+                    })
+                });
+                move || {
+                    drop(listener);
+                    if let Some(element) =
+                        previous.and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok())
+                    {
+                        let _ = element.focus();
+                    }
+                }
+            },
+            (),
+        );
+    }
+
+    let on_cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            on_cancel.emit(());
+        })
+    };
+    let on_confirm = {
+        let on_confirm = props.on_confirm.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            on_confirm.emit(());
+        })
+    };
+    let confirm_class = if props.destructive {
+        "bg-red-600 text-white"
+    } else {
+        "bg-primary text-primary-foreground"
+    };
+
+    html! {
+        <div class="fixed inset-0 z-[60] flex items-center justify-center bg-black/40" onclick={on_cancel.clone()}>
+            <div class="w-full max-w-sm bg-card border border-border rounded-xl shadow-lg p-6" role="alertdialog" aria-modal="true"
+                aria-labelledby="confirm-modal-title" aria-describedby="confirm-modal-message"
+                onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}>
+                <h3 id="confirm-modal-title" class="font-bold text-foreground text-lg mb-2">{ props.title.clone() }</h3>
+                <p id="confirm-modal-message" class="text-sm text-muted-foreground mb-6">{ props.message.clone() }</p>
+                <div class="flex justify-end gap-2">
+                    <button ref={cancel_ref} type="button" onclick={on_cancel} class="px-4 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{"Cancel"}</button>
+                    <button ref={confirm_ref} type="button" onclick={on_confirm} class={classes!("px-4", "py-2", "rounded-[10px]", "text-xs", "font-bold", confirm_class)}>{ props.confirm_label.clone() }</button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Focuses whatever element `node` is attached to, if it is mounted.
+fn focus_node(node: &NodeRef) {
+    if let Some(element) = node.cast::<web_sys::HtmlElement>() {
+        let _ = element.focus();
+    }
+}
+
+/// Focuses the input behind `node`, e.g. to jump to an add form.
+fn focus_input_callback(node: &NodeRef) -> Callback<MouseEvent> {
+    let node = node.clone();
+    Callback::from(move |_| {
+        if let Some(input) = node.cast::<web_sys::HtmlInputElement>() {
+            let _ = input.focus();
+        }
+    })
+}
+
+/// Groups the digits of a whole number for the given style. Western and
+/// European group by thousands; Indian groups the last three digits, then
+/// pairs (12,34,567).
+fn format_grouped(value: i64, style: GroupingStyle) -> String {
+    let (separator, _) = style.separators();
+    let digits = value.unsigned_abs().to_string();
+    let mut out: Vec<char> = Vec::new();
+    for (i, ch) in digits.chars().rev().enumerate() {
+        let boundary = match style {
+            GroupingStyle::Indian => i == 3 || (i > 3 && (i - 3) % 2 == 0),
+            _ => i > 0 && i % 3 == 0,
+        };
+        if boundary {
+            out.push(separator);
         }
-        out.push(*ch);
+        out.push(ch);
     }
     let formatted: String = out.into_iter().rev().collect();
-    if is_negative {
+    if value < 0 {
         format!("-{}", formatted)
     } else {
         formatted
     }
 }
 
-fn format_currency(amount: i64, symbol: &str) -> String {
-    let sign = if amount < 0 { "-" } else { "" };
-    format!("{}{} {}.00", sign, symbol, format_with_commas(amount.abs()))
+/// Amounts are stored in whole units, so any decimals shown are zeros.
+fn format_currency(amount: i64, currency: &CurrencyFormat) -> String {
+    let (_, decimal_separator) = currency.grouping.separators();
+    let decimals = if currency.decimal_places > 0 {
+        format!(
+            "{}{}",
+            decimal_separator,
+            "0".repeat(currency.decimal_places as usize)
+        )
+    } else {
+        String::new()
+    };
+    let number = format!(
+        "{}{}",
+        format_grouped(amount.abs(), currency.grouping),
+        decimals
+    );
+    let gap = if currency.symbol_spacing { " " } else { "" };
+    if amount >= 0 {
+        return format!("{}{}{}", currency.symbol, gap, number);
+    }
+    match currency.negative_style {
+        NegativeStyle::LeadingSign => format!("-{}{}{}", currency.symbol, gap, number),
+        NegativeStyle::SignAfterSymbol => format!("{}{}-{}", currency.symbol, gap, number),
+        NegativeStyle::Parentheses => format!("({}{}{})", currency.symbol, gap, number),
+    }
+}
+
+/// Like `format_currency`, but marks positive amounts with a leading `+`.
+fn format_signed_currency(amount: i64, currency: &CurrencyFormat) -> String {
+    if amount > 0 {
+        format!("+{}", format_currency(amount, currency))
+    } else {
+        format_currency(amount, currency)
+    }
+}
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Weekday of a day number from `days_from_civil`, Sunday = 0.
+fn weekday_of(days: i64) -> usize {
+    (days + 4).rem_euclid(7) as usize
+}
+
+/// Spending per weekday as `(total, average)`, indexed like `WEEKDAY_NAMES`.
+/// The average divides by how many of that weekday fall between the first
+/// and last expense, so quiet days pull it down. Rows whose date doesn't
+/// parse are skipped.
+fn weekday_spending(
+    transactions: &[Transaction],
+    savings_as_spending: bool,
+    rounding: RoundingMode,
+) -> [(i64, i64); 7] {
+    let mut totals = [0i64; 7];
+    let mut span: Option<(i64, i64)> = None;
+    for tx in transactions
+        .iter()
+        .filter(|tx| is_spending(tx, savings_as_spending))
+    {
+        let Some(day) = day_number(&tx.date) else {
+            continue;
+        };
+        totals[weekday_of(day)] += tx.amount.abs();
+        span = Some(match span {
+            Some((first, last)) => (first.min(day), last.max(day)),
+            None => (day, day),
+        });
+    }
+
+    let mut occurrences = [0i64; 7];
+    if let Some((first, last)) = span {
+        for day in first..=last {
+            occurrences[weekday_of(day)] += 1;
+        }
+    }
+    std::array::from_fn(|i| (totals[i], round_ratio(totals[i], occurrences[i], rounding)))
+}
+
+/// Seven bars, Monday first, scaled to the busiest day, which is highlighted.
+fn weekday_chart(spending: &[(i64, i64); 7], currency: &CurrencyFormat) -> Html {
+    let order = [1, 2, 3, 4, 5, 6, 0];
+    let max = spending.iter().map(|(total, _)| *total).max().unwrap_or(0);
+    if max == 0 {
+        return html! { <p class="text-sm text-muted-foreground">{"No spending yet."}</p> };
+    }
+    let busiest = order
+        .into_iter()
+        .max_by_key(|i| (spending[*i].0, std::cmp::Reverse(*i)))
+        .unwrap_or(0);
+
+    html! {
+        <>
+            <div class="flex items-end gap-2 h-32" role="img" aria-label={format!("Most spending on {}", WEEKDAY_NAMES[busiest])}>
+                { for order.into_iter().map(|i| {
+                    let (total, average) = spending[i];
+                    let height = format!("height: {}%", percent_of(total, max).max(1));
+                    let color = if i == busiest { "bg-primary" } else { "bg-secondary" };
+                    html! {
+                        <div class="flex-1 h-full flex flex-col justify-end" title={format!("{}: {} total, {} on average", WEEKDAY_NAMES[i], format_currency(total, currency), format_currency(average, currency))}>
+                            <div class={classes!("w-full", "rounded-t", color)} style={height}></div>
+                        </div>
+                    }
+                }) }
+            </div>
+            <div class="flex gap-2 mt-1">
+                { for order.into_iter().map(|i| html! {
+                    <span class={classes!("flex-1", "text-center", "text-[10px]", if i == busiest { "font-bold text-foreground" } else { "text-muted-foreground" })}>{ &WEEKDAY_NAMES[i][..3] }</span>
+                }) }
+            </div>
+            <p class="text-sm text-muted-foreground mt-3">
+                { format!("You spend the most on {}: ", WEEKDAY_NAMES[busiest]) }
+                <span class="font-semibold text-foreground"><CurrencyAmount amount={spending[busiest].0} currency={currency.clone()} /></span>
+                {" in total, "}
+                <span class="font-semibold text-foreground"><CurrencyAmount amount={spending[busiest].1} currency={currency.clone()} /></span>
+                { format!(" on an average {}.", WEEKDAY_NAMES[busiest]) }
+            </p>
+        </>
+    }
+}
+
+/// Year, month and day of a `YYYY-MM-DD` date or ISO timestamp.
+fn parse_ymd(date: &str) -> Option<(i64, u32, u32)> {
+    let bytes = date.as_bytes();
+    if bytes.len() < 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    let (year, month, day) = (date.get(0..4)?, date.get(5..7)?, date.get(8..10)?);
+    if !(digits(year) && digits(month) && digits(day)) {
+        return None;
+    }
+    let (month, day) = (month.parse().ok()?, day.parse().ok()?);
+    if !(1..=12).contains(&month) || day == 0 {
+        return None;
+    }
+    Some((year.parse().ok()?, month, day))
+}
+
+fn next_month(year: i64, month: u32) -> (i64, u32) {
+    if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    }
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    let (next_year, next) = next_month(year, month);
+    (days_from_civil(next_year, next, 1) - days_from_civil(year, month, 1)) as u32
+}
+
+/// First day, as `days_from_civil`, of the period that starts in
+/// `year`-`month`. All period boundaries come from here: a period runs from
+/// `month_start_day` to the day before the next period's start, and a start
+/// day past the end of a short month falls on its last day, so a 31st start
+/// runs Feb 28 (29) to Mar 30.
+fn period_start(year: i64, month: u32, month_start_day: u32) -> i64 {
+    let day = month_start_day.clamp(1, days_in_month(year, month));
+    days_from_civil(year, month, day)
+}
+
+/// The period a day falls in, as the `(year, month)` it starts in.
+fn period_of(year: i64, month: u32, day: u32, month_start_day: u32) -> (i64, u32) {
+    if days_from_civil(year, month, day) >= period_start(year, month, month_start_day) {
+        (year, month)
+    } else if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    }
+}
+
+/// First day of the week containing `days`, as `days_from_civil`.
+fn week_start_of(days: i64, week_start: u32) -> i64 {
+    // 1970-01-01 was a Thursday
+    let weekday = (days + 4).rem_euclid(7);
+    days - (weekday - week_start as i64).rem_euclid(7)
+}
+
+/// Returns the `YYYY-MM` period of a transaction date (`YYYY-MM-DD` or an
+/// ISO timestamp), or `None` if the prefix doesn't look like a date. With a
+/// later month start, days before it belong to the previous month's period.
+fn month_key(date: &str, bounds: PeriodBounds) -> Option<String> {
+    let (year, month, day) = parse_ymd(date)?;
+    let (year, month) = period_of(year, month, day, bounds.month_start_day);
+    Some(format!("{:04}-{:02}", year, month))
+}
+
+fn current_year() -> i32 {
+    js_sys::Date::new_0().get_full_year() as i32
+}
+
+fn year_of(date: &str) -> Option<i32> {
+    date.get(0..4)?.parse().ok()
+}
+
+/// Distinct transaction years, newest first; always includes the current year.
+fn transaction_years(transactions: &[Transaction]) -> Vec<i32> {
+    let mut years: Vec<i32> = transactions
+        .iter()
+        .filter_map(|tx| year_of(&tx.date))
+        .chain(std::iter::once(current_year()))
+        .collect();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years.dedup();
+    years
+}
+
+fn current_month_key(bounds: PeriodBounds) -> String {
+    month_key(&today_key(), bounds).unwrap_or_default()
+}
+
+/// Today's date as `YYYY-MM-DD`, the format of `<input type="date">`.
+fn today_key() -> String {
+    let now = js_sys::Date::new_0();
+    format!(
+        "{:04}-{:02}-{:02}",
+        now.get_full_year(),
+        now.get_month() + 1,
+        now.get_date()
+    )
+}
+
+fn previous_month_key(key: &str) -> Option<String> {
+    let year = key.get(0..4)?.parse::<i32>().ok()?;
+    let month = key.get(5..7)?.parse::<u32>().ok()?;
+    if month <= 1 {
+        Some(format!("{:04}-12", year - 1))
+    } else {
+        Some(format!("{:04}-{:02}", year, month - 1))
+    }
+}
+
+/// Sums income and expenses (as a positive magnitude) for one `YYYY-MM` period.
+fn month_totals(transactions: &[Transaction], key: &str, bounds: PeriodBounds) -> (i64, i64) {
+    income_expense_totals(
+        transactions
+            .iter()
+            .filter(|tx| month_key(&tx.date, bounds).as_deref() == Some(key)),
+    )
+}
+
+/// Sums income and expenses (as a positive magnitude). Transfers are skipped
+/// since they net out across accounts.
+fn income_expense_totals<'a>(
+    transactions: impl IntoIterator<Item = &'a Transaction>,
+) -> (i64, i64) {
+    transactions
+        .into_iter()
+        .filter(|tx| !is_transfer(tx))
+        .fold((0, 0), |(income, expenses), tx| {
+            if tx.amount > 0 {
+                (income + tx.amount, expenses)
+            } else {
+                (income, expenses + tx.amount.abs())
+            }
+        })
+}
+
+/// The `count` month keys ending at `current_key`, oldest first.
+fn recent_month_keys(current_key: &str, count: usize) -> Vec<String> {
+    let mut keys = vec![current_key.to_string()];
+    while keys.len() < count {
+        match keys.last().and_then(|key| previous_month_key(key)) {
+            Some(prev) => keys.push(prev),
+            None => break,
+        }
+    }
+    keys.reverse();
+    keys
+}
+
+/// Spend in one category for each of `months`, ignoring transfers.
+fn category_month_series(
+    transactions: &[Transaction],
+    category: &str,
+    months: &[String],
+    bounds: PeriodBounds,
+    savings_as_spending: bool,
+) -> Vec<i64> {
+    months
+        .iter()
+        .map(|month| {
+            transactions
+                .iter()
+                .filter(|tx| {
+                    is_spending(tx, savings_as_spending)
+                        && category_key(&tx.category) == category_key(category)
+                        && month_key(&tx.date, bounds).as_ref() == Some(month)
+                })
+                .map(|tx| tx.amount.abs())
+                .sum()
+        })
+        .collect()
+}
+
+const PROJECTION_WIDTH: f64 = 600.0;
+const PROJECTION_HEIGHT: f64 = 200.0;
+
+/// Running total of the goal's contributions as `(day number, saved)`, one
+/// point per contribution date in date order.
+fn cumulative_contributions(contributions: &[Contribution]) -> Vec<(i64, i64)> {
+    let mut dated: Vec<(i64, i64)> = contributions
+        .iter()
+        .filter_map(|c| Some((day_number(&c.date)?, c.amount)))
+        .collect();
+    dated.sort_by_key(|(day, _)| *day);
+    let mut points: Vec<(i64, i64)> = Vec::new();
+    let mut total = 0;
+    for (day, amount) in dated {
+        total += amount;
+        match points.last_mut() {
+            Some(last) if last.0 == day => last.1 = total,
+            _ => points.push((day, total)),
+        }
+    }
+    points
+}
+
+/// Saved-over-time line against the straight pace from the first
+/// contribution to `target_amount` on `target_date`. Goals without a target
+/// date get the saved line only.
+fn goal_projection_chart(
+    goal: &SavingGoalState,
+    currency: &CurrencyFormat,
+    rounding: RoundingMode,
+) -> Html {
+    let today = day_number(&today_key()).unwrap_or_default();
+    let actual = cumulative_contributions(&goal.contributions);
+    let start = actual.first().map(|(day, _)| *day).unwrap_or(today);
+    let saved = actual.last().map(|(_, total)| *total).unwrap_or(0);
+    // the pace line needs a target date after the start
+    let target_day = day_number(&goal.target_date).filter(|day| *day > start);
+
+    let end = [Some(today), target_day, actual.last().map(|(day, _)| *day)]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(today)
+        .max(start + 1);
+    let top = actual
+        .iter()
+        .map(|(_, total)| *total)
+        .chain([goal.target_amount, 1])
+        .max()
+        .unwrap_or(1);
+    let x = |day: i64| (day - start) as f64 / (end - start) as f64 * PROJECTION_WIDTH;
+    let y = |amount: i64| PROJECTION_HEIGHT - amount.max(0) as f64 / top as f64 * PROJECTION_HEIGHT;
+
+    // saved steps up on each contribution date and holds until today
+    let mut actual_points = vec![format!("{:.1},{:.1}", x(start), y(0))];
+    let mut previous = 0;
+    for (day, total) in &actual {
+        actual_points.push(format!("{:.1},{:.1}", x(*day), y(previous)));
+        actual_points.push(format!("{:.1},{:.1}", x(*day), y(*total)));
+        previous = *total;
+    }
+    actual_points.push(format!("{:.1},{:.1}", x(today.max(start)), y(saved)));
+
+    // where the pace line says the goal should be today
+    let pace_today = target_day.map(|target| {
+        let elapsed = (today - start).clamp(0, target - start);
+        round_ratio(goal.target_amount * elapsed, target - start, rounding)
+    });
+    let (status, status_class) = match pace_today {
+        _ if goal.target_amount > 0 && saved >= goal.target_amount => {
+            ("Target reached.".to_string(), "text-green-600")
+        }
+        Some(pace) if saved >= pace => (
+            format!(
+                "On track — {} ahead of pace.",
+                format_currency(saved - pace, currency)
+            ),
+            "text-green-600",
+        ),
+        Some(pace) => (
+            format!(
+                "Behind pace by {}.",
+                format_currency(pace - saved, currency)
+            ),
+            "text-red-600",
+        ),
+        None if goal.target_date.is_empty() => (
+            "Set a target date to see the pace needed.".to_string(),
+            "text-muted-foreground",
+        ),
+        None => (
+            "The target date is before the first contribution, so there is no pace to show."
+                .to_string(),
+            "text-muted-foreground",
+        ),
+    };
+
+    html! {
+        <div class="bg-card rounded-2xl shadow-md border border-border p-5">
+            <div class="flex items-center justify-between mb-4">
+                <h3 class="font-bold text-foreground text-lg">{"Progress vs Pace"}</h3>
+                <div class="flex items-center gap-4 text-xs text-muted-foreground">
+                    <span class="flex items-center gap-1"><span class="w-3 h-0.5 bg-primary"></span>{"Saved"}</span>
+                    if target_day.is_some() {
+                        <span class="flex items-center gap-1"><span class="w-3 h-0.5 border-t-2 border-dashed border-secondary"></span>{"Pace to target"}</span>
+                    }
+                </div>
+            </div>
+            <svg class="w-full h-48" viewBox={format!("0 0 {} {}", PROJECTION_WIDTH, PROJECTION_HEIGHT)} preserveAspectRatio="none" role="img" aria-label={status.clone()}>
+                <line x1="0" y1={y(goal.target_amount).to_string()} x2={PROJECTION_WIDTH.to_string()} y2={y(goal.target_amount).to_string()} stroke="#e2e8f0" stroke-width="1" />
+                if let Some(target) = target_day {
+                    <line x1={x(start).to_string()} y1={y(0).to_string()} x2={x(target).to_string()} y2={y(goal.target_amount).to_string()} stroke="#B2CBDE" stroke-width="2" stroke-dasharray="6 4" vector-effect="non-scaling-stroke" />
+                }
+                <polyline points={actual_points.join(" ")} fill="none" stroke="#173E63" stroke-width="2.5" stroke-linejoin="round" vector-effect="non-scaling-stroke" />
+            </svg>
+            <div class="flex justify-between text-[10px] text-muted-foreground mt-1">
+                <span>{ date_key_from_days(start) }</span>
+                <span>{ date_key_from_days(end) }</span>
+            </div>
+            <p class={classes!("text-sm", "font-semibold", "mt-3", status_class)}>{ status }</p>
+        </div>
+    }
+}
+
+/// Tiny inline trend line scaled to the series' own max; an all-zero series
+/// draws a flat line labelled "no history".
+fn sparkline(values: &[i64]) -> Html {
+    const WIDTH: f64 = 64.0;
+    const HEIGHT: f64 = 18.0;
+    let max = values.iter().copied().max().unwrap_or(0);
+    let step = if values.len() > 1 {
+        WIDTH / (values.len() - 1) as f64
+    } else {
+        0.0
+    };
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            let y = if max > 0 {
+                HEIGHT - 2.0 - (*value as f64 / max as f64) * (HEIGHT - 4.0)
+            } else {
+                HEIGHT / 2.0
+            };
+            format!("{:.1},{:.1}", idx as f64 * step, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    html! {
+        <span class="flex items-center gap-1" title="Last 6 months">
+            <svg width={WIDTH.to_string()} height={HEIGHT.to_string()} viewBox={format!("0 0 {} {}", WIDTH, HEIGHT)} aria-hidden="true">
+                <polyline points={points} fill="none" stroke={if max > 0 { "#1D617A" } else { "#B2CBDE" }} stroke-width="1.5" stroke-linejoin="round" stroke-linecap="round" />
+            </svg>
+            if max == 0 {
+                <span class="text-[10px] text-muted-foreground">{"no history"}</span>
+            }
+        </span>
+    }
+}
+
+/// Proposed monthly limit per category: the average spend over the three
+/// full months before `current_key`. Months with no transactions at all are
+/// left out of the average, and categories without history are skipped.
+fn suggested_limits(
+    transactions: &[Transaction],
+    current_key: &str,
+    bounds: PeriodBounds,
+    savings_as_spending: bool,
+    rounding: RoundingMode,
+) -> Vec<(String, i64)> {
+    let mut months = Vec::new();
+    let mut key = current_key.to_string();
+    for _ in 0..3 {
+        match previous_month_key(&key) {
+            Some(prev) => {
+                months.push(prev.clone());
+                key = prev;
+            }
+            None => break,
+        }
+    }
+
+    let active_months = months
+        .iter()
+        .filter(|m| {
+            transactions
+                .iter()
+                .any(|tx| month_key(&tx.date, bounds).as_ref() == Some(*m))
+        })
+        .count() as i64;
+    if active_months == 0 {
+        return vec![];
+    }
+
+    let totals = sum_by_category(
+        transactions
+            .iter()
+            .filter(|tx| {
+                let in_window = month_key(&tx.date, bounds)
+                    .map(|m| months.contains(&m))
+                    .unwrap_or(false);
+                in_window && is_spending(tx, savings_as_spending)
+            })
+            .map(|tx| (tx.category.as_str(), tx.amount.abs())),
+    );
+
+    let mut averages: Vec<(String, i64)> = totals
+        .into_iter()
+        .map(|(category, total)| (category, round_ratio(total, active_months, rounding)))
+        .filter(|(_, average)| *average > 0)
+        .collect();
+    averages.sort_by_key(|b| std::cmp::Reverse(b.1));
+    averages
 }
 
-#[function_component(App)]
-fn app() -> Html {
-    let active_page = use_state(|| Page::Dashboard);
-    let auth_status = use_state(|| AuthStatus::Checking);
-    let settings = use_state(load_settings);
-    let on_select = {
-        let active_page = active_page.clone();
-        Callback::from(move |page: Page| active_page.set(page))
-    };
+#[function_component(App)]
+fn app() -> Html {
+    let settings = use_state(load_settings);
+    let active_page = {
+        let landing = settings.default_page;
+        use_state(move || landing)
+    };
+    let auth_status = use_state(|| AuthStatus::Checking);
+    let unsaved_forms = use_state(UnsavedForms::default);
+    let known_categories = KnownCategories(use_state(Vec::<String>::new));
+    {
+        let known_categories = known_categories.clone();
+        use_effect_with_deps(
+            move |status| {
+                if *status == AuthStatus::Authenticated {
+                    spawn_local(async move {
+                        let list = match fetch_categories().await {
+                            Some(list) => distinct_categories(list.iter().map(String::as_str)),
+                            None => local_categories(),
+                        };
+                        known_categories.0.set(list);
+                    });
+                }
+                || ()
+            },
+            *auth_status,
+        );
+    }
+    let leave_to = use_state(|| None::<Page>);
+    // read by `on_select`, which keyboard shortcuts hold on to across renders
+    let current_page = use_mut_ref(|| *active_page);
+    *current_page.borrow_mut() = *active_page;
+    let on_select = {
+        let active_page = active_page.clone();
+        let unsaved_forms = unsaved_forms.clone();
+        let leave_to = leave_to.clone();
+        Callback::from(move |page: Page| {
+            let leaving = page != *current_page.borrow();
+            if leaving && load_settings().confirm_on_leave && !unsaved_forms.0.borrow().is_empty() {
+                leave_to.set(Some(page));
+            } else {
+                active_page.set(page);
+            }
+        })
+    };
+    let show_shortcuts = use_state(|| false);
+    let onboarding = use_state(load_onboarding);
+    let open_add = use_state(|| false);
+    let duplicate_draft = use_state(|| None::<Transaction>);
+    let sw_update = use_state(|| None::<web_sys::ServiceWorker>);
+    let session_warning = use_state(|| false);
+    // bumped after each proactive refresh so the expiry timers re-arm
+    let session_epoch = use_state(|| 0u32);
+    let nav_status = use_state_eq(|| NavStatus {
+        goal_completed: goal_completed(&load_saving_goal()),
+        ..NavStatus::default()
+    });
+    let summary = SummaryContext {
+        state: use_state(SummaryState::default),
+        pending: use_mut_ref(|| None),
+        epoch: use_mut_ref(|| 0),
+    };
+    {
+        // drop the previous user's totals and badges once signed out
+        let summary = summary.clone();
+        let nav_status = nav_status.clone();
+        use_effect_with_deps(
+            move |status| {
+                if *status == AuthStatus::Unauthenticated {
+                    summary.clear();
+                    nav_status.set(NavStatus::default());
+                }
+                || ()
+            },
+            *auth_status,
+        );
+    }
+    {
+        // retry Savings transactions that failed to post, on sign-in and
+        // whenever the browser comes back online
+        let summary = summary.clone();
+        use_effect_with_deps(
+            move |status| {
+                let mut listener = None;
+                if *status == AuthStatus::Authenticated {
+                    let flush = move || {
+                        let summary = summary.clone();
+                        spawn_local(async move {
+                            if flush_pending_savings().await > 0 {
+                                summary.refresh();
+                            }
+                        });
+                    };
+                    flush.clone()();
+                    listener = web_sys::window()
+                        .map(|window| EventListener::new(&window, "online", move |_| flush()));
+                }
+                move || drop(listener)
+            },
+            *auth_status,
+        );
+    }
+    {
+        // checked once per sign-in; the reminder itself limits to once per period
+        let settings = (*settings).clone();
+        use_effect_with_deps(
+            move |status| {
+                if *status == AuthStatus::Authenticated {
+                    remind_goal_contribution(&settings);
+                }
+                || ()
+            },
+            *auth_status,
+        );
+    }
+    let on_duplicate = {
+        let active_page = active_page.clone();
+        let duplicate_draft = duplicate_draft.clone();
+        Callback::from(move |tx: Transaction| {
+            duplicate_draft.set(Some(tx));
+            active_page.set(Page::Dashboard);
+        })
+    };
+
+    {
+        let sw_update = sw_update.clone();
+        use_effect_with_deps(
+            move |_| {
+                let listeners = register_service_worker(Callback::from(move |worker| {
+                    sw_update.set(Some(worker))
+                }));
+                move || drop(listeners)
+            },
+            (),
+        );
+    }
+
+    {
+        let deps = (*auth_status, *show_shortcuts);
+        let on_select = on_select.clone();
+        let show_shortcuts = show_shortcuts.clone();
+        let open_add = open_add.clone();
+        use_effect_with_deps(
+            move |(status, shortcuts_visible)| {
+                let status = *status;
+                let shortcuts_visible = *shortcuts_visible;
+                // `g` arms a two-key sequence; the next key picks the page
+                let pending_g = Rc::new(Cell::new(false));
+                let listener = web_sys::window().map(|window| {
+                    EventListener::new(&window, "keydown", move |event| {
+                        let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() else {
+                            return;
+                        };
+                        if status != AuthStatus::Authenticated
+                            || event.ctrl_key()
+                            || event.meta_key()
+                            || event.alt_key()
+                            || is_typing_target(event)
+                        {
+                            return;
+                        }
+
+                        let key = event.key();
+                        if pending_g.replace(false) {
+                            if let Some((_, _, page)) =
+                                SHORTCUT_PAGES.iter().find(|(k, _, _)| *k == key)
+                            {
+                                event.prevent_default();
+                                on_select.emit(*page);
+                            }
+                            return;
+                        }
+
+                        match key.as_str() {
+                            "g" => pending_g.set(true),
+                            "n" => {
+                                event.prevent_default();
+                                on_select.emit(Page::Dashboard);
+                                open_add.set(true);
+                            }
+                            "?" => show_shortcuts.set(!shortcuts_visible),
+                            "Escape" if shortcuts_visible => show_shortcuts.set(false),
+                            _ => {}
+                        }
+                    })
+                });
+                move || drop(listener)
+            },
+            deps,
+        );
+    }
 
     {
         let auth_status = auth_status.clone();
         use_effect_with_deps(
             move |_| {
                 spawn_local(async move {
-                    let url = format!("{}/api/auth/refresh", API_BASE_URL);
-                    let response = Request::post(&url)
-                        .credentials(RequestCredentials::Include)
-                        .send()
-                        .await;
-
-                    match response {
-                        Ok(resp) if resp.ok() => {
-                            if let Ok(json) = resp.json::<serde_json::Value>().await {
-                                if let Some(token) =
-                                    json.get("access_token").and_then(|v| v.as_str())
-                                {
-                                    if let Some(window) = web_sys::window() {
-                                        if let Ok(Some(storage)) = window.local_storage() {
-                                            let _ = storage.set_item("access_token", token);
-                                        }
-                                    }
-                                }
-                            }
+                    // stays `Checking` until the retries settle
+                    if refresh_access_token_with_retry().await {
+                        auth_status.set(AuthStatus::Authenticated);
+                    } else {
+                        // Fallback to existing access token (keeps user logged in on refresh)
+                        if token_store::get().is_some() {
                             auth_status.set(AuthStatus::Authenticated);
-                        }
-                        _ => {
-                            // Fallback to existing access token (keeps user logged in on refresh)
-                            let mut has_token = false;
-                            if let Some(window) = web_sys::window() {
-                                if let Ok(Some(storage)) = window.local_storage() {
-                                    if let Ok(token_opt) = storage.get_item("access_token") {
-                                        if let Some(token) = token_opt {
-                                            if !token.is_empty() {
-                                                has_token = true;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-
-                            if has_token {
-                                auth_status.set(AuthStatus::Authenticated);
-                            } else {
-                                auth_status.set(AuthStatus::Unauthenticated);
-                            }
+                        } else {
+                            auth_status.set(AuthStatus::Unauthenticated);
                         }
                     }
                 });
@@ -2284,11 +12398,83 @@ fn app() -> Html {
         );
     }
 
+    // Session expiry: warn a minute ahead, then fall back to the sign-in
+    // screen once the access token has actually expired.
+    {
+        let deps = (*auth_status, *session_epoch);
+        let auth_status = auth_status.clone();
+        let session_warning = session_warning.clone();
+        use_effect_with_deps(
+            move |(status, _)| {
+                let mut timers = Vec::new();
+                let expiry = (*status == AuthStatus::Authenticated)
+                    .then(access_token_expiry_ms)
+                    .flatten();
+                if let Some(expiry) = expiry {
+                    let remaining = expiry - js_sys::Date::now();
+                    let delay = |ms: f64| ms.clamp(0.0, u32::MAX as f64) as u32;
+                    if remaining > 0.0 {
+                        let warn = session_warning.clone();
+                        timers.push(Timeout::new(
+                            delay(remaining - SESSION_WARNING_MS),
+                            move || warn.set(true),
+                        ));
+                        timers.push(Timeout::new(delay(remaining), move || {
+                            session_warning.set(false);
+                            token_store::clear();
+                            auth_status.set(AuthStatus::Unauthenticated);
+                        }));
+                    }
+                }
+                move || drop(timers)
+            },
+            deps,
+        );
+    }
+
+    let on_stay_signed_in = {
+        let session_warning = session_warning.clone();
+        let session_epoch = session_epoch.clone();
+        Callback::from(move |_| {
+            let session_warning = session_warning.clone();
+            let session_epoch = session_epoch.clone();
+            spawn_local(async move {
+                if refresh_access_token().await {
+                    session_warning.set(false);
+                    session_epoch.set(*session_epoch + 1);
+                }
+            });
+        })
+    };
+
     let content = match *active_page {
-        Page::Dashboard => html! { <DashboardPage /> },
+        Page::Dashboard => html! {
+            <DashboardPage
+                open_add={*open_add}
+                on_open_add_handled={{
+                    let open_add = open_add.clone();
+                    Callback::from(move |_| open_add.set(false))
+                }}
+                draft={(*duplicate_draft).clone()}
+                on_draft_handled={{
+                    let duplicate_draft = duplicate_draft.clone();
+                    Callback::from(move |_| duplicate_draft.set(None))
+                }}
+                on_navigate={on_select.clone()}
+                on_first_run={{
+                    let onboarding = onboarding.clone();
+                    Callback::from(move |_| {
+                        if *onboarding == Onboarding::NotStarted {
+                            save_onboarding(Onboarding::Step(0));
+                            onboarding.set(Onboarding::Step(0));
+                        }
+                    })
+                }}
+            />
+        },
         Page::Budget => html! { <BudgetPage /> },
-        Page::Income => html! { <IncomePage /> },
-        Page::Expense => html! { <ExpensePage /> },
+        Page::Income => html! { <IncomePage on_duplicate={on_duplicate.clone()} /> },
+        Page::Expense => html! { <ExpensePage on_duplicate={on_duplicate.clone()} /> },
         Page::Savings => html! { <SavingsPage /> },
         Page::Summary => html! { <SummaryPage /> },
         Page::Settings => html! { <SettingsPage /> },
@@ -2308,13 +12494,415 @@ fn app() -> Html {
 
     html! {
         <ContextProvider<UseStateHandle<AppSettings>> context={settings}>
-            <Layout active_page={*active_page} on_select={on_select}>
+        <ContextProvider<SummaryContext> context={summary}>
+        <ContextProvider<UseStateHandle<NavStatus>> context={nav_status}>
+        <ContextProvider<UnsavedForms> context={(*unsaved_forms).clone()}>
+        <ContextProvider<KnownCategories> context={known_categories.clone()}>
+            <Layout active_page={*active_page} on_select={on_select.clone()}>
                 { content }
             </Layout>
+            <datalist id={CATEGORY_LIST_ID}>
+                { for known_categories.0.iter().map(|category| html! { <option value={category.clone()} /> }) }
+            </datalist>
+            if let Some(page) = *leave_to {
+                <ConfirmModal
+                    title="Discard unsaved changes?"
+                    message="A form on this page has input that hasn't been saved. Leaving will discard it."
+                    confirm_label="Discard and leave"
+                    destructive=true
+                    on_confirm={{
+                        let leave_to = leave_to.clone();
+                        let unsaved_forms = unsaved_forms.clone();
+                        Callback::from(move |_| {
+                            unsaved_forms.0.borrow_mut().clear();
+                            leave_to.set(None);
+                            active_page.set(page);
+                        })
+                    }}
+                    on_cancel={{
+                        let leave_to = leave_to.clone();
+                        Callback::from(move |_| leave_to.set(None))
+                    }}
+                />
+            }
+            if let Onboarding::Step(step) = *onboarding {
+                { onboarding_card(step, &onboarding, &on_select) }
+            }
+            if *show_shortcuts {
+                <ShortcutsOverlay on_close={Callback::from(move |_| show_shortcuts.set(false))} />
+            }
+            if *session_warning {
+                <div class="fixed top-4 left-1/2 -translate-x-1/2 z-50 flex items-center gap-3 bg-amber-50 border border-amber-300 text-amber-900 text-sm px-4 py-3 rounded-[10px] shadow-lg" role="status">
+                    <span>{"Your session expires in less than a minute."}</span>
+                    <button type="button" onclick={on_stay_signed_in} class="font-bold underline">{"Stay signed in"}</button>
+                </div>
+            }
+            if let Some(worker) = &*sw_update {
+                <div class="fixed bottom-6 left-1/2 -translate-x-1/2 z-50 flex items-center gap-3 bg-primary text-primary-foreground text-sm px-4 py-3 rounded-[10px] shadow-lg" role="status">
+                    <span>{"A new version of PondoBro is available."}</span>
+                    <button type="button" onclick={{
+                        let worker = worker.clone();
+                        Callback::from(move |_| {
+                            // the worker activates, and `controllerchange` reloads the page
+                            let _ = worker.post_message(&JsValue::from_str("SKIP_WAITING"));
+                        })
+                    }} class="font-bold underline">{"Reload"}</button>
+                    <button type="button" onclick={{
+                        let sw_update = sw_update.clone();
+                        Callback::from(move |_| sw_update.set(None))
+                    }} class="text-white/70 hover:text-white" aria-label="Dismiss">{"✕"}</button>
+                </div>
+            }
+        </ContextProvider<KnownCategories>>
+        </ContextProvider<UnsavedForms>>
+        </ContextProvider<UseStateHandle<NavStatus>>>
+        </ContextProvider<SummaryContext>>
         </ContextProvider<UseStateHandle<AppSettings>>>
     }
 }
 
+/// Steps of the first-run guide, in order. Each one points at the page whose
+/// existing form does the work.
+#[derive(Clone, Copy, PartialEq)]
+enum OnboardingStep {
+    Currency,
+    Budget,
+    Goal,
+}
+
+impl OnboardingStep {
+    const ALL: [OnboardingStep; 3] = [
+        OnboardingStep::Currency,
+        OnboardingStep::Budget,
+        OnboardingStep::Goal,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            OnboardingStep::Currency => "Pick your currency",
+            OnboardingStep::Budget => "Add your first budget",
+            OnboardingStep::Goal => "Set a saving goal",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            OnboardingStep::Currency => {
+                "Choose the currency and number format amounts are shown in."
+            }
+            OnboardingStep::Budget => "Give a spending category a monthly limit to track.",
+            OnboardingStep::Goal => "Name something you're saving for and a target amount.",
+        }
+    }
+
+    /// Page holding the form for this step, and its sidebar label.
+    fn page(self) -> (Page, &'static str) {
+        match self {
+            OnboardingStep::Currency => (Page::Settings, "Settings"),
+            OnboardingStep::Budget => (Page::Budget, "Budget"),
+            OnboardingStep::Goal => (Page::Savings, "Saving Goal"),
+        }
+    }
+
+    /// Whether the step's data exists. The currency always has a value, so
+    /// that step only completes when the user moves on.
+    fn is_done(self) -> bool {
+        match self {
+            OnboardingStep::Currency => false,
+            OnboardingStep::Budget => !load_budgets().is_empty(),
+            OnboardingStep::Goal => !is_default_goal(&load_saving_goal()),
+        }
+    }
+}
+
+/// Progress through the first-run guide, stored under "onboarding".
+#[derive(Clone, Copy, PartialEq)]
+enum Onboarding {
+    NotStarted,
+    /// Index into `OnboardingStep::ALL`.
+    Step(usize),
+    /// Completed or skipped; the guide never shows again.
+    Finished,
+}
+
+fn load_onboarding() -> Onboarding {
+    let raw = web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item("onboarding").ok().flatten());
+    match raw.as_deref() {
+        None => Onboarding::NotStarted,
+        Some("done") => Onboarding::Finished,
+        Some(step) => step
+            .parse()
+            .ok()
+            .filter(|&step| step < OnboardingStep::ALL.len())
+            .map_or(Onboarding::Finished, Onboarding::Step),
+    }
+}
+
+fn save_onboarding(state: Onboarding) {
+    let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+        return;
+    };
+    let _ = match state {
+        Onboarding::NotStarted => storage.remove_item("onboarding"),
+        Onboarding::Step(step) => storage.set_item("onboarding", &step.to_string()),
+        Onboarding::Finished => storage.set_item("onboarding", "done"),
+    };
+}
+
+/// Floating getting-started card for step `step`. "Open" jumps to the page
+/// with the step's form; Next moves on and Skip ends the guide for good.
+fn onboarding_card(
+    step: usize,
+    onboarding: &UseStateHandle<Onboarding>,
+    on_select: &Callback<Page>,
+) -> Html {
+    let Some(&current) = OnboardingStep::ALL.get(step) else {
+        return html! {};
+    };
+    let (page, page_label) = current.page();
+    let last = step + 1 == OnboardingStep::ALL.len();
+    let advance = |next: Onboarding| {
+        let onboarding = onboarding.clone();
+        Callback::from(move |_| {
+            save_onboarding(next);
+            onboarding.set(next);
+        })
+    };
+    let on_next = advance(if last {
+        Onboarding::Finished
+    } else {
+        Onboarding::Step(step + 1)
+    });
+    let on_skip = advance(Onboarding::Finished);
+    let on_open = on_select.reform(move |_| page);
+
+    html! {
+        <div class="fixed bottom-6 right-6 z-40 w-80 max-w-[calc(100vw-3rem)] bg-card border border-border rounded-xl shadow-lg p-5" role="region" aria-label="Getting started">
+            <div class="flex items-center justify-between mb-2">
+                <p class="text-[10px] font-bold uppercase tracking-widest text-muted-foreground">
+                    { format!("Getting started · Step {} of {}", step + 1, OnboardingStep::ALL.len()) }
+                </p>
+                <button type="button" onclick={on_skip} class="text-xs text-muted-foreground hover:text-foreground">{"Skip"}</button>
+            </div>
+            <h3 class="font-bold text-foreground">{ current.title() }</h3>
+            <p class="text-sm text-muted-foreground mt-1">{ current.hint() }</p>
+            if current.is_done() {
+                <p class="text-xs text-green-600 font-bold mt-2">{"✓ Done"}</p>
+            }
+            <div class="flex justify-end gap-2 mt-4">
+                <button type="button" onclick={on_open} class="px-3 py-2 rounded-[10px] text-xs font-bold bg-secondary text-primary">{ format!("Open {}", page_label) }</button>
+                <button type="button" onclick={on_next} class="px-3 py-2 rounded-[10px] text-xs font-bold bg-primary text-primary-foreground">{ if last { "Finish" } else { "Next" } }</button>
+            </div>
+        </div>
+    }
+}
+
+/// Registers `sw.js` (see index.html) so the app shell loads offline, and
+/// calls `on_update` with the new worker once an update has installed and is
+/// waiting to take over. The returned listeners must be kept alive.
+fn register_service_worker(
+    on_update: Callback<web_sys::ServiceWorker>,
+) -> Rc<RefCell<Vec<EventListener>>> {
+    let listeners = Rc::new(RefCell::new(Vec::new()));
+    let Some(window) = web_sys::window() else {
+        return listeners;
+    };
+    let navigator = window.navigator();
+    if !js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        return listeners;
+    }
+    let container = navigator.service_worker();
+
+    // After the user accepts an update the new worker claims the page. A
+    // first install also claims it, but there is nothing stale to reload.
+    let had_controller = container.controller().is_some();
+    let reload = EventListener::new(&container, "controllerchange", move |_| {
+        if had_controller {
+            if let Some(window) = web_sys::window() {
+                let _ = window.location().reload();
+            }
+        }
+    });
+    listeners.borrow_mut().push(reload);
+
+    let kept = listeners.clone();
+    spawn_local(async move {
+        let Ok(registration) = JsFuture::from(container.register("sw.js")).await else {
+            return;
+        };
+        let registration: web_sys::ServiceWorkerRegistration = registration.unchecked_into();
+        // A first install has no controller yet; only later installs are updates.
+        let has_controller = {
+            let container = container.clone();
+            move || container.controller().is_some()
+        };
+        if let Some(waiting) = registration.waiting() {
+            if has_controller() {
+                on_update.emit(waiting);
+            }
+        }
+
+        let inner = kept.clone();
+        let on_found = EventListener::new(&registration.clone(), "updatefound", move |_| {
+            let Some(installing) = registration.installing() else {
+                return;
+            };
+            let worker = installing.clone();
+            let on_update = on_update.clone();
+            let has_controller = has_controller.clone();
+            let on_state = EventListener::new(&installing, "statechange", move |_| {
+                if worker.state() == web_sys::ServiceWorkerState::Installed && has_controller() {
+                    on_update.emit(worker.clone());
+                }
+            });
+            inner.borrow_mut().push(on_state);
+        });
+        kept.borrow_mut().push(on_found);
+    });
+    listeners
+}
+
+/// When the stored access token expires, in epoch milliseconds.
+fn access_token_expiry_ms() -> Option<f64> {
+    access_token_claims()?
+        .get("exp")?
+        .as_f64()
+        .map(|secs| secs * 1000.0)
+}
+
+/// How long before the access token expires the "Stay signed in" banner shows.
+const SESSION_WARNING_MS: f64 = 60_000.0;
+
+/// Result of one `POST /api/auth/refresh`.
+#[derive(Clone, Copy, PartialEq)]
+enum RefreshOutcome {
+    Refreshed,
+    /// The server answered and turned the session down.
+    Rejected,
+    /// No answer, or a server error; worth retrying.
+    Unreachable,
+}
+
+/// Attempts the boot refresh makes while the server is unreachable.
+const BOOT_REFRESH_ATTEMPTS: u32 = 4;
+/// Delay before the first retry, doubled after each one.
+const BOOT_REFRESH_BACKOFF_MS: u32 = 500;
+/// No retry starts once this much time has passed since boot began.
+const BOOT_REFRESH_MAX_WAIT_MS: f64 = 5_000.0;
+
+/// Exchanges the refresh cookie for a new access token and stores it.
+async fn refresh_access_token() -> bool {
+    request_token_refresh().await == RefreshOutcome::Refreshed
+}
+
+/// Boot-time refresh. A slow or restarting backend is retried with
+/// exponential backoff so it doesn't sign the user out; a rejection ends
+/// it at once.
+async fn refresh_access_token_with_retry() -> bool {
+    let started = js_sys::Date::now();
+    let mut delay = BOOT_REFRESH_BACKOFF_MS;
+    for attempt in 1..=BOOT_REFRESH_ATTEMPTS {
+        match request_token_refresh().await {
+            RefreshOutcome::Refreshed => return true,
+            RefreshOutcome::Rejected => return false,
+            RefreshOutcome::Unreachable => {}
+        }
+        let elapsed = js_sys::Date::now() - started;
+        if attempt == BOOT_REFRESH_ATTEMPTS || elapsed + delay as f64 > BOOT_REFRESH_MAX_WAIT_MS {
+            break;
+        }
+        TimeoutFuture::new(delay).await;
+        delay *= 2;
+    }
+    false
+}
+
+async fn request_token_refresh() -> RefreshOutcome {
+    let url = format!("{}/api/auth/refresh", API_BASE_URL);
+    let request = Request::post(&url)
+        .credentials(RequestCredentials::Include)
+        .build();
+    let response = send_api(request, None).await;
+
+    match response {
+        Ok(resp) if resp.ok() => {
+            if let Ok(json) = resp.json::<serde_json::Value>().await {
+                if let Some(token) = json.get("access_token").and_then(|v| v.as_str()) {
+                    token_store::set(token);
+                }
+            }
+            RefreshOutcome::Refreshed
+        }
+        Ok(resp) if resp.status() < 500 => RefreshOutcome::Rejected,
+        _ => RefreshOutcome::Unreachable,
+    }
+}
+
+/// Second key of the `g <key>` navigation shortcuts.
+const SHORTCUT_PAGES: [(&str, &str, Page); 7] = [
+    ("d", "Dashboard", Page::Dashboard),
+    ("b", "Budget", Page::Budget),
+    ("i", "Income Tracker", Page::Income),
+    ("e", "Expense Tracker", Page::Expense),
+    ("s", "Saving Goal", Page::Savings),
+    ("r", "Summary Report", Page::Summary),
+    ("p", "Settings", Page::Settings),
+];
+
+/// Shortcuts must not fire while the user is typing into a form field.
+fn is_typing_target(event: &web_sys::KeyboardEvent) -> bool {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .map(|element| {
+            matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                || element.has_attribute("contenteditable")
+        })
+        .unwrap_or(false)
+}
+
+#[derive(Properties, PartialEq)]
+struct ShortcutsOverlayProps {
+    on_close: Callback<()>,
+}
+
+#[function_component(ShortcutsOverlay)]
+fn shortcuts_overlay(props: &ShortcutsOverlayProps) -> Html {
+    let on_close = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+
+    html! {
+        <div class="fixed inset-0 z-50 flex items-center justify-center bg-black/30" onclick={on_close.clone()}>
+            <div class="w-full max-w-sm bg-card border border-border rounded-xl shadow-lg p-6" role="dialog" aria-modal="true" aria-label="Keyboard shortcuts" onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}>
+                <div class="flex items-center justify-between mb-4">
+                    <h3 class="font-bold text-foreground text-lg">{"Keyboard Shortcuts"}</h3>
+                    <button type="button" class="text-xs text-muted-foreground hover:text-foreground" onclick={on_close}>{"Close"}</button>
+                </div>
+                <ul class="space-y-2 text-sm">
+                    { for SHORTCUT_PAGES.iter().map(|(key, label, _)| html! {
+                        <li class="flex items-center justify-between">
+                            <span class="text-muted-foreground">{ format!("Go to {}", label) }</span>
+                            <span class="font-mono text-xs bg-secondary text-secondary-foreground px-2 py-0.5 rounded">{ format!("g {}", key) }</span>
+                        </li>
+                    }) }
+                    <li class="flex items-center justify-between">
+                        <span class="text-muted-foreground">{"Add transaction"}</span>
+                        <span class="font-mono text-xs bg-secondary text-secondary-foreground px-2 py-0.5 rounded">{"n"}</span>
+                    </li>
+                    <li class="flex items-center justify-between">
+                        <span class="text-muted-foreground">{"Show this help"}</span>
+                        <span class="font-mono text-xs bg-secondary text-secondary-foreground px-2 py-0.5 rounded">{"?"}</span>
+                    </li>
+                </ul>
+            </div>
+        </div>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 struct AuthScreenProps {
     on_authenticated: Callback<()>,
@@ -2328,9 +12916,11 @@ fn auth_screen(props: &AuthScreenProps) -> Html {
     let confirm_password = use_state(|| "".to_string());
     let error = use_state(|| None::<String>);
     let loading = use_state(|| false);
+    let remember_me = use_state(token_store::remember_me);
 
     let on_submit = {
         let is_login = is_login.clone();
+        let remember_me = remember_me.clone();
         let email = email.clone();
         let password = password.clone();
         let error = error.clone();
@@ -2342,6 +12932,7 @@ fn auth_screen(props: &AuthScreenProps) -> Html {
             let email_val = (*email).clone();
             let password_val = (*password).clone();
             let confirm_val = (*confirm_password).clone();
+            let remember = *remember_me;
             let on_authenticated = on_authenticated.clone();
 
             if email_val.is_empty() || password_val.is_empty() {
@@ -2377,23 +12968,18 @@ fn auth_screen(props: &AuthScreenProps) -> Html {
                     "confirmPassword": confirm_val,
                 });
 
-                let response = Request::post(&url)
+                let request = Request::post(&url)
                     .header("Content-Type", "application/json")
                     .credentials(RequestCredentials::Include)
-                    .body(serde_json::to_string(&body).unwrap())
-                    .unwrap()
-                    .send()
-                    .await;
+                    .body(serde_json::to_string(&body).unwrap());
+                let response = send_api(request, None).await;
 
                 match response {
                     Ok(resp) if resp.ok() => {
                         if let Ok(json) = resp.json::<serde_json::Value>().await {
                             if let Some(token) = json.get("access_token").and_then(|v| v.as_str()) {
-                                if let Some(window) = web_sys::window() {
-                                    if let Ok(Some(storage)) = window.local_storage() {
-                                        let _ = storage.set_item("access_token", token);
-                                    }
-                                }
+                                token_store::set_remember_me(remember);
+                                token_store::set(token);
                             }
                         }
                         on_authenticated.emit(());
@@ -2405,6 +12991,9 @@ fn auth_screen(props: &AuthScreenProps) -> Html {
                             .unwrap_or_else(|_| "Login failed".to_string());
                         error_async.set(Some(msg));
                     }
+                    Err(ApiError::Timeout) => {
+                        error_async.set(Some(ApiError::Timeout.message().to_string()));
+                    }
                     Err(_) => {
                         error_async.set(Some("Network error".to_string()));
                     }
@@ -2479,6 +13068,21 @@ fn auth_screen(props: &AuthScreenProps) -> Html {
                         </div>
                     }
 
+                    <label class="flex items-center gap-2 text-sm text-foreground">
+                        <input
+                            type="checkbox"
+                            checked={*remember_me}
+                            onchange={{
+                                let remember_me = remember_me.clone();
+                                Callback::from(move |e: Event| {
+                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                    remember_me.set(input.checked());
+                                })
+                            }}
+                        />
+                        {"Remember me"}
+                    </label>
+
                     if let Some(msg) = &*error {
                         <div class="text-sm text-red-500">{ msg.clone() }</div>
                     }
@@ -2514,11 +13118,8 @@ fn icon_base(path: &'static str) -> Html {
 fn icon_bell() -> Html {
     icon_base("M18 8a6 6 0 10-12 0c0 7-3 7-3 7h18s-3 0-3-7")
 }
-fn icon_moon() -> Html {
-    icon_base("M21 12.79A9 9 0 1111.21 3a7 7 0 109.79 9.79z")
-}
-fn icon_chevron_down() -> Html {
-    icon_base("M6 9l6 6 6-6")
+fn icon_chevron_left() -> Html {
+    icon_base("M15 18l-6-6 6-6")
 }
 fn icon_layout_grid() -> Html {
     icon_base("M3 3h8v8H3zM13 3h8v8h-8zM3 13h8v8H3zM13 13h8v8h-8z")
@@ -2547,6 +13148,9 @@ fn icon_log_out() -> Html {
 fn icon_plus() -> Html {
     icon_base("M12 5v14M5 12h14")
 }
+fn icon_arrow_up() -> Html {
+    icon_base("M12 19V5M5 12l7-7 7 7")
+}
 fn icon_arrow_up_right() -> Html {
     icon_base("M7 17L17 7M7 7h10v10")
 }
@@ -2554,3 +13158,470 @@ fn icon_arrow_up_right() -> Html {
 fn main() {
     yew::Renderer::<App>::new().render();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_rapid_submits_send_one_post() {
+        let in_flight = Rc::new(RefCell::new(false));
+        // stands in for the POST: counts sends and keeps each claim the way
+        // the pending request does
+        let posts = RefCell::new(Vec::new());
+        let click = || start_submit(&in_flight, |claim| posts.borrow_mut().push(claim));
+
+        assert!(click());
+        assert!(!click());
+        assert_eq!(posts.borrow().len(), 1);
+
+        // the request finishes, success or failure, and the form is free
+        posts.borrow_mut().clear();
+        assert!(!*in_flight.borrow());
+        assert!(click());
+        assert_eq!(posts.borrow().len(), 1);
+    }
+
+    #[test]
+    fn groups_digits_in_each_style() {
+        assert_eq!(format_grouped(1234567, GroupingStyle::Western), "1,234,567");
+        assert_eq!(
+            format_grouped(1234567, GroupingStyle::European),
+            "1.234.567"
+        );
+        assert_eq!(format_grouped(1234567, GroupingStyle::Indian), "12,34,567");
+        assert_eq!(
+            format_grouped(1234567, GroupingStyle::Space),
+            "1\u{a0}234\u{a0}567"
+        );
+    }
+
+    #[test]
+    fn every_listed_currency_uses_its_own_symbol() {
+        for (code, symbol, _) in CURRENCIES {
+            assert_eq!(currency_symbol_for(code), symbol, "{}", code);
+        }
+        // an unknown code shows itself, never another currency's symbol
+        assert_eq!(currency_symbol_for("XYZ"), "XYZ");
+    }
+
+    #[test]
+    fn missing_currency_code_falls_back_to_the_app_symbol() {
+        let app = CurrencyFormat::from_settings(&AppSettings {
+            currency_code: "USD".to_string(),
+            currency_symbol: "$".to_string(),
+            ..default_settings()
+        });
+        assert_eq!(app.for_code(None).symbol, "$");
+        assert_eq!(app.for_code(Some("  ")).symbol, "$");
+        assert_eq!(format_currency(5, &app.for_code(None)), "$5.00");
+        assert_eq!(app.for_code(Some("eur")).symbol, "€");
+    }
+
+    #[test]
+    fn negative_styles_only_change_negative_amounts() {
+        let cases = [
+            (NegativeStyle::LeadingSign, 1500, "₱1,500.00"),
+            (NegativeStyle::LeadingSign, -1500, "-₱1,500.00"),
+            (NegativeStyle::LeadingSign, 0, "₱0.00"),
+            (NegativeStyle::SignAfterSymbol, 1500, "₱1,500.00"),
+            (NegativeStyle::SignAfterSymbol, -1500, "₱-1,500.00"),
+            (NegativeStyle::SignAfterSymbol, 0, "₱0.00"),
+            (NegativeStyle::Parentheses, 1500, "₱1,500.00"),
+            (NegativeStyle::Parentheses, -1500, "(₱1,500.00)"),
+            (NegativeStyle::Parentheses, 0, "₱0.00"),
+        ];
+        for (negative_style, amount, expected) in cases {
+            let currency = CurrencyFormat::from_settings(&AppSettings {
+                negative_style,
+                ..default_settings()
+            });
+            assert_eq!(format_currency(amount, &currency), expected);
+        }
+    }
+
+    #[test]
+    fn symbol_spacing_puts_a_space_after_the_symbol() {
+        let spaced = |symbol_spacing| {
+            CurrencyFormat::from_settings(&AppSettings {
+                currency_code: "SAR".to_string(),
+                currency_symbol: "SAR".to_string(),
+                symbol_spacing,
+                ..default_settings()
+            })
+        };
+        assert_eq!(format_currency(250, &spaced(false)), "SAR250.00");
+        assert_eq!(format_currency(250, &spaced(true)), "SAR 250.00");
+        assert_eq!(format_currency(-250, &spaced(true)), "-SAR 250.00");
+    }
+
+    #[test]
+    fn parses_signed_zero_and_non_numeric_amounts() {
+        assert_eq!(parse_amount("-500"), Ok(-500));
+        assert_eq!(parse_amount("500"), Ok(500));
+        // zero parses; the forms reject it with their own message
+        assert_eq!(parse_amount("0"), Ok(0));
+        assert!(parse_amount("abc").unwrap_err().contains("not a number"));
+    }
+
+    #[test]
+    fn rejects_exponents_and_fractions_of_a_unit() {
+        assert!(parse_amount("1e5")
+            .unwrap_err()
+            .contains("scientific notation"));
+        assert!(parse_amount(&cents_to_decimal("1e5", '.', 2)).is_err());
+        assert_eq!(sanitize_amount_input("1e5", '.', 2), "15");
+        // typing stops at two fraction digits
+        assert_eq!(sanitize_amount_input("1.234", '.', 2), "1.23");
+        // amounts are whole units, so a fraction is refused, not rounded
+        assert!(parse_amount("1.234").unwrap_err().contains("whole units"));
+        assert!(parse_amount("1.5").unwrap_err().contains("whole units"));
+        assert!(parse_amount("-0.5").unwrap_err().contains("whole units"));
+        assert_eq!(parse_amount("12.00"), Ok(12));
+        assert_eq!(parse_amount("-12."), Ok(-12));
+        // bank imports still round their cents
+        assert_eq!(parse_rounded_amount("1.5"), Ok(2));
+        assert_eq!(parse_rounded_amount("-1.5"), Ok(-2));
+    }
+
+    #[test]
+    fn month_start_clamps_to_short_months() {
+        // February, in a common and a leap year
+        assert_eq!(period_start(2025, 2, 31), days_from_civil(2025, 2, 28));
+        assert_eq!(period_start(2024, 2, 31), days_from_civil(2024, 2, 29));
+        assert_eq!(period_start(2024, 2, 30), days_from_civil(2024, 2, 29));
+        // a 31st start in a 30-day month
+        assert_eq!(period_start(2025, 4, 31), days_from_civil(2025, 4, 30));
+        assert_eq!(period_start(2025, 5, 31), days_from_civil(2025, 5, 31));
+    }
+
+    #[test]
+    fn days_before_the_start_belong_to_the_previous_period() {
+        assert_eq!(period_of(2024, 2, 28, 31), (2024, 1));
+        assert_eq!(period_of(2024, 2, 29, 31), (2024, 2));
+        assert_eq!(period_of(2025, 2, 28, 31), (2025, 2));
+        assert_eq!(period_of(2025, 3, 30, 31), (2025, 2));
+        assert_eq!(period_of(2025, 1, 24, 25), (2024, 12));
+
+        let bounds = PeriodBounds {
+            week_start: 0,
+            month_start_day: 31,
+        };
+        assert_eq!(month_key("2025-04-29", bounds).as_deref(), Some("2025-03"));
+        assert_eq!(month_key("2025-04-30", bounds).as_deref(), Some("2025-04"));
+        assert_eq!(
+            month_key("2025-05-01T08:00:00Z", bounds).as_deref(),
+            Some("2025-04")
+        );
+        assert_eq!(month_key("2025-05-31", bounds).as_deref(), Some("2025-05"));
+    }
+
+    fn transaction(category: &str, amount: i64) -> Transaction {
+        Transaction {
+            id: None,
+            date: "2025-03-01".to_string(),
+            description: "Test".to_string(),
+            category: category.to_string(),
+            amount,
+            note: None,
+            receipt_url: None,
+            account: None,
+            tax: false,
+            currency: None,
+            split_group: None,
+            flagged: false,
+        }
+    }
+
+    fn budget(category: &str, limit: i64) -> BudgetItem {
+        BudgetItem {
+            category: category.to_string(),
+            limit,
+            history: vec![],
+        }
+    }
+
+    #[test]
+    fn merge_moves_every_spelling_and_leaves_transfers() {
+        let rows = [
+            transaction("food", -100),
+            transaction(" FOOD ", -50),
+            transaction("Dining", -75),
+            transaction(TRANSFER_CATEGORY, -20),
+        ];
+        let moved = recategorized(&rows, "Food", "Dining");
+        assert_eq!(moved.len(), 2);
+        assert!(moved.iter().all(|tx| tx.category == "Dining"));
+        assert!(recategorized(&rows, TRANSFER_CATEGORY, "Dining").is_empty());
+        // once moved, the two categories count as one
+        let counts = category_counts(&[rows[2].clone(), moved[0].clone(), moved[1].clone()]);
+        assert_eq!(counts, vec![("Dining".to_string(), 3)]);
+    }
+
+    #[test]
+    fn transfers_match_in_any_case() {
+        assert!(is_transfer(&transaction("transfer", -20)));
+        assert!(is_transfer(&transaction(" TRANSFER ", 20)));
+        assert!(!is_transfer(&transaction("Transfers", -20)));
+        assert!(!is_spending(&transaction("transfer", -20), true));
+    }
+
+    #[test]
+    fn forgetting_a_round_up_removes_its_contribution_and_queued_post() {
+        fn contribution(description: &str, amount: i64) -> Contribution {
+            Contribution {
+                date: "2025-03-01".to_string(),
+                description: description.to_string(),
+                amount,
+                goal_id: "goal-1".to_string(),
+                round_up: true,
+            }
+        }
+        let round_up = RoundUp {
+            contribution: contribution("Round-up: Coffee", 5),
+            transaction: None,
+            payload: savings_payload("2025-03-01", "Trip", "Round-up", 5),
+        };
+        let mut goal = SavingGoalState {
+            id: "goal-1".to_string(),
+            title: "Trip".to_string(),
+            target_amount: 1_000,
+            target_date: "".to_string(),
+            // an identical later round-up stays; only one entry goes
+            contributions: vec![
+                contribution("Round-up: Lunch", 3),
+                contribution("Round-up: Coffee", 5),
+                contribution("Round-up: Coffee", 5),
+            ],
+            cadence: SavingCadence::None,
+        };
+        let other = savings_payload("2025-03-01", "Trip", "Auto-save", 40);
+        let mut pending = vec![other.clone(), round_up.payload.clone()];
+
+        forget_round_up(&round_up, &mut goal, &mut pending);
+        assert_eq!(goal.contributions.len(), 2);
+        assert_eq!(goal.contributions[0].description, "Round-up: Lunch");
+        assert_eq!(pending, vec![other.clone()]);
+
+        // once posted, the queue is left alone
+        let posted = RoundUp {
+            transaction: Some(transaction(SAVINGS_CATEGORY, -5)),
+            ..round_up
+        };
+        let mut pending = vec![posted.payload.clone()];
+        forget_round_up(&posted, &mut goal, &mut pending);
+        assert_eq!(goal.contributions.len(), 1);
+        assert_eq!(pending.len(), 1);
+    }
+
+    #[test]
+    fn merge_keeps_one_budget_per_category() {
+        // the target's budget wins over the merged one
+        let merged = merge_budget_categories(
+            vec![
+                budget("food", 500),
+                budget("Dining", 300),
+                budget("Rent", 900),
+            ],
+            "Food",
+            "dining",
+        );
+        let limits: Vec<_> = merged
+            .iter()
+            .map(|b| (b.category.as_str(), b.limit))
+            .collect();
+        assert_eq!(limits, vec![("Dining", 300), ("Rent", 900)]);
+
+        // with no budget on the target, the source's is renamed
+        let renamed = merge_budget_categories(vec![budget("food", 500)], "FOOD", "Groceries");
+        assert_eq!(renamed[0].category, "Groceries");
+        assert_eq!(renamed[0].limit, 500);
+    }
+
+    #[test]
+    fn rounds_halves_in_each_mode() {
+        // (numerator, denominator, half up, floor, half even)
+        let cases = [
+            (5, 2, 3, 2, 2),
+            (-5, 2, -3, -3, -2),
+            (7, 2, 4, 3, 4),
+            (-7, 2, -4, -4, -4),
+            (5, -2, -3, -3, -2),
+        ];
+        for (n, d, half_up, down, half_even) in cases {
+            assert_eq!(
+                round_ratio(n, d, RoundingMode::HalfUp),
+                half_up,
+                "{}/{}",
+                n,
+                d
+            );
+            assert_eq!(round_ratio(n, d, RoundingMode::Down), down, "{}/{}", n, d);
+            assert_eq!(
+                round_ratio(n, d, RoundingMode::HalfEven),
+                half_even,
+                "{}/{}",
+                n,
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn down_is_a_floor() {
+        assert_eq!(round_ratio(29, 10, RoundingMode::Down), 2);
+        assert_eq!(round_ratio(-29, 10, RoundingMode::Down), -3);
+        assert_eq!(round_ratio(-21, 10, RoundingMode::Down), -3);
+        // exact quotients are left alone
+        assert_eq!(round_ratio(-20, 10, RoundingMode::Down), -2);
+        assert_eq!(round_ratio(1, 0, RoundingMode::Down), 0);
+    }
+
+    #[test]
+    fn same_keystrokes_read_per_entry_mode() {
+        let with_entry = |amount_entry| {
+            CurrencyFormat::from_settings(&AppSettings {
+                amount_entry,
+                ..default_settings()
+            })
+        };
+        let decimal = with_entry(AmountEntry::Decimal);
+        let cents = with_entry(AmountEntry::Cents);
+
+        // typing "12.50": decimal mode keeps the separator, cents mode takes
+        // digits only
+        assert_eq!(
+            sanitize_amount_input("12.50", '.', decimal.entry_places()),
+            "12.50"
+        );
+        assert_eq!(
+            sanitize_amount_input("12.50", '.', cents.entry_places()),
+            "1250"
+        );
+        assert_eq!(
+            sanitize_amount_input("12,50", ',', decimal.entry_places()),
+            "12,50"
+        );
+
+        // "1250" is ₱1,250 in decimal mode and ₱12.50 in cents mode. Amounts
+        // are whole units, so cents mode takes only whole units of cents
+        // and says so instead of rounding
+        assert_eq!(parse_entered_amount("1250", &decimal), Ok(1250));
+        assert_eq!(cents_to_decimal("1250", '.', cents.decimal_places), "12.50");
+        assert_eq!(parse_entered_amount("1200", &cents), Ok(12));
+        assert_eq!(parse_entered_amount("-1200", &cents), Ok(-12));
+        let whole_units = "Amounts are saved in whole units, so cents must end in 00, like 1200.";
+        assert_eq!(
+            parse_entered_amount("1250", &cents),
+            Err(whole_units.to_string())
+        );
+        assert_eq!(parse_entered_amount("-5", &decimal), Ok(-5));
+        assert_eq!(cents_to_decimal("-5", '.', cents.decimal_places), "-0.05");
+        assert_eq!(
+            parse_entered_amount("-5", &cents),
+            Err(whole_units.to_string())
+        );
+
+        // a zero-decimal currency reads the same either way
+        let yen = cents.for_code(Some("JPY"));
+        assert_eq!(parse_entered_amount("1250", &yen), Ok(1250));
+    }
+
+    const ROW: &str =
+        r#"{"id":7,"date":"2025-03-01","description":"Lunch","category":"Food","amount":-150}"#;
+
+    #[test]
+    fn reads_a_bare_transactions_array() {
+        let rows = parse_transactions(&format!("[{}]", ROW)).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, Some(7));
+        assert_eq!(rows[0].amount, -150);
+        assert!(parse_transactions("[]").unwrap().is_empty());
+    }
+
+    #[test]
+    fn reads_wrapped_and_paged_transactions() {
+        let items = parse_transactions(&format!(r#"{{"items":[{}],"total":41,"offset":0}}"#, ROW));
+        assert_eq!(items.unwrap()[0].description, "Lunch");
+        let data = parse_transactions(&format!(r#"{{"data":[{}]}}"#, ROW));
+        assert_eq!(data.unwrap()[0].category, "Food");
+        // an error object is reported, not read as an empty list
+        assert!(parse_transactions(r#"{"error":"Not authenticated"}"#).is_err());
+    }
+    #[test]
+    fn picking_an_account_pre_fills_its_currency() {
+        let currencies = HashMap::from([("Wise".to_string(), "USD".to_string())]);
+        assert_eq!(account_currency(&currencies, "Wise", "PHP"), "USD");
+        // accounts without a saved currency use the app's
+        assert_eq!(account_currency(&currencies, "Cash", "PHP"), "PHP");
+        assert_eq!(stored_currency("USD", "PHP").as_deref(), Some("USD"));
+        assert_eq!(stored_currency("php", "PHP"), None);
+    }
+
+    #[test]
+    fn round_up_and_auto_save_skip_other_currencies() {
+        let mut income = transaction("Salary", 1_000);
+        assert!(in_app_currency(&income, "PHP"));
+        income.currency = Some("php".to_string());
+        assert!(in_app_currency(&income, "PHP"));
+        income.currency = Some("USD".to_string());
+        assert!(!in_app_currency(&income, "PHP"));
+        assert!(!should_offer_auto_save(
+            &income,
+            10,
+            RoundingMode::default(),
+            "PHP"
+        ));
+    }
+
+    #[test]
+    fn duplicated_amounts_are_written_in_the_row_currency() {
+        let cents = CurrencyFormat::from_settings(&AppSettings {
+            amount_entry: AmountEntry::Cents,
+            ..default_settings()
+        });
+        let yen = cents.for_code(Some("JPY"));
+        // a zero-decimal currency has no cents to type
+        assert_eq!(yen.entry_text(1_500), "1500");
+        assert_eq!(
+            parse_entered_amount(&yen.entry_text(1_500), &yen),
+            Ok(1_500)
+        );
+        assert_eq!(cents.for_code(None).entry_text(15), cents.entry_text(15));
+    }
+
+    #[test]
+    fn csv_keeps_a_running_balance_per_currency() {
+        let mut usd = transaction("Food", -30);
+        usd.currency = Some("usd".to_string());
+        let rows = [transaction("Salary", 1_000), usd, transaction("Food", -200)];
+        let csv = transactions_csv(
+            &rows,
+            CsvOptions {
+                split_amounts: false,
+                running_balance: true,
+            },
+            "PHP",
+        );
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Date,Description,Category,Account,Currency,Amount,Balance,Note"
+        );
+        assert!(lines[1].ends_with(",PHP,1000,1000,"));
+        assert!(lines[2].ends_with(",USD,-30,-30,"));
+        assert!(lines[3].ends_with(",PHP,-200,800,"));
+    }
+
+    #[test]
+    fn accounts_total_counts_only_app_currency_accounts() {
+        let balances = vec![
+            ("Cash".to_string(), 1_000, "PHP".to_string()),
+            ("Wise".to_string(), 5_000, "USD".to_string()),
+            ("Bank".to_string(), -250, "php".to_string()),
+        ];
+        assert_eq!(app_currency_total(&balances, "PHP"), 750);
+        assert_eq!(app_currency_total(&balances, "USD"), 5_000);
+        assert_eq!(app_currency_total(&[], "PHP"), 0);
+    }
+}
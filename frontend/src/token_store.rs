@@ -0,0 +1,71 @@
+//! Where the access token lives. With "Remember me" on it goes to
+//! `localStorage` and survives a browser restart; with it off it goes to
+//! `sessionStorage` and is dropped when the tab closes.
+
+use web_sys::Storage;
+
+const TOKEN_KEY: &str = "access_token";
+/// Kept in `localStorage` so the choice outlives the session it applies to.
+const REMEMBER_KEY: &str = "remember_me";
+
+fn local() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+fn session() -> Option<Storage> {
+    web_sys::window()?.session_storage().ok()?
+}
+
+/// The stored preference; remembering is the default, as before the option
+/// existed.
+pub fn remember_me() -> bool {
+    local()
+        .and_then(|storage| storage.get_item(REMEMBER_KEY).ok()?)
+        .map(|raw| raw != "false")
+        .unwrap_or(true)
+}
+
+pub fn set_remember_me(remember: bool) {
+    if let Some(storage) = local() {
+        let _ = storage.set_item(REMEMBER_KEY, if remember { "true" } else { "false" });
+    }
+}
+
+fn active() -> Option<Storage> {
+    if remember_me() {
+        local()
+    } else {
+        session()
+    }
+}
+
+/// The current access token, if any.
+pub fn get() -> Option<String> {
+    active()?
+        .get_item(TOKEN_KEY)
+        .ok()?
+        .filter(|token| !token.is_empty())
+}
+
+/// Stores `token` in the backend picked by the preference and drops any copy
+/// left in the other one.
+pub fn set(token: &str) {
+    let (keep, other) = if remember_me() {
+        (local(), session())
+    } else {
+        (session(), local())
+    };
+    if let Some(storage) = other {
+        let _ = storage.remove_item(TOKEN_KEY);
+    }
+    if let Some(storage) = keep {
+        let _ = storage.set_item(TOKEN_KEY, token);
+    }
+}
+
+/// Removes the token from both backends.
+pub fn clear() {
+    for storage in [local(), session()].into_iter().flatten() {
+        let _ = storage.remove_item(TOKEN_KEY);
+    }
+}
@@ -0,0 +1,66 @@
+// PondoBro service worker: keeps the app shell available offline.
+// Bump CACHE_VERSION whenever the caching strategy changes; Trunk already
+// hashes the wasm/js bundle names, so new builds are picked up at runtime.
+const CACHE_VERSION = "pondobro-shell-v1";
+const SHELL = ["./", "index.html", "PondoBro.png", "manifest.webmanifest"];
+
+self.addEventListener("install", (event) => {
+	event.waitUntil(caches.open(CACHE_VERSION).then((cache) => cache.addAll(SHELL)));
+});
+
+self.addEventListener("activate", (event) => {
+	event.waitUntil(
+		caches
+			.keys()
+			.then((keys) => Promise.all(keys.filter((key) => key !== CACHE_VERSION).map((key) => caches.delete(key))))
+			.then(() => self.clients.claim())
+	);
+});
+
+// The page asks a waiting worker to take over once the user accepts the
+// "update available" prompt.
+self.addEventListener("message", (event) => {
+	if (event.data === "SKIP_WAITING") {
+		self.skipWaiting();
+	}
+});
+
+self.addEventListener("fetch", (event) => {
+	const request = event.request;
+	const url = new URL(request.url);
+	// API calls (a different origin) and non-GETs always go to the network.
+	if (request.method !== "GET" || url.origin !== self.location.origin || url.pathname.startsWith("/api/")) {
+		return;
+	}
+
+	// Navigations: network first so a fresh index.html (with new bundle
+	// hashes) wins, falling back to the cached shell when offline.
+	if (request.mode === "navigate") {
+		event.respondWith(
+			fetch(request)
+				.then((response) => {
+					const copy = response.clone();
+					caches.open(CACHE_VERSION).then((cache) => cache.put("index.html", copy));
+					return response;
+				})
+				.catch(() => caches.match("index.html"))
+		);
+		return;
+	}
+
+	// Static assets (hashed wasm/js/css, icons): cache first, filling the
+	// cache on the first successful fetch.
+	event.respondWith(
+		caches.match(request).then(
+			(cached) =>
+				cached ||
+				fetch(request).then((response) => {
+					if (response.ok) {
+						const copy = response.clone();
+						caches.open(CACHE_VERSION).then((cache) => cache.put(request, copy));
+					}
+					return response;
+				})
+		)
+	);
+});